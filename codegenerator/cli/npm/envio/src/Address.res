@@ -26,4 +26,14 @@ module Evm = {
   }
 
   let fromAddressOrThrow = address => address->toString->fromStringOrThrow
+
+  // Used for Address entity fields, so the same address
+  // is always stored in its checksummed form
+  let checksumSchema =
+    S.string
+    ->S.setName("Address")
+    ->S.transform(_ => {
+      parser: fromStringOrThrow,
+      serializer: address => address->fromAddressOrThrow->toString,
+    })
 }
@@ -46,6 +46,32 @@ let trackTablesRoute = Rest.route(() => {
   responses,
 })
 
+let trackFunctionRoute = Rest.route(() => {
+  method: Post,
+  path: "",
+  input: s => {
+    let _ = s.field("type", S.literal("pg_track_function"))
+    {
+      "args": s.field("args", S.json(~validate=false)),
+      "auth": s->auth,
+    }
+  },
+  responses,
+})
+
+let addComputedFieldRoute = Rest.route(() => {
+  method: Post,
+  path: "",
+  input: s => {
+    let _ = s.field("type", S.literal("pg_add_computed_field"))
+    {
+      "args": s.field("args", S.json(~validate=false)),
+      "auth": s->auth,
+    }
+  },
+  responses,
+})
+
 let createSelectPermissionRoute = Rest.route(() => {
   method: Post,
   path: "",
@@ -130,10 +156,97 @@ let trackTables = async (~endpoint, ~auth, ~pgSchema, ~tableNames: array<string>
   }
 }
 
+let trackFunction = async (~endpoint, ~auth, ~pgSchema, ~functionName: string) => {
+  try {
+    let result = await trackFunctionRoute->Rest.fetch(
+      {
+        "auth": auth,
+        "args": {
+          "function": {
+            "name": functionName,
+            "schema": pgSchema,
+          },
+          "source": "default",
+          "configuration": {
+            // Otherwise the function in gql will be prefixed with the schema name (when it's not public)
+            "custom_name": functionName,
+          },
+        }->(Utils.magic: 'a => Js.Json.t),
+      },
+      ~client=Rest.client(endpoint),
+    )
+    let msg = switch result {
+    | QuerySucceeded => "Function Tracked"
+    | AlreadyDone => "Function Already Tracked"
+    }
+    Logging.trace({
+      "msg": msg,
+      "functionName": functionName,
+    })
+  } catch {
+  | exn =>
+    Logging.error({
+      "msg": `EE809: There was an issue tracking the ${functionName} function in hasura - indexing may still work - but you may have issues querying the data in hasura.`,
+      "functionName": functionName,
+      "err": exn->Internal.prettifyExn,
+    })
+  }
+}
+
+let addComputedField = async (
+  ~endpoint,
+  ~auth,
+  ~pgSchema,
+  ~table: Table.table,
+  ~computedField: Table.computedField,
+) => {
+  let tableName = table.tableName
+  try {
+    let result = await addComputedFieldRoute->Rest.fetch(
+      {
+        "auth": auth,
+        "args": {
+          "source": "default",
+          "table": {
+            "schema": pgSchema,
+            "name": tableName,
+          },
+          "name": computedField.name,
+          "definition": {
+            "function": {
+              "schema": pgSchema,
+              "name": table->Table.getComputedFieldFnName(computedField),
+            },
+            "table_argument": "entity",
+          },
+        }->(Utils.magic: 'a => Js.Json.t),
+      },
+      ~client=Rest.client(endpoint),
+    )
+    let msg = switch result {
+    | QuerySucceeded => "Hasura computed field added"
+    | AlreadyDone => "Hasura computed field already added"
+    }
+    Logging.trace({
+      "msg": msg,
+      "tableName": tableName,
+      "computedField": computedField.name,
+    })
+  } catch {
+  | exn =>
+    Logging.error({
+      "msg": `EE810: There was an issue adding the ${computedField.name} computed field to the ${tableName} table in hasura - indexing may still work - but you may have issues querying the data in hasura.`,
+      "tableName": tableName,
+      "err": exn->Internal.prettifyExn,
+    })
+  }
+}
+
 let createSelectPermissions = async (
   ~auth,
   ~endpoint,
   ~tableName: string,
+  ~computedFieldNames: array<string>,
   ~pgSchema,
   ~responseLimit,
   ~aggregateEntities,
@@ -151,6 +264,7 @@ let createSelectPermissions = async (
           "source": "default",
           "permission": {
             "columns": "*",
+            "computed_fields": computedFieldNames,
             "filter": Js.Obj.empty(),
             "limit": responseLimit,
             "allow_aggregations": aggregateEntities->Js.Array2.includes(tableName),
@@ -231,20 +345,44 @@ let trackDatabase = async (
   Logging.info("Tracking tables in Hasura")
 
   let _ = await clearHasuraMetadata(~endpoint, ~auth)
-  let tableNames =
-    [allStaticTables, allEntityTables]
-    ->Belt.Array.concatMany
-    ->Js.Array2.map(({tableName}: Table.table) => tableName)
+  let allTables = [allStaticTables, allEntityTables]->Belt.Array.concatMany
+  let tableNames = allTables->Js.Array2.map(({tableName}: Table.table) => tableName)
 
   await trackTables(~endpoint, ~auth, ~pgSchema, ~tableNames)
 
+  // Full text search functions return rows of the entity table,
+  // so they should be tracked after the tables
+  let _ =
+    await allEntityTables
+    ->Js.Array2.map(({fullTextIndices}: Table.table) =>
+      fullTextIndices->Js.Array2.map((fullTextIndex: Table.fullTextIndex) =>
+        trackFunction(~endpoint, ~auth, ~pgSchema, ~functionName=fullTextIndex.name)
+      )
+    )
+    ->Utils.Array.flatten
+    ->Promise.all
+
+  // Computed fields should exist before they are allowed in the select permissions
+  let _ =
+    await allEntityTables
+    ->Js.Array2.map((table: Table.table) =>
+      table.computedFields->Js.Array2.map(computedField =>
+        addComputedField(~endpoint, ~auth, ~pgSchema, ~table, ~computedField)
+      )
+    )
+    ->Utils.Array.flatten
+    ->Promise.all
+
   let _ =
-    await tableNames
-    ->Js.Array2.map(tableName =>
+    await allTables
+    ->Js.Array2.map(({tableName, computedFields}: Table.table) =>
       createSelectPermissions(
         ~endpoint,
         ~auth,
         ~tableName,
+        ~computedFieldNames=computedFields->Js.Array2.map((computedField: Table.computedField) =>
+          computedField.name
+        ),
         ~pgSchema,
         ~responseLimit,
         ~aggregateEntities,
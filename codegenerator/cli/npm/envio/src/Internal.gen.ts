@@ -66,4 +66,11 @@ export type fuelTransferParams = {
   readonly amount: bigint
 };
 
+export type fuelPredicateSpendParams<data> = {
+  readonly owner: Address_t; 
+  readonly assetId: string; 
+  readonly amount: bigint; 
+  readonly data: data
+};
+
 export type noEventFilters = $$noEventFilters;
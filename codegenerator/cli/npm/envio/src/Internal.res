@@ -99,9 +99,16 @@ type fuelEventKind =
   | Burn
   | Transfer
   | Call
+  // Coin inputs owned by the predicate. Decodes the predicate data
+  | PredicateSpend({decode: string => unknown})
+  // Transactions running the script. Decodes the script data
+  | ScriptRun({decode: string => eventParams})
+  | ScriptLogData({logId: string, decode: string => eventParams})
 type fuelEventConfig = {
   ...eventConfig,
   kind: fuelEventKind,
+  blockSchema: S.schema<eventBlock>,
+  transactionSchema: S.schema<eventTransaction>,
 }
 type fuelContractConfig = {
   name: string,
@@ -174,6 +181,20 @@ let fuelTransferParamsSchema = S.schema(s => {
   assetId: s.matches(S.string),
   amount: s.matches(BigInt.schema),
 })
+@genType
+type fuelPredicateSpendParams<'data> = {
+  owner: Address.t,
+  assetId: string,
+  amount: bigint,
+  data: 'data,
+}
+let makeFuelPredicateSpendParamsSchema = (dataSchema: S.t<'data>) =>
+  S.schema(s => {
+    owner: s.matches(Address.schema),
+    assetId: s.matches(S.string),
+    amount: s.matches(BigInt.schema),
+    data: s.matches(dataSchema),
+  })
 
 type entity = private {id: string}
 type entityConfig = {
@@ -19,7 +19,45 @@ let makeCreateTableIndicesQuery = (table: Table.table, ~pgSchema) => {
   let compositeIndices = table->Table.getCompositeIndices
 
   singleIndices->Array.map(createIndex)->Js.Array2.joinWith("\n") ++
-    compositeIndices->Array.map(createCompositeIndex)->Js.Array2.joinWith("\n")
+    compositeIndices->Array.map(createCompositeIndex)->Js.Array2.joinWith("\n") ++
+    table.fullTextIndices
+    ->Array.map(fullTextIndex => {
+      let columnName = fullTextIndex->Table.getFullTextColumnName
+      `CREATE INDEX IF NOT EXISTS "${tableName}_${columnName}" ON "${pgSchema}"."${tableName}" USING GIN("${columnName}");`
+    })
+    ->Js.Array2.joinWith("\n")
+}
+
+let makeFullTextColumn = (fullTextIndex: Table.fullTextIndex) => {
+  let document =
+    fullTextIndex.fields
+    ->Js.Array2.map(fieldName => `coalesce("${fieldName}", '')`)
+    ->Js.Array2.joinWith(` || ' ' || `)
+  `"${fullTextIndex->Table.getFullTextColumnName}" tsvector GENERATED ALWAYS AS (to_tsvector('${fullTextIndex.language}', ${document})) STORED`
+}
+
+// A search function per full text index, tracked by Hasura as a root query field
+let makeFullTextSearchFnQuery = (
+  fullTextIndex: Table.fullTextIndex,
+  ~tableName,
+  ~pgSchema,
+) => {
+  `CREATE OR REPLACE FUNCTION "${pgSchema}"."${fullTextIndex.name}"(search text)
+RETURNS SETOF "${pgSchema}"."${tableName}" AS $$
+  SELECT * FROM "${pgSchema}"."${tableName}"
+  WHERE "${fullTextIndex->Table.getFullTextColumnName}" @@ websearch_to_tsquery('${fullTextIndex.language}', search);
+$$ LANGUAGE sql STABLE;`
+}
+
+let makeComputedFieldFnQuery = (table: Table.table, computedField: Table.computedField, ~pgSchema) => {
+  let returnType = switch computedField.fieldType {
+  | Custom(name) if !(name->Table.isParameterizedBuiltinType) => `"${pgSchema}".${name}`
+  | fieldType => (fieldType :> string)
+  }
+  `CREATE OR REPLACE FUNCTION "${pgSchema}"."${table->Table.getComputedFieldFnName(computedField)}"(entity "${pgSchema}"."${table.tableName}")
+RETURNS ${returnType} AS $$
+  SELECT ${computedField.sql};
+$$ LANGUAGE sql STABLE;`
 }
 
 let makeCreateTableQuery = (table: Table.table, ~pgSchema) => {
@@ -33,7 +71,7 @@ let makeCreateTableQuery = (table: Table.table, ~pgSchema) => {
 
       {
         `"${fieldName}" ${switch fieldType {
-          | Custom(name) if !(name->Js.String2.startsWith("NUMERIC(")) => `"${pgSchema}".${name}`
+          | Custom(name) if !(name->Table.isParameterizedBuiltinType) => `"${pgSchema}".${name}`
           | _ => (fieldType :> string)
           }}${isArray ? "[]" : ""}${switch defaultValue {
           | Some(defaultValue) => ` DEFAULT ${defaultValue}`
@@ -41,6 +79,7 @@ let makeCreateTableQuery = (table: Table.table, ~pgSchema) => {
           }}`
       }
     })
+    ->Js.Array2.concat(table.fullTextIndices->Array.map(makeFullTextColumn))
     ->Js.Array2.joinWith(", ")
 
   let primaryKeyFieldNames = table->Table.getPrimaryKeyFieldNames
@@ -115,6 +154,20 @@ GRANT ALL ON SCHEMA "${pgSchema}" TO public;`,
   entities->Js.Array2.forEach((entity: Internal.entityConfig) => {
     functionsQuery := functionsQuery.contents ++ "\n" ++ entity.entityHistory.createInsertFnQuery
 
+    entity.table.computedFields->Js.Array2.forEach(computedField => {
+      functionsQuery :=
+        functionsQuery.contents ++
+        "\n" ++
+        makeComputedFieldFnQuery(entity.table, computedField, ~pgSchema)
+    })
+
+    entity.table.fullTextIndices->Js.Array2.forEach(fullTextIndex => {
+      functionsQuery :=
+        functionsQuery.contents ++
+        "\n" ++
+        makeFullTextSearchFnQuery(fullTextIndex, ~tableName=entity.table.tableName, ~pgSchema)
+    })
+
     entity.table
     ->Table.getDerivedFromFields
     ->Js.Array2.forEach(derivedFromField => {
@@ -0,0 +1,27 @@
+// Spaces out calls to a data-source, so it doesn't receive
+// more than maxRequestsPerSecond requests in any second.
+// The calls are started in the order they were scheduled.
+type t = {
+  intervalMillis: float,
+  mutable nextSlotMillis: float,
+}
+
+let make = (~maxRequestsPerSecond: int) => {
+  intervalMillis: 1000. /. maxRequestsPerSecond->Belt.Int.toFloat,
+  nextSlotMillis: 0.,
+}
+
+// Reserves the next free slot and returns how long to wait for it
+let reserveSlot = (rateLimiter: t, ~nowMillis) => {
+  let slotMillis = Pervasives.max(nowMillis, rateLimiter.nextSlotMillis)
+  rateLimiter.nextSlotMillis = slotMillis +. rateLimiter.intervalMillis
+  slotMillis -. nowMillis
+}
+
+let schedule = async (rateLimiter: t, fn: unit => promise<'a>) => {
+  let delayMillis = rateLimiter->reserveSlot(~nowMillis=Js.Date.now())
+  if delayMillis > 0. {
+    await Utils.delay(delayMillis->Js.Math.ceil_int)
+  }
+  await fn()
+}
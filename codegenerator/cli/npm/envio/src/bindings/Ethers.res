@@ -102,6 +102,16 @@ module Network = {
   external fromChainId: (~chainId: int) => t = "from"
 }
 
+module FetchRequest = {
+  type t
+
+  @module("ethers") @scope("ethers") @new
+  external make: string => t = "FetchRequest"
+
+  @send
+  external setHeader: (t, string, string) => unit = "setHeader"
+}
+
 module JsonRpcProvider = {
   type t
 
@@ -136,9 +146,24 @@ module JsonRpcProvider = {
     makeWithOptions(~rpcUrl, ~network, ~options={staticNetwork: network, ?priority, ?stallTimeout})
   }
 
-  let make = (~rpcUrl: string, ~chainId: int): t => {
+  @module("ethers") @scope("ethers") @new
+  external makeWithRequest: (
+    ~request: FetchRequest.t,
+    ~network: Network.t,
+    ~options: rpcOptions,
+  ) => t = "JsonRpcProvider"
+
+  let make = (~rpcUrl: string, ~chainId: int, ~headers: dict<string>=?): t => {
     let network = Network.fromChainId(~chainId)
-    makeStatic(~rpcUrl, ~network)
+    switch headers {
+    | Some(headers) =>
+      let request = FetchRequest.make(rpcUrl)
+      headers
+      ->Js.Dict.entries
+      ->Js.Array2.forEach(((name, value)) => request->FetchRequest.setHeader(name, value))
+      makeWithRequest(~request, ~network, ~options={staticNetwork: network})
+    | None => makeStatic(~rpcUrl, ~network)
+    }
   }
 
   @send
@@ -178,3 +203,35 @@ module JsonRpcProvider = {
   @send
   external getBlock: (t, int) => promise<Js.nullable<block>> = "getBlock"
 }
+
+module WebSocketProvider = {
+  type t
+
+  @module("ethers") @scope("ethers") @new
+  external makeWithNetwork: (~rpcUrl: string, ~network: Network.t) => t = "WebSocketProvider"
+
+  let make = (~rpcUrl: string, ~chainId: int): t => {
+    makeWithNetwork(~rpcUrl, ~network=Network.fromChainId(~chainId))
+  }
+
+  // For WebSocketProvider the "block" event is backed
+  // by the eth_subscribe("newHeads") subscription
+  @send
+  external onBlock: (t, @as("block") _, int => unit) => promise<t> = "on"
+
+  @send
+  external destroy: t => promise<unit> = "destroy"
+
+  module WebSocket = {
+    type t
+
+    @set
+    external setOnError: (t, Js.Exn.t => unit) => unit = "onerror"
+
+    @set
+    external setOnClose: (t, unit => unit) => unit = "onclose"
+  }
+
+  @get
+  external websocket: t => WebSocket.t = "websocket"
+}
@@ -5,6 +5,7 @@ type derived
 @unboxed
 type fieldType =
   | @as("INTEGER") Integer
+  | @as("BIGINT") BigInt
   | @as("BOOLEAN") Boolean
   | @as("NUMERIC") Numeric
   | @as("DOUBLE PRECISION") DoublePrecision
@@ -14,8 +15,14 @@ type fieldType =
   | @as("TIMESTAMP WITH TIME ZONE") Timestamp
   | @as("TIMESTAMP") TimestampWithoutTimezone
   | @as("TIMESTAMP WITH TIME ZONE NULL") TimestampWithNullTimezone
+  | @as("DATE") Date
   | Custom(string)
 
+// Custom field types are either enums created in the pg schema
+// or builtin types with parameters like NUMERIC(p, s) and CHARACTER(n)
+let isParameterizedBuiltinType = (fieldType: string) =>
+  fieldType->Js.String2.startsWith("NUMERIC(") || fieldType->Js.String2.startsWith("CHARACTER(")
+
 type field = {
   fieldName: string,
   fieldType: fieldType,
@@ -87,18 +94,50 @@ let getFieldType = (field: field) => {
   (field.fieldType :> string) ++ (field.isArray ? "[]" : "")
 }
 
+// Defined with the @fulltext directive on an entity
+type fullTextIndex = {
+  name: string,
+  language: string,
+  fields: array<string>,
+}
+
+// Defined with the @computed directive on an entity field.
+// Not stored in the table, but resolved by Hasura with a sql function
+type computedField = {
+  name: string,
+  fieldType: fieldType,
+  // Sql expression referencing the table row as `entity`
+  sql: string,
+}
+
 type table = {
   tableName: string,
   fields: array<fieldOrDerived>,
   compositeIndices: array<array<string>>,
+  fullTextIndices: array<fullTextIndex>,
+  computedFields: array<computedField>,
 }
 
-let mkTable = (tableName, ~compositeIndices=[], ~fields) => {
+let mkTable = (
+  tableName,
+  ~compositeIndices=[],
+  ~fullTextIndices=[],
+  ~computedFields=[],
+  ~fields,
+) => {
   tableName,
   fields,
   compositeIndices,
+  fullTextIndices,
+  computedFields,
 }
 
+let getComputedFieldFnName = (table, computedField: computedField) =>
+  table.tableName ++ "_" ++ computedField.name
+
+// The generated tsvector column backing the full text index
+let getFullTextColumnName = (fullTextIndex: fullTextIndex) => fullTextIndex.name ++ "_tsvector"
+
 let getPrimaryKeyFieldNames = table =>
   table.fields->Array.keepMap(field =>
     switch field {
@@ -206,7 +245,8 @@ let toSqlParams = (table: table, ~schema, ~pgSchema) => {
               hasArrayField := true
               S.array(child->coerceSchema)->S.toUnknown
             }
-          | JSON(_) => {
+          | JSON(_)
+          | Object(_) => {
               hasArrayField := true
               schema
             }
@@ -240,8 +280,8 @@ let toSqlParams = (table: table, ~schema, ~pgSchema) => {
           switch field {
           | Field(f) =>
             switch f.fieldType {
-            // The case for `BigDecimal! @config(precision: 10, scale: 8)`
-            | Custom(fieldType) if fieldType->Js.String2.startsWith("NUMERIC(") => fieldType
+            // The case for `BigDecimal! @config(precision: 10, scale: 8)`, `Address` and `Bytes32`
+            | Custom(fieldType) if fieldType->isParameterizedBuiltinType => fieldType
             | Custom(fieldType) => `${(Text :> string)}[]::"${pgSchema}".${(fieldType :> string)}`
             | Boolean => `${(Integer :> string)}[]::${(f.fieldType :> string)}`
             | fieldType => (fieldType :> string)
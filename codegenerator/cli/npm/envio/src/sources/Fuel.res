@@ -20,6 +20,20 @@ external transpileAbi: Js.Json.t => Ethers.abi = "transpileAbi"
 @module("./vendored-fuel-abi-coder.js") @scope("AbiCoder")
 external getLogDecoder: (~abi: Ethers.abi, ~logId: string) => string => unknown = "getLogDecoder"
 
+@module("./vendored-fuel-abi-coder.js") @scope("AbiCoder")
+external getMainArgsDecoderUnsafe: (~abi: Ethers.abi) => string => unknown = "getMainArgsDecoder"
+
+// Decodes predicate data and script data using arguments of the main function
+let getMainArgsDecoder = (~abi: Ethers.abi) => {
+  let decode = getMainArgsDecoderUnsafe(~abi)
+  data => data->decode->Utils.magic
+}
+
+// Predicates and scripts are identified by the root of their bytecode.
+// For predicates it's the same as the address of the coin owner
+@module("./vendored-fuel-abi-coder.js")
+external getBytecodeRoot: string => string = "getBytecodeRoot"
+
 module Receipt = {
   @tag("receiptType")
   type t =
@@ -27,6 +41,7 @@ module Receipt = {
     | @as(6) LogData({data: string, rb: bigint})
     | @as(7) Transfer({amount: bigint, assetId: string, to: string})
     | @as(8) TransferOut({amount: bigint, assetId: string, toAddress: string})
+    | @as(9) ScriptResult({result: int})
     | @as(11) Mint({val: bigint, subId: string})
     | @as(12) Burn({val: bigint, subId: string})
 
@@ -35,3 +50,13 @@ module Receipt = {
     data => data->decode->Utils.magic
   }
 }
+
+module Input = {
+  // Only coin inputs are used, since they are the ones spent by predicates
+  type coin = {
+    owner: Address.t,
+    amount: bigint,
+    assetId: string,
+    predicateData: string,
+  }
+}
@@ -0,0 +1,299 @@
+// A minimal client for the GraphQL API of a fuel-core node.
+// It's used for networks without a HyperFuel instance, eg. a local fuel-core for tests.
+
+// TAI64 label of the unix epoch
+let tai64UnixEpoch = BigInt.fromStringUnsafe("4611686018427387914")
+
+// The zero contract id is used for receipts produced by scripts
+let zeroContractId = "0x0000000000000000000000000000000000000000000000000000000000000000"
+
+let intStringSchema = S.string->S.transform(s => {
+  parser: str =>
+    switch str->Belt.Int.fromString {
+    | Some(v) => v
+    | None => s.fail("The string is not a valid integer")
+    },
+  serializer: int => int->Belt.Int.toString,
+})
+
+let bigintStringSchema = S.string->S.transform(s => {
+  parser: str =>
+    switch str->BigInt.fromString {
+    | Some(v) => v
+    | None => s.fail("The string is not a valid bigint")
+    },
+  serializer: bigint => bigint->BigInt.toString,
+})
+
+let tai64TimestampSchema = S.string->S.transform(s => {
+  parser: str =>
+    switch str->BigInt.fromString->Belt.Option.flatMap(tai64 =>
+      tai64->BigInt.sub(tai64UnixEpoch)->BigInt.toInt
+    ) {
+    | Some(v) => v
+    | None => s.fail("The string is not a valid TAI64 timestamp")
+    },
+  serializer: unixTimestamp =>
+    unixTimestamp->BigInt.fromInt->BigInt.add(tai64UnixEpoch)->BigInt.toString,
+})
+
+let blockSchema = S.object((s): HyperFuel.block => {
+  id: s.field("id", S.string),
+  height: s.field("height", intStringSchema),
+  time: s.field("header", S.object(s => s.field("time", tai64TimestampSchema))),
+})
+
+type rawReceipt = {
+  receiptType: string,
+  id: option<string>,
+  contractId: option<string>,
+  to: option<string>,
+  toAddress: option<string>,
+  amount: option<bigint>,
+  assetId: option<string>,
+  val: option<bigint>,
+  subId: option<string>,
+  rb: option<bigint>,
+  data: option<string>,
+}
+
+let rawReceiptSchema = S.object(s => {
+  receiptType: s.field("receiptType", S.string),
+  id: s.field("id", S.null(S.string)),
+  contractId: s.field("contractId", S.null(S.string)),
+  to: s.field("to", S.null(S.string)),
+  toAddress: s.field("toAddress", S.null(S.string)),
+  amount: s.field("amount", S.null(bigintStringSchema)),
+  assetId: s.field("assetId", S.null(S.string)),
+  val: s.field("val", S.null(bigintStringSchema)),
+  subId: s.field("subId", S.null(S.string)),
+  rb: s.field("rb", S.null(bigintStringSchema)),
+  data: s.field("data", S.null(S.string)),
+})
+
+type receipt = {
+  contractId: Address.t,
+  receipt: Fuel.Receipt.t,
+}
+
+exception MissingReceiptField({receiptType: string, field: string})
+
+let getField = (value, ~receiptType, ~field) =>
+  switch value {
+  | Some(v) => v
+  | None => raise(MissingReceiptField({receiptType, field}))
+  }
+
+// Converts to the same receipt representation as HyperFuel.
+// Returns None for receipt types which can't be indexed
+let parseReceipt = (raw: rawReceipt): option<receipt> => {
+  let {receiptType} = raw
+  let contractId = switch receiptType {
+  | "MINT" | "BURN" => raw.contractId
+  | _ => raw.id
+  }
+  let receipt: option<Fuel.Receipt.t> = switch receiptType {
+  | "CALL" =>
+    Some(
+      Call({
+        assetId: raw.assetId->getField(~receiptType, ~field="assetId"),
+        amount: raw.amount->getField(~receiptType, ~field="amount"),
+        to: raw.to->getField(~receiptType, ~field="to"),
+      }),
+    )
+  | "LOG_DATA" =>
+    Some(
+      LogData({
+        data: raw.data->getField(~receiptType, ~field="data"),
+        rb: raw.rb->getField(~receiptType, ~field="rb"),
+      }),
+    )
+  | "TRANSFER" =>
+    Some(
+      Transfer({
+        amount: raw.amount->getField(~receiptType, ~field="amount"),
+        assetId: raw.assetId->getField(~receiptType, ~field="assetId"),
+        to: raw.to->getField(~receiptType, ~field="to"),
+      }),
+    )
+  | "TRANSFER_OUT" =>
+    Some(
+      TransferOut({
+        amount: raw.amount->getField(~receiptType, ~field="amount"),
+        assetId: raw.assetId->getField(~receiptType, ~field="assetId"),
+        toAddress: raw.toAddress->getField(~receiptType, ~field="toAddress"),
+      }),
+    )
+  | "MINT" =>
+    Some(
+      Mint({
+        val: raw.val->getField(~receiptType, ~field="val"),
+        subId: raw.subId->getField(~receiptType, ~field="subId"),
+      }),
+    )
+  | "BURN" =>
+    Some(
+      Burn({
+        val: raw.val->getField(~receiptType, ~field="val"),
+        subId: raw.subId->getField(~receiptType, ~field="subId"),
+      }),
+    )
+  | _ => None
+  }
+  switch (contractId, receipt) {
+  | (Some(contractId), Some(receipt)) if contractId !== zeroContractId =>
+    Some({contractId: contractId->Address.unsafeFromString, receipt})
+  | _ => None
+  }
+}
+
+type transaction = {
+  id: string,
+  // None when the transaction didn't succeed
+  receipts: option<array<rawReceipt>>,
+}
+
+// Only successful transactions have the receipts selected
+let successReceiptsSchema = S.null(
+  S.object(s => s.field("receipts", S.option(S.array(rawReceiptSchema)))),
+)->S.transform(_ => {
+  parser: status => status->Belt.Option.flatMap(receipts => receipts),
+})
+
+type blockWithTransactions = {
+  id: string,
+  height: int,
+  time: int,
+  transactions: array<transaction>,
+}
+
+let toBlock = ({id, height, time}: blockWithTransactions): HyperFuel.block => {
+  id,
+  height,
+  time,
+}
+
+let toBlockData = ({id, height, time}: HyperFuel.block): ReorgDetection.blockDataWithTimestamp => {
+  blockNumber: height,
+  blockHash: id,
+  blockTimestamp: time,
+}
+
+// The parent of the first requested block is fetched together with the range,
+// so its hash can be compared with the scanned one for reorg detection.
+// Returns the parent block and the requested blocks
+let splitParentBlock = (blocks: array<blockWithTransactions>, ~fromBlock) =>
+  switch blocks->Belt.Array.get(0) {
+  | Some(block) if fromBlock > 0 && block.height === fromBlock - 1 => (
+      Some(block->toBlock),
+      blocks->Js.Array2.sliceFrom(1),
+    )
+  | _ => (None, blocks)
+  }
+
+let makeReorgGuard = (
+  ~parentBlock: option<HyperFuel.block>,
+  ~lastBlock: HyperFuel.block,
+): ReorgDetection.reorgGuard => {
+  rangeLastBlock: lastBlock->toBlockData->ReorgDetection.generalizeBlockDataWithTimestamp,
+  prevRangeLastBlock: parentBlock->Belt.Option.map(block =>
+    block->toBlockData->ReorgDetection.generalizeBlockDataWithTimestamp
+  ),
+}
+
+let makeGraphQlRoute = (~query, ~variablesSchema, ~dataSchema) =>
+  Rest.route(() => {
+    method: Post,
+    path: "",
+    input: s => {
+      let _ = s.field("query", S.literal(query))
+      s.field("variables", variablesSchema)
+    },
+    responses: [s => s.field("data", dataSchema)],
+  })
+
+module GetBlocks = {
+  let query = `query($first: Int, $after: String) {
+  blocks(first: $first, after: $after) {
+    nodes {
+      id
+      height
+      header { time }
+      transactions {
+        id
+        status {
+          ... on SuccessStatus {
+            receipts { receiptType id contractId to toAddress amount assetId val subId rb data }
+          }
+        }
+      }
+    }
+  }
+}`
+
+  type variables = {
+    first: int,
+    // The height of the block before the first requested one
+    after: option<string>,
+  }
+
+  let route = makeGraphQlRoute(
+    ~query,
+    ~variablesSchema=S.object(s => {
+      first: s.field("first", S.int),
+      after: s.field("after", S.null(S.string)),
+    }),
+    ~dataSchema=S.object(s =>
+      s.field(
+        "blocks",
+        S.object(s =>
+          s.field(
+            "nodes",
+            S.array(
+              S.object(s => {
+                id: s.field("id", S.string),
+                height: s.field("height", intStringSchema),
+                time: s.field("header", S.object(s => s.field("time", tai64TimestampSchema))),
+                transactions: s.field(
+                  "transactions",
+                  S.array(
+                    S.object(s => {
+                      id: s.field("id", S.string),
+                      receipts: s.field("status", successReceiptsSchema),
+                    }),
+                  ),
+                ),
+              }),
+            ),
+          ),
+        ),
+      )
+    ),
+  )
+}
+
+module GetBlock = {
+  let query = `query($height: U32) {
+  block(height: $height) { id height header { time } }
+}`
+
+  let route = makeGraphQlRoute(
+    ~query,
+    ~variablesSchema=S.object(s => s.field("height", intStringSchema)),
+    ~dataSchema=S.object(s => s.field("block", S.null(blockSchema))),
+  )
+}
+
+module GetHeight = {
+  let query = `query {
+  chain { latestBlock { height } }
+}`
+
+  let route = makeGraphQlRoute(
+    ~query,
+    ~variablesSchema=S.unit,
+    ~dataSchema=S.object(s =>
+      s.field("chain", S.object(s => s.field("latestBlock", S.object(s => s.field("height", intStringSchema)))))
+    ),
+  )
+}
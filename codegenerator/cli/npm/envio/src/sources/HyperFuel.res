@@ -32,9 +32,26 @@ type block = {
 
 type item = {
   transactionId: string,
+  // Only present when there are selected transaction fields
+  transaction: option<HyperFuelClient.FuelTypes.transaction>,
+  // For receipts produced by scripts it's the bytecode root of the script
   contractId: Address.t,
+  isScriptReceipt: bool,
   receipt: Fuel.Receipt.t,
-  receiptIndex: int,
+  // The running index of the receipt among the receipts of the block,
+  // the same as for the fuel-core source
+  logIndex: int,
+  block: block,
+}
+
+type inputItem = {
+  transactionId: string,
+  transaction: option<HyperFuelClient.FuelTypes.transaction>,
+  owner: Address.t,
+  input: Fuel.Input.coin,
+  // Predicate spends go after all receipts of the block,
+  // so the index doesn't collide with the receipts logIndex
+  logIndex: int,
   block: block,
 }
 
@@ -43,7 +60,12 @@ type blockNumberAndHash = {
   hash: string,
 }
 
-type logsQueryPage = hyperSyncPage<item>
+type logsQueryPage = {
+  items: array<item>,
+  inputItems: array<inputItem>,
+  nextBlock: int,
+  archiveHeight: int,
+}
 
 type missingParams = {
   queryName: string,
@@ -68,10 +90,20 @@ module GetLogs = {
 
   exception Error(error)
 
+  // Every successful script transaction ends with a ScriptResult receipt,
+  // other transaction types don't produce receipts
+  let scriptResultReceiptsSelection: HyperFuelClient.QueryTypes.receiptSelection = {
+    receiptType: [ScriptResult],
+    txStatus: [1],
+  }
+
   let makeRequestBody = (
     ~fromBlock,
     ~toBlockInclusive,
     ~recieptsSelection,
+    ~inputsSelection,
+    ~blockFieldSelection,
+    ~transactionFieldSelection,
   ): HyperFuelClient.QueryTypes.query => {
     {
       fromBlock,
@@ -79,7 +111,13 @@ module GetLogs = {
       | Some(toBlockInclusive) => Some(toBlockInclusive + 1)
       | None => None
       },
-      receipts: recieptsSelection,
+      // The receipt index is counted per transaction,
+      // so the receipts count of every transaction is needed to derive the index in the block
+      receipts: recieptsSelection->Array.concat([scriptResultReceiptsSelection]),
+      inputs: ?switch inputsSelection {
+      | [] => None
+      | inputsSelection => Some(inputsSelection)
+      },
       fieldSelection: {
         receipt: [
           TxId,
@@ -96,8 +134,17 @@ module GetLogs = {
           ToAddress,
           AssetId,
           To,
+          Result,
         ],
-        block: [Id, Height, Time],
+        input: ?switch inputsSelection {
+        | [] => None
+        | _ => Some([TxId, BlockHeight, Owner, Amount, AssetId, PredicateData])
+        },
+        block: [Id, Height, Time]->Array.concat(blockFieldSelection),
+        transaction: ?switch transactionFieldSelection {
+        | [] => None
+        | transactionFieldSelection => Some([Id]->Array.concat(transactionFieldSelection))
+        },
       },
     }
   }
@@ -117,10 +164,11 @@ module GetLogs = {
   }
 
   //Note this function can throw an error
-  let decodeLogQueryPageItems = (response_data: HyperFuelClient.queryResponseDataTyped): array<
-    item,
-  > => {
-    let {receipts, blocks} = response_data
+  let decodeLogQueryPageItems = (response_data: HyperFuelClient.queryResponseDataTyped): (
+    array<item>,
+    array<inputItem>,
+  ) => {
+    let {receipts, blocks, transactions, inputs} = response_data
 
     let blocksDict = Js.Dict.empty()
     blocks
@@ -129,50 +177,149 @@ module GetLogs = {
       blocksDict->Js.Dict.set(block.height->(Utils.magic: int => string), block)
     })
 
+    let transactionsDict = Js.Dict.empty()
+    transactions->Array.forEach(transaction => {
+      transactionsDict->Js.Dict.set(transaction.id, transaction)
+    })
+
+    let getBlock = blockHeight =>
+      blocksDict
+      ->Utils.Dict.dangerouslyGetNonOption(blockHeight->(Utils.magic: int => string))
+      ->getParam("Failed to find block associated to receipt")
+
+    // Receipts produced by scripts don't have a contract,
+    // so they are identified by the root of the script bytecode.
+    // The script is present only when script events are selected
+    let scriptRootsByTxId = Js.Dict.empty()
+    let getScriptRoot = txId =>
+      switch scriptRootsByTxId->Utils.Dict.dangerouslyGetNonOption(txId) {
+      | Some(root) => root
+      | None => {
+          let root =
+            transactionsDict
+            ->Utils.Dict.dangerouslyGetNonOption(txId)
+            ->Option.flatMap(transaction => transaction.script)
+            ->Option.map(script => script->Fuel.getBytecodeRoot->Address.unsafeFromString)
+          scriptRootsByTxId->Js.Dict.set(txId, root)
+          root
+        }
+      }
+
+    // The receipts of a transaction go before the ones of the next transaction in the block,
+    // so the offset of a transaction is the receipts count of the previous ones
+    let receiptsCountByBlock = Js.Dict.empty()
+    let receiptsOffsetByTxId = Js.Dict.empty()
+    receipts->Array.forEach(receipt => {
+      switch receipt.receiptType {
+      | ScriptResult => {
+          let blockKey = receipt.blockHeight->(Utils.magic: int => string)
+          let offset =
+            receiptsCountByBlock
+            ->Utils.Dict.dangerouslyGetNonOption(blockKey)
+            ->Option.getWithDefault(0)
+          receiptsOffsetByTxId->Js.Dict.set(receipt.txId, offset)
+          receiptsCountByBlock->Js.Dict.set(blockKey, offset + receipt.receiptIndex + 1)
+        }
+      | _ => ()
+      }
+    })
+
     let items = []
 
     receipts->Array.forEach(receipt => {
-      switch receipt.rootContractId {
+      switch switch receipt.rootContractId {
+      | Some(contractId) => Some(contractId)
+      | None => getScriptRoot(receipt.txId)
+      } {
       | None => ()
       | Some(contractId) => {
-          let block =
-            blocksDict
-            ->Utils.Dict.dangerouslyGetNonOption(receipt.blockHeight->(Utils.magic: int => string))
-            ->getParam("Failed to find block associated to receipt")
+          let block = getBlock(receipt.blockHeight)
           items
           ->Array.push({
             transactionId: receipt.txId,
-            block: {
-              height: block.height,
-              id: block.id,
-              time: block.time,
-            },
+            transaction: transactionsDict->Utils.Dict.dangerouslyGetNonOption(receipt.txId),
+            // Keep the selected block fields on the object
+            block: block->(Utils.magic: HyperFuelClient.FuelTypes.block => block),
             contractId,
+            isScriptReceipt: receipt.rootContractId->Option.isNone,
             receipt: receipt->(Utils.magic: HyperFuelClient.FuelTypes.receipt => Fuel.Receipt.t),
-            receiptIndex: receipt.receiptIndex,
+            logIndex: receiptsOffsetByTxId
+            ->Utils.Dict.dangerouslyGetNonOption(receipt.txId)
+            ->getParam("Failed to find ScriptResult receipt of transaction") + receipt.receiptIndex,
+          })
+          ->ignore
+        }
+      }
+    })
+
+    let inputItems = []
+    // All coin inputs of the block are queried,
+    // so the index doesn't depend on the indexed predicates
+    let inputsCountByBlock = Js.Dict.empty()
+
+    inputs->Array.forEach(input => {
+      let blockKey = input.blockHeight->(Utils.magic: int => string)
+      let inputIndex =
+        inputsCountByBlock->Utils.Dict.dangerouslyGetNonOption(blockKey)->Option.getWithDefault(0)
+      inputsCountByBlock->Js.Dict.set(blockKey, inputIndex + 1)
+      switch input.owner {
+      | None => ()
+      | Some(owner) => {
+          let owner = owner->Address.unsafeFromString
+          inputItems
+          ->Array.push({
+            transactionId: input.txId,
+            transaction: transactionsDict->Utils.Dict.dangerouslyGetNonOption(input.txId),
+            block: getBlock(input.blockHeight)->(
+              Utils.magic: HyperFuelClient.FuelTypes.block => block
+            ),
+            owner,
+            input: {
+              owner,
+              amount: input.amount->getParam("amount"),
+              assetId: input.assetId->getParam("assetId"),
+              predicateData: input.predicateData->Option.getWithDefault("0x"),
+            },
+            logIndex: receiptsCountByBlock
+            ->Utils.Dict.dangerouslyGetNonOption(blockKey)
+            ->Option.getWithDefault(0) + inputIndex,
           })
           ->ignore
         }
       }
     })
-    items
+
+    (items, inputItems)
   }
 
   let convertResponse = (res: HyperFuelClient.queryResponseTyped): logsQueryPage => {
     let {nextBlock, ?archiveHeight} = res
+    let (items, inputItems) = res.data->decodeLogQueryPageItems
     let page: logsQueryPage = {
-      items: res.data->decodeLogQueryPageItems,
+      items,
+      inputItems,
       nextBlock,
       archiveHeight: archiveHeight->Option.getWithDefault(0), // TODO: FIXME: Shouldn't have a default here
     }
     page
   }
 
-  let query = async (~serverUrl, ~fromBlock, ~toBlock, ~recieptsSelection): logsQueryPage => {
+  let query = async (
+    ~serverUrl,
+    ~fromBlock,
+    ~toBlock,
+    ~recieptsSelection,
+    ~inputsSelection,
+    ~blockFieldSelection,
+    ~transactionFieldSelection,
+  ): logsQueryPage => {
     let query: HyperFuelClient.QueryTypes.query = makeRequestBody(
       ~fromBlock,
       ~toBlockInclusive=toBlock,
       ~recieptsSelection,
+      ~inputsSelection,
+      ~blockFieldSelection,
+      ~transactionFieldSelection,
     )
 
     let hyperFuelClient = CachedClients.getClient(serverUrl)
@@ -187,33 +334,32 @@ module GetLogs = {
 }
 
 module BlockData = {
-  let convertResponse = (res: HyperFuelClient.queryResponseTyped): option<
+  let convertResponse = (res: HyperFuelClient.queryResponseTyped): array<
     ReorgDetection.blockDataWithTimestamp,
   > => {
-    res.data.blocks->Option.flatMap(blocks => {
-      blocks
-      ->Array.get(0)
-      ->Option.map(block => {
-        switch block {
-        | {height: blockNumber, time: timestamp, id: blockHash} =>
-          (
-            {
-              blockTimestamp: timestamp,
-              blockNumber,
-              blockHash,
-            }: ReorgDetection.blockDataWithTimestamp
-          )
-        }
-      })
+    res.data.blocks
+    ->Option.getWithDefault([])
+    ->Array.map(block => {
+      switch block {
+      | {height: blockNumber, time: timestamp, id: blockHash} =>
+        (
+          {
+            blockTimestamp: timestamp,
+            blockNumber,
+            blockHash,
+          }: ReorgDetection.blockDataWithTimestamp
+        )
+      }
     })
   }
 
-  let rec queryBlockData = async (~serverUrl, ~blockNumber, ~logger): option<
+  // Returns the data of all blocks in the range, paginating when the response doesn't cover it
+  let rec queryBlocksData = async (~serverUrl, ~fromBlock, ~toBlock, ~logger): array<
     ReorgDetection.blockDataWithTimestamp,
   > => {
     let query: HyperFuelClient.QueryTypes.query = {
-      fromBlock: blockNumber,
-      toBlockExclusive: blockNumber + 1,
+      fromBlock,
+      toBlockExclusive: toBlock + 1,
       // FIXME: Theoretically it should work without the outputs filter, but it doesn't for some reason
       outputs: [%raw(`{}`)],
       // FIXME: Had to add inputs {} as well, since it failed on block 1211599 during wildcard Call indexing
@@ -228,7 +374,11 @@ module BlockData = {
 
     let logger = Logging.createChildFrom(
       ~logger,
-      ~params={"logType": "hypersync get blockhash query", "blockNumber": blockNumber},
+      ~params={
+        "logType": "hypersync get blockhash query",
+        "fromBlock": fromBlock,
+        "toBlock": toBlock,
+      },
     )
 
     let executeQuery = () => hyperFuelClient->HyperFuelClient.getSelectedData(query)
@@ -236,21 +386,42 @@ module BlockData = {
     let res = await executeQuery->Time.retryAsyncWithExponentialBackOff(~logger)
 
     // If the block is not found, retry the query. This can occur since replicas of hypersync might not hack caught up yet
-    if res.nextBlock <= blockNumber {
+    if res.nextBlock <= fromBlock {
       let logger = Logging.createChild(~params={"url": serverUrl})
       let delayMilliseconds = 100
       logger->Logging.childInfo(
-        `Block #${blockNumber->Int.toString} not found in HyperFuel. HyperFuel has multiple instances and it's possible that they drift independently slightly from the head. Indexing should continue correctly after retrying the query in ${delayMilliseconds->Int.toString}ms.`,
+        `Block #${fromBlock->Int.toString} not found in HyperFuel. HyperFuel has multiple instances and it's possible that they drift independently slightly from the head. Indexing should continue correctly after retrying the query in ${delayMilliseconds->Int.toString}ms.`,
       )
       await Time.resolvePromiseAfterDelay(~delayMilliseconds)
-      await queryBlockData(~serverUrl, ~blockNumber, ~logger)
+      await queryBlocksData(~serverUrl, ~fromBlock, ~toBlock, ~logger)
+    } else if res.nextBlock <= toBlock {
+      let nextBlocks = await queryBlocksData(
+        ~serverUrl,
+        ~fromBlock=res.nextBlock,
+        ~toBlock,
+        ~logger,
+      )
+      res->convertResponse->Array.concat(nextBlocks)
     } else {
       res->convertResponse
     }
   }
+
+  let queryBlockData = async (~serverUrl, ~blockNumber, ~logger): option<
+    ReorgDetection.blockDataWithTimestamp,
+  > => {
+    let blocks = await queryBlocksData(
+      ~serverUrl,
+      ~fromBlock=blockNumber,
+      ~toBlock=blockNumber,
+      ~logger,
+    )
+    blocks->Array.get(0)
+  }
 }
 
 let queryBlockData = BlockData.queryBlockData
+let queryBlocksData = BlockData.queryBlocksData
 
 let heightRoute = Rest.route(() => {
   path: "/height",
@@ -93,13 +93,42 @@ module QueryTypes = {
 
   type receiptFieldSelection = array<receiptFieldOptions>
 
+  type inputFieldOptions =
+    | @as("tx_id") TxId
+    | @as("tx_status") TxStatus
+    | @as("block_height") BlockHeight
+    | @as("input_type") InputType
+    | @as("utxo_id") UtxoId
+    | @as("owner") Owner
+    | @as("amount") Amount
+    | @as("asset_id") AssetId
+    | @as("predicate_gas_used") PredicateGasUsed
+    | @as("predicate") Predicate
+    | @as("predicate_data") PredicateData
+
+  type inputFieldSelection = array<inputFieldOptions>
+
   type fieldSelection = {
     block?: blockFieldSelection,
     transaction?: transactionFieldSelection,
     receipt?: receiptFieldSelection,
+    input?: inputFieldSelection,
   }
 
-  type inputSelection
+  type inputType =
+    | @as(0) Coin
+    | @as(1) Contract
+    | @as(2) Message
+
+  type inputSelection = {
+    owner?: array<Address.t>,
+    assetId?: array<string>,
+    contract?: array<string>,
+    sender?: array<string>,
+    recipient?: array<string>,
+    inputType?: array<inputType>,
+    txStatus?: array<int>,
+  }
   type outputSelection
 
   type receiptSelection = {
@@ -218,7 +247,7 @@ module FuelTypes = {
     /** The state root of contract after transaction execution from a transaction that changed the state of a contract. */
     outputContractStateRoot?: string,
     /** An array of witnesses. */
-    witnesses?: string,
+    witnesses?: array<string>,
     /** The root of the receipts. */
     receiptsRoot?: string,
     /** The status type of the transaction. */
@@ -243,7 +272,7 @@ module FuelTypes = {
   }
   /** An object representing all possible types of receipts. */
   type receipt = {
-    /** Index of the receipt in the block */
+    /** Index of the receipt in the transaction */
     receiptIndex: int,
     /** Contract that produced the receipt */
     rootContractId?: Address.t,
@@ -357,9 +386,9 @@ module FuelTypes = {
     consensusParametersVersion: int,
     stateTransitionBytecodeVersion: int,
     /** The number of transactions in the block. */
-    transactionsCount: string,
+    transactionsCount: int,
     /** The number of receipt messages in the block. */
-    messageReceiptCount: string,
+    messageReceiptCount: int,
     /** The merkle root of the transactions in the block. */
     transactionsRoot: string,
     messageOutboxRoot: string,
@@ -176,6 +176,19 @@ module GetBlockByNumber = {
   )
 }
 
+module GetBlockNumberByTag = {
+  let route = makeRpcRoute(
+    "eth_getBlockByNumber",
+    S.tuple(s =>
+      {
+        "tag": s.item(0, S.string),
+        "includeTransactions": s.item(1, S.bool),
+      }
+    ),
+    S.null(S.object(s => s.field("number", hexIntSchema))),
+  )
+}
+
 module GetBlockHeight = {
   let route = makeRpcRoute("eth_blockNumber", S.tuple(_ => ()), hexIntSchema)
 }
@@ -32,7 +32,15 @@ type getItemsError =
 
 exception GetItemsError(getItemsError)
 
-type sourceFor = Sync | Fallback
+// The RPC block tags which can be used to detect the reorg depth of a chain
+type finalityTag = | @as("finalized") Finalized | @as("safe") Safe
+
+type sourceFor =
+  | Sync
+  | Fallback
+  // Only used to detect new blocks at the head of the chain.
+  // Never becomes the active source for fetching items.
+  | Realtime
 type t = {
   name: string,
   sourceFor: sourceFor,
@@ -40,11 +48,15 @@ type t = {
   poweredByHyperSync: bool,
   /* Frequency (in ms) used when polling for new events on this network. */
   pollingInterval: int,
+  // The share of queries the source gets when the sync sources are load balanced
+  weight?: int,
   getBlockHashes: (
     ~blockNumbers: array<int>,
     ~logger: Pino.t,
   ) => promise<result<array<ReorgDetection.blockDataWithTimestamp>, exn>>,
   getHeightOrThrow: unit => promise<int>,
+  // Only supported by RPC sources. Resolves to None when the tagged block isn't available
+  getBlockNumberByTagOrThrow?: (~tag: finalityTag) => promise<option<int>>,
   getItemsOrThrow: (
     ~fromBlock: int,
     ~toBlock: option<int>,
@@ -16,6 +16,11 @@ type t = {
   stalledPollingInterval: int,
   getHeightRetryInterval: (~retry: int) => int,
   mutable activeSource: Source.t,
+  // When any of the sync sources has a weight,
+  // the queries are spread between all of them instead of using only the active one
+  isLoadBalanced: bool,
+  consecutiveFailures: Utils.Map.t<Source.t, int>,
+  balancerCurrentWeights: Utils.Map.t<Source.t, float>,
   mutable waitingForNewBlockStateId: option<int>,
   // Should take into consideration partitions fetching for previous states (before rollback)
   mutable fetchingPartitionsCount: int,
@@ -65,6 +70,11 @@ let make = (
     maxPartitionConcurrency,
     sources: Utils.Set.fromArray(sources),
     activeSource: initialActiveSource,
+    isLoadBalanced: sources->Js.Array2.some(source =>
+      source.sourceFor === Sync && source.weight->Option.isSome
+    ),
+    consecutiveFailures: Utils.Map.make(),
+    balancerCurrentWeights: Utils.Map.make(),
     waitingForNewBlockStateId: None,
     fetchingPartitionsCount: 0,
     newBlockFallbackStallTimeout,
@@ -229,6 +239,9 @@ let waitForNewBlock = async (sourceManager: t, ~currentBlockHeight) => {
   sources->Utils.Set.forEach(source => {
     if (
       source.sourceFor === Sync ||
+      // Realtime sources are subscribed to new heads,
+      // so they are expected to be the fastest to notice a new block
+      source.sourceFor === Realtime ||
         // Even if the active source is a fallback, still include
         // it to the list. So we don't wait for a timeout again
         // if all main sync sources are still not valid
@@ -287,7 +300,11 @@ let waitForNewBlock = async (sourceManager: t, ~currentBlockHeight) => {
     ]),
   )
 
-  sourceManager.activeSource = source
+  // Realtime sources can't fetch items,
+  // so keep the current active source
+  if source.sourceFor !== Realtime {
+    sourceManager.activeSource = source
+  }
 
   // Show a higher level log if we displayed a warning/error after newBlockFallbackStallTimeout
   let log = status.contents === Stalled ? Logging.childInfo : Logging.childTrace
@@ -323,6 +340,7 @@ let getNextSyncSource = (
       switch source.sourceFor {
       | Sync => true
       | Fallback => attemptFallbacks || source === initialSource
+      | Realtime => false
       }
     ) {
       (hasActive.contents ? after : before)->Array.push(source)
@@ -339,6 +357,63 @@ let getNextSyncSource = (
   }
 }
 
+let getEffectiveWeight = (sourceManager, ~source: Source.t) => {
+  let weight = source.weight->Option.getWithDefault(1)->Int.toFloat
+  let failures =
+    sourceManager.consecutiveFailures->Utils.Map.get(source)->Option.getWithDefault(0)
+  // Halve the weight for every consecutive failure,
+  // so failing sources get fewer queries but are still retried from time to time
+  weight /. Js.Math.pow_float(~base=2., ~exp=Pervasives.min(failures, 10)->Int.toFloat)
+}
+
+// Picks the sync source for the next query with smooth weighted round-robin.
+// Returns None when there are no other sync sources to pick from.
+let getNextBalancedSource = (sourceManager, ~excludedSource=?) => {
+  let totalWeight = ref(0.)
+  let selected = ref(None)
+
+  sourceManager.sources->Utils.Set.forEach(source => {
+    if (
+      source.sourceFor === Sync &&
+        switch excludedSource {
+        | Some(excludedSource) => excludedSource !== source
+        | None => true
+        }
+    ) {
+      let effectiveWeight = sourceManager->getEffectiveWeight(~source)
+      let currentWeight =
+        sourceManager.balancerCurrentWeights->Utils.Map.get(source)->Option.getWithDefault(0.) +.
+          effectiveWeight
+      let _ = sourceManager.balancerCurrentWeights->Utils.Map.set(source, currentWeight)
+      totalWeight := totalWeight.contents +. effectiveWeight
+
+      switch selected.contents {
+      | Some((_, selectedWeight)) if selectedWeight >= currentWeight => ()
+      | _ => selected := Some((source, currentWeight))
+      }
+    }
+  })
+
+  selected.contents->Option.map(((source, currentWeight)) => {
+    let _ =
+      sourceManager.balancerCurrentWeights->Utils.Map.set(
+        source,
+        currentWeight -. totalWeight.contents,
+      )
+    source
+  })
+}
+
+let trackSourceFailure = (sourceManager, ~source) => {
+  let failures =
+    sourceManager.consecutiveFailures->Utils.Map.get(source)->Option.getWithDefault(0)
+  let _ = sourceManager.consecutiveFailures->Utils.Map.set(source, failures + 1)
+}
+
+let trackSourceSuccess = (sourceManager, ~source) => {
+  let _ = sourceManager.consecutiveFailures->Utils.Map.delete(source)
+}
+
 let executeQuery = async (sourceManager: t, ~query: FetchState.query, ~currentBlockHeight) => {
   let toBlockRef = ref(
     switch query.target {
@@ -350,10 +425,26 @@ let executeQuery = async (sourceManager: t, ~query: FetchState.query, ~currentBl
   )
   let responseRef = ref(None)
   let retryRef = ref(0)
-  let initialSource = sourceManager.activeSource
+  // The source for the query when load balanced.
+  // Otherwise, all queries use the active source.
+  let balancedSourceRef = ref(
+    sourceManager.isLoadBalanced ? sourceManager->getNextBalancedSource : None,
+  )
+  let initialSource = balancedSourceRef.contents->Option.getWithDefault(sourceManager.activeSource)
+
+  let switchSource = (~source, ~nextSource) =>
+    switch balancedSourceRef.contents {
+    | Some(_) => {
+        balancedSourceRef := Some(nextSource)
+        if sourceManager.activeSource === source {
+          sourceManager.activeSource = nextSource
+        }
+      }
+    | None => sourceManager.activeSource = nextSource
+    }
 
   while responseRef.contents->Option.isNone {
-    let source = sourceManager.activeSource
+    let source = balancedSourceRef.contents->Option.getWithDefault(sourceManager.activeSource)
     let toBlock = toBlockRef.contents
     let retry = retryRef.contents
 
@@ -388,6 +479,7 @@ let executeQuery = async (sourceManager: t, ~query: FetchState.query, ~currentBl
         "numEvents": response.parsedQueueItems->Array.length,
         "stats": response.stats,
       })
+      sourceManager->trackSourceSuccess(~source)
       responseRef := Some(response)
     } catch {
     | Source.GetItemsError(error) =>
@@ -395,7 +487,13 @@ let executeQuery = async (sourceManager: t, ~query: FetchState.query, ~currentBl
       | UnsupportedSelection(_)
       | FailedGettingFieldSelection(_)
       | FailedParsingItems(_) => {
-          let nextSource = sourceManager->getNextSyncSource(~initialSource)
+          let balancedSource = sourceManager.isLoadBalanced
+            ? sourceManager->getNextBalancedSource(~excludedSource=source)
+            : None
+          let nextSource = switch balancedSource {
+          | Some(balancedSource) => balancedSource
+          | None => sourceManager->getNextSyncSource(~initialSource)
+          }
 
           // These errors are impossible to recover, so we delete the source
           // from sourceManager so it's not attempted anymore
@@ -428,7 +526,7 @@ let executeQuery = async (sourceManager: t, ~query: FetchState.query, ~currentBl
               "msg": "Switching to another data-source",
               "source": nextSource.name,
             })
-            sourceManager.activeSource = nextSource
+            switchSource(~source, ~nextSource)
             retryRef := 0
           }
         }
@@ -450,15 +548,27 @@ let executeQuery = async (sourceManager: t, ~query: FetchState.query, ~currentBl
         // just keep the value high
         let attemptFallbacks = retry >= 10
 
-        let nextSource = switch retry {
-        // Don't attempt a switch on first two failure
-        | 0 | 1 => source
-        | _ =>
-          // Then try to switch every second failure
-          if retry->mod(2) === 0 {
-            sourceManager->getNextSyncSource(~initialSource, ~attemptFallbacks)
-          } else {
-            source
+        sourceManager->trackSourceFailure(~source)
+
+        let balancedSource =
+          sourceManager.isLoadBalanced && !attemptFallbacks
+            ? sourceManager->getNextBalancedSource(~excludedSource=source)
+            : None
+
+        let nextSource = switch balancedSource {
+        // When load balanced, immediately retry with another sync source
+        | Some(balancedSource) => balancedSource
+        | None =>
+          switch retry {
+          // Don't attempt a switch on first two failure
+          | 0 | 1 => source
+          | _ =>
+            // Then try to switch every second failure
+            if retry->mod(2) === 0 {
+              sourceManager->getNextSyncSource(~initialSource, ~attemptFallbacks)
+            } else {
+              source
+            }
           }
         }
 
@@ -478,8 +588,14 @@ let executeQuery = async (sourceManager: t, ~query: FetchState.query, ~currentBl
             "msg": "Switching to another data-source",
             "source": nextSource.name,
           })
-          sourceManager.activeSource = nextSource
-        } else {
+          switchSource(~source, ~nextSource)
+        }
+        if (
+          !shouldSwitch ||
+          // Still back off when the load balanced sources are all failing
+          (sourceManager.isLoadBalanced &&
+          sourceManager.consecutiveFailures->Utils.Map.has(nextSource))
+        ) {
           await Utils.delay(Pervasives.min(backoffMillis, 60_000))
         }
         retryRef := retryRef.contents + 1
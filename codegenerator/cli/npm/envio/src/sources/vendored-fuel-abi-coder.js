@@ -17,6 +17,8 @@
 // 2. Changed BigNumberCoder to return BigInt instead of BN.js
 // 3. Exposed AbiCoder and added getLogDecoder static method, to do all prep work once
 // 4. Added transpileAbi function to convert json abi to old fuel abi
+// 5. Added getMainArgsDecoder static method and getBytecodeRoot function to index predicates and scripts
+// 6. Changed ByteCoder and RawSliceCoder to return a hex string instead of Uint8Array and number[]
 
 // Here's the generated diff from pnpm patch
 
@@ -126,6 +128,7 @@ __export(src_exports, {
   ASSET_ID_LEN: () => ASSET_ID_LEN,
   AbiCoder: () => AbiCoder,
   transpileAbi: () => transpileAbi,
+  getBytecodeRoot: () => getBytecodeRoot,
   ArrayCoder: () => ArrayCoder,
   B256Coder: () => B256Coder,
   B512Coder: () => B512Coder,
@@ -537,7 +540,10 @@ var ByteCoder = class extends Coder {
         `Invalid bytes byte data size.`
       );
     }
-    return [dataBytes, offsetAndLength + length];
+    return [
+      "0x" + Buffer.from(dataBytes).toString("hex"),
+      offsetAndLength + length,
+    ];
   }
 };
 __publicField(ByteCoder, "memorySize", 1);
@@ -779,9 +785,10 @@ var RawSliceCoder = class extends Coder {
         `Invalid raw slice byte data size.`
       );
     }
-    const internalCoder = new ArrayCoder(new NumberCoder("u8"), length);
-    const [decodedValue] = internalCoder.decode(dataBytes, 0);
-    return [decodedValue, offsetAndLength + length];
+    return [
+      "0x" + Buffer.from(dataBytes).toString("hex"),
+      offsetAndLength + length,
+    ];
   }
 };
 
@@ -1432,6 +1439,41 @@ var AbiCoder = class {
     );
     return (data) => internalCoder.decode(import_utils12.arrayify(data), 0)[0];
   }
+  static getMainArgsDecoder(
+    abi,
+    options = {
+      padToWordSize: false,
+    }
+  ) {
+    const mainFn = abi.functions.find((fn) => fn.name === "main");
+    if (!mainFn) {
+      throw new import_errors20.FuelError(
+        import_errors20.ErrorCode.FUNCTION_NOT_FOUND,
+        `Function 'main' doesn't exist in the ABI.`
+      );
+    }
+    const inputCoders = mainFn.inputs.map((input) => [
+      input.name,
+      getCoderForEncoding(options.encoding)(
+        new ResolvedAbiType(abi, input),
+        options
+      ),
+    ]);
+    return (data) => {
+      if (inputCoders.length === 0) {
+        return undefined;
+      }
+      const bytes = import_utils12.arrayify(data);
+      const decoded = {};
+      let offset = 0;
+      for (const [name, coder] of inputCoders) {
+        const [value, newOffset] = coder.decode(bytes, offset);
+        decoded[name] = value;
+        offset = newOffset;
+      }
+      return decoded;
+    };
+  }
   static encode(abi, argument, value, options) {
     return this.getCoder(abi, argument, options).encode(value);
   }
@@ -1657,6 +1699,57 @@ function parseComponents(abi, types, components) {
     }) ?? null
   );
 }
+// The "FUEL" seed prefixed to the merkle root of the bytecode
+const FUEL_SEED = "0x4655454c";
+// The same algorithm as getPredicateRoot from @fuel-ts/account,
+// but it's also used to identify scripts
+function getBytecodeRoot(bytecode) {
+  return (0, import_hasher.sha256)(
+    (0, import_utils11.concat)([FUEL_SEED, getBytecodeMerkleRoot(bytecode)])
+  );
+}
+function getBytecodeMerkleRoot(bytecode) {
+  const leafSize = 16 * 1024;
+  const wordSize = 8;
+  const bytes = (0, import_utils11.arrayify)(bytecode);
+  const paddedBytes = new Uint8Array(
+    Math.ceil(bytes.length / wordSize) * wordSize
+  );
+  paddedBytes.set(bytes);
+  if (paddedBytes.length === 0) {
+    return (0, import_hasher.sha256)("0x");
+  }
+  let nodes = [];
+  for (let i = 0; i < paddedBytes.length; i += leafSize) {
+    nodes.push(
+      (0, import_hasher.sha256)(
+        (0, import_utils11.concat)([
+          new Uint8Array([0]),
+          paddedBytes.slice(i, i + leafSize),
+        ])
+      )
+    );
+  }
+  while (nodes.length > 1) {
+    const parents = [];
+    for (let i = 0; i + 1 < nodes.length; i += 2) {
+      parents.push(
+        (0, import_hasher.sha256)(
+          (0, import_utils11.concat)([
+            new Uint8Array([1]),
+            nodes[i],
+            nodes[i + 1],
+          ])
+        )
+      );
+    }
+    if (nodes.length % 2 === 1) {
+      parents.push(nodes[nodes.length - 1]);
+    }
+    nodes = parents;
+  }
+  return nodes[0];
+}
 function transpileAbi(abi) {
   if (!abi.specVersion) {
     return abi;
@@ -1816,6 +1909,7 @@ var Interface = class {
   (module.exports = {
     AbiCoder,
     transpileAbi,
+    getBytecodeRoot,
     ASSET_ID_LEN,
     ArrayCoder,
     B256Coder,
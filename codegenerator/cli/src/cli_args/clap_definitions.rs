@@ -46,13 +46,21 @@ pub enum CommandType {
     Init(InitArgs),
 
     /// Development commands for starting, stopping, and restarting the indexer with automatic codegen for any changed files
-    Dev,
+    Dev(LocalEnvArgs),
 
     /// Stop the local environment - delete the database and stop all processes (including Docker) for the current directory
-    Stop,
+    Stop(LocalEnvArgs),
+
+    ///Check the local environment and the endpoints configured for the indexer, and report
+    ///which checks pass or fail
+    Doctor(LocalEnvArgs),
 
     ///Generate indexing code from user-defined configuration & schema files
-    Codegen,
+    Codegen(CodegenArgs),
+
+    ///Manage the schema file of the project
+    #[command(subcommand)]
+    Schema(SchemaSubcommands),
 
     ///Prints a summary of the benchmark data after running the indexer
     ///with envio start --bench flag or setting 'ENVIO_SAVE_BENCHMARK_DATA=true'
@@ -88,6 +96,17 @@ pub enum JsonSchema {
     Fuel,
 }
 
+#[derive(Debug, Args)]
+pub struct CodegenArgs {
+    ///Print the context passed to the codegen templates as JSON without generating any files
+    #[arg(long, action)]
+    pub print_context: bool,
+    ///Check that every configured HyperSync and RPC endpoint is reachable and serves the chain
+    ///id of its network before generating the code
+    #[arg(long, action)]
+    pub check_endpoints: bool,
+}
+
 #[derive(Debug, Args)]
 pub struct StartArgs {
     ///Clear your database and restart indexing from scratch
@@ -96,6 +115,29 @@ pub struct StartArgs {
     ///Saves benchmark data to a file during indexing
     #[arg(short = 'b', long, action)]
     pub bench: bool,
+    #[command(flatten)]
+    pub local_env: LocalEnvArgs,
+}
+
+#[derive(Debug, Default, Args)]
+pub struct LocalEnvArgs {
+    ///Use a locally running Postgres instead of starting it with Docker. Hasura is only used
+    ///when HASURA_GRAPHQL_ENDPOINT is set. The same as setting ENVIO_DOCKER=false
+    #[arg(long, action)]
+    pub no_docker: bool,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum SchemaSubcommands {
+    ///Append an entity with the event params as fields for each config event missing one in the schema
+    Generate(SchemaGenerateArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct SchemaGenerateArgs {
+    ///Print the types that would be added without modifying the schema file
+    #[arg(long, action)]
+    pub dry_run: bool,
 }
 
 #[derive(Debug, Subcommand)]
@@ -169,11 +211,11 @@ pub enum InitFlow {
 
 pub mod evm {
     use crate::{
-        config_parsing::chain_helpers::{Network, NetworkWithExplorer},
+        config_parsing::chain_registry::{ChainMetadata, ChainRegistry},
         evm, init_config,
     };
 
-    use anyhow::Context;
+    use anyhow::{anyhow, Context};
     use clap::{Args, Subcommand};
     use std::str::FromStr;
     use strum::{Display, EnumIter, EnumString};
@@ -237,8 +279,8 @@ pub mod evm {
     #[derive(Args, Debug, Default, Clone)]
     pub struct ExplorerImportArgs {
         ///Network to import the contract from
-        #[arg(short, long)]
-        pub blockchain: Option<NetworkWithExplorer>,
+        #[arg(short, long, value_parser = parse_network_with_explorer)]
+        pub blockchain: Option<&'static ChainMetadata>,
 
         ///API token for the block explorer
         #[arg(long)]
@@ -253,9 +295,16 @@ pub mod evm {
         pub all_events: bool,
     }
 
+    fn parse_network_with_explorer(s: &str) -> anyhow::Result<&'static ChainMetadata> {
+        ChainRegistry::global()?
+            .get_by_name(s)
+            .filter(|chain| chain.explorer_url.is_some())
+            .ok_or_else(|| anyhow!("Unknown network name or the network doesn't have an explorer"))
+    }
+
     #[derive(Debug, Clone)]
     pub enum NetworkOrChainId {
-        NetworkName(Network),
+        NetworkName(&'static ChainMetadata),
         ChainId(u64),
     }
 
@@ -263,7 +312,7 @@ pub mod evm {
         fn from(value: NetworkOrChainId) -> Self {
             match value {
                 NetworkOrChainId::ChainId(val) => val,
-                NetworkOrChainId::NetworkName(name) => name as u64,
+                NetworkOrChainId::NetworkName(chain) => chain.id,
             }
         }
     }
@@ -272,11 +321,9 @@ pub mod evm {
         type Err = anyhow::Error;
 
         fn from_str(s: &str) -> Result<Self, Self::Err> {
-            let res_network: Result<Network, _> = s.parse();
-
-            match res_network {
-                Ok(n) => Ok(NetworkOrChainId::NetworkName(n)),
-                Err(_) => {
+            match ChainRegistry::global()?.get_by_name(s) {
+                Some(chain) => Ok(NetworkOrChainId::NetworkName(chain)),
+                None => {
                     let chain_id: u64 = s.parse().context("Invalid network name or id")?;
                     Ok(NetworkOrChainId::ChainId(chain_id))
                 }
@@ -352,16 +399,26 @@ pub mod fuel {
 
     #[derive(Subcommand, Debug, EnumIter, EnumString, Display, Clone)]
     pub enum LocalOrExplorerImport {
-        // Not supported https://forum.fuel.network/t/get-abi-by-contract-address/5535
-        // ///Initialize by pulling the contract ABI from a block explorer
-        // #[strum(serialize = "Block Explorer")]
-        // Explorer(ExplorerImportArgs),
-        // ----
+        ///Initialize by pulling the contract ABI from an ABI registry
+        #[strum(serialize = "ABI Registry")]
+        Explorer(ExplorerImportArgs),
         ///Initialize from a local json ABI file
         #[strum(serialize = "Local ABI")]
         Local(LocalImportArgs),
     }
 
+    #[derive(Args, Debug, Default, Clone)]
+    pub struct ExplorerImportArgs {
+        ///Network to import the contract from
+        #[arg(short, long)]
+        pub blockchain: Option<init_config::fuel::Network>,
+
+        ///URL of the ABI registry serving contract ABIs by network id and address. Can also be
+        ///set with the ENVIO_FUEL_ABI_REGISTRY_URL env variable
+        #[arg(long)]
+        pub abi_registry_url: Option<String>,
+    }
+
     #[derive(Args, Debug, Default, Clone)]
     pub struct LocalImportArgs {
         ///The path to a json abi file
@@ -13,7 +13,7 @@ pub mod evm {
 
     use crate::{
         config_parsing::{
-            chain_helpers,
+            chain_registry::ChainRegistry,
             contract_import::converters::{NetworkKind, SelectedContract},
             human_config::{
                 evm::{ContractConfig, EventConfig, HumanConfig, Network, NetworkRpc},
@@ -44,6 +44,7 @@ pub mod evm {
     type ContractName = String;
     impl ContractImportSelection {
         pub fn to_human_config(&self, init_config: &InitConfig) -> Result<HumanConfig> {
+            let chain_registry = ChainRegistry::global()?;
             let mut networks_map: HashMap<u64, Network> = HashMap::new();
             let mut global_contracts: HashMap<ContractName, GlobalContract<ContractConfig>> =
                 HashMap::new();
@@ -113,15 +114,11 @@ pub mod evm {
                                 }
                             };
 
-                            let end_block = match selected_network.network {
-                                NetworkKind::Supported(network) => {
-                                    chain_helpers::Network::from(network).get_finite_end_block()
-                                }
-                                NetworkKind::Unsupported { network_id, .. } => {
-                                    chain_helpers::Network::from_network_id(network_id)
-                                        .ok()
-                                        .and_then(|network| network.get_finite_end_block())
-                                }
+                            let end_block = match &selected_network.network {
+                                NetworkKind::Supported(chain) => chain.finite_end_block,
+                                NetworkKind::Unsupported { network_id, .. } => chain_registry
+                                    .get_by_id(*network_id)
+                                    .and_then(|chain| chain.finite_end_block),
                             };
 
                             Network {
@@ -132,6 +129,7 @@ pub mod evm {
                                 start_block: selected_network.network.get_start_block(),
                                 end_block,
                                 confirmed_block_threshold: None,
+                                finality: None,
                                 contracts: Vec::new(),
                             }
                         });
@@ -202,18 +200,21 @@ pub mod evm {
 pub mod fuel {
     use std::collections::HashMap;
 
+    use anyhow::{Context, Result};
     use clap::ValueEnum;
+    use itertools::Itertools;
     use serde::{Deserialize, Serialize};
-    use strum::{Display, EnumIter, EnumString, IntoEnumIterator};
+    use strum::{Display, EnumIter, EnumString};
 
     use crate::{
         config_parsing::human_config::{
             fuel::{
                 ContractConfig, EcosystemTag, EventConfig, HumanConfig, Network as NetworkConfig,
             },
-            NetworkContract,
+            GlobalContract, NetworkContract,
         },
         fuel::{abi::FuelAbi, address::Address},
+        utils::unique_hashmap,
     };
 
     use super::InitConfig;
@@ -223,25 +224,57 @@ pub mod fuel {
         Greeter,
     }
 
-    #[derive(Clone, Debug, Display, Eq, Hash, PartialEq, EnumIter)]
+    #[derive(Clone, Debug, ValueEnum, Display, Eq, Hash, PartialEq, EnumIter)]
     pub enum Network {
         Mainnet = 9889,
         Testnet = 0,
     }
 
+    impl Network {
+        pub fn get_network_id(&self) -> u64 {
+            self.clone() as u64
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct ContractImportNetworkSelection {
+        pub network: Network,
+        pub addresses: Vec<Address>,
+    }
+
+    impl ContractImportNetworkSelection {
+        pub fn new(network: Network, address: Address) -> Self {
+            Self {
+                network,
+                addresses: vec![address],
+            }
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct SelectedContract {
         pub name: String,
-        pub addresses: Vec<Address>,
         pub abi: FuelAbi,
         pub selected_events: Vec<EventConfig>,
-        pub network: Network,
+        pub networks: Vec<ContractImportNetworkSelection>,
     }
 
     impl SelectedContract {
         pub fn get_vendored_abi_file_path(&self) -> String {
             format!("abis/{}-abi.json", self.name.to_lowercase())
         }
+
+        pub fn get_last_network_mut(&mut self) -> Result<&mut ContractImportNetworkSelection> {
+            self.networks
+                .last_mut()
+                .context("Failed to get the last select contract network")
+        }
+
+        pub fn get_last_network(&self) -> Result<&ContractImportNetworkSelection> {
+            self.networks
+                .last()
+                .context("Failed to get the last select contract network")
+        }
     }
 
     #[derive(Clone, Debug)]
@@ -249,61 +282,96 @@ pub mod fuel {
         pub contracts: Vec<SelectedContract>,
     }
 
+    type ContractName = String;
     impl ContractImportSelection {
-        pub fn to_human_config(&self, init_config: &InitConfig) -> HumanConfig {
-            let mut contracts_by_network: HashMap<Network, Vec<SelectedContract>> = HashMap::new();
-
-            for contract in self.contracts.clone() {
-                match contracts_by_network.get_mut(&contract.network) {
-                    None => {
-                        contracts_by_network.insert(contract.network.clone(), vec![contract]);
-                    }
-                    Some(contracts) => contracts.push(contract),
-                }
-            }
+        pub fn to_human_config(&self, init_config: &InitConfig) -> Result<HumanConfig> {
+            let mut networks_map: HashMap<u64, NetworkConfig> = HashMap::new();
+            let mut global_contracts: HashMap<ContractName, GlobalContract<ContractConfig>> =
+                HashMap::new();
+
+            for selected_contract in &self.contracts {
+                let is_multi_chain_contract = selected_contract.networks.len() > 1;
+
+                let contract_config = ContractConfig {
+                    abi_file_path: selected_contract.get_vendored_abi_file_path(),
+                    handler: init_config.language.get_event_handler_directory(),
+                    events: selected_contract.selected_events.clone(),
+                };
 
-            let mut network_configs = vec![];
-            for network in Network::iter() {
-                match contracts_by_network.get(&network) {
-                    None => (),
-                    Some(contracts) => network_configs.push(NetworkConfig {
-                        id: network as u64,
-                        start_block: 0,
-                        end_block: None,
-                        hyperfuel_config: None,
-                        contracts: contracts
+                let config = if is_multi_chain_contract {
+                    //Add the contract to global contract config and return none for local contract
+                    //config
+                    unique_hashmap::try_insert(
+                        &mut global_contracts,
+                        selected_contract.name.clone(),
+                        GlobalContract {
+                            name: selected_contract.name.clone(),
+                            config: contract_config,
+                        },
+                    )
+                    .context(format!(
+                        "Unexpected, failed to add global contract {}. Contract should have \
+                         unique names",
+                        selected_contract.name
+                    ))?;
+                    None
+                } else {
+                    Some(contract_config)
+                };
+
+                for selected_network in &selected_contract.networks {
+                    let network = networks_map
+                        .entry(selected_network.network.get_network_id())
+                        .or_insert(NetworkConfig {
+                            id: selected_network.network.get_network_id(),
+                            start_block: 0,
+                            end_block: None,
+                            hyperfuel_config: None,
+                            fuel_core: None,
+                            confirmed_block_threshold: None,
+                            contracts: Vec::new(),
+                        });
+
+                    network.contracts.push(NetworkContract {
+                        name: selected_contract.name.clone(),
+                        address: selected_network
+                            .addresses
                             .iter()
-                            .map(|selected_contract| NetworkContract {
-                                name: selected_contract.name.clone(),
-                                address: selected_contract
-                                    .addresses
-                                    .iter()
-                                    .map(|a| a.to_string())
-                                    .collect::<Vec<String>>()
-                                    .into(),
-                                config: Some(ContractConfig {
-                                    abi_file_path: selected_contract.get_vendored_abi_file_path(),
-                                    handler: init_config.language.get_event_handler_directory(),
-                                    events: selected_contract.selected_events.clone(),
-                                }),
-                                start_block: None,
-                            })
-                            .collect(),
-                    }),
+                            .map(|a| a.to_string())
+                            .collect::<Vec<String>>()
+                            .into(),
+                        config: config.clone(),
+                        start_block: None,
+                    });
                 }
             }
 
-            HumanConfig {
+            let contracts = match global_contracts
+                .into_values()
+                .sorted_by_key(|v| v.name.clone())
+                .collect::<Vec<_>>()
+            {
+                values if values.is_empty() => None,
+                values => Some(values),
+            };
+
+            Ok(HumanConfig {
                 name: init_config.name.clone(),
                 description: None,
                 ecosystem: EcosystemTag::Fuel,
                 schema: None,
                 output: None,
-                contracts: None,
+                contracts,
+                predicates: None,
+                scripts: None,
+                rollback_on_reorg: None,
+                save_full_history: None,
+                field_selection: None,
                 raw_events: None,
                 preload_handlers: Some(true),
-                networks: network_configs,
-            }
+                allow_unknown_types: None,
+                networks: networks_map.into_values().sorted_by_key(|v| v.id).collect(),
+            })
         }
     }
 
@@ -361,3 +429,131 @@ pub struct InitConfig {
     pub language: Language,
     pub api_token: Option<String>,
 }
+
+#[cfg(test)]
+mod test {
+    use super::{
+        fuel::{
+            ContractImportNetworkSelection, ContractImportSelection, Network, SelectedContract,
+        },
+        Ecosystem, InitConfig, Language,
+    };
+    use crate::{
+        config_parsing::human_config::{
+            fuel::{
+                ContractConfig, EcosystemTag, EventConfig, HumanConfig, Network as NetworkConfig,
+            },
+            GlobalContract, NetworkContract,
+        },
+        fuel::{abi::FuelAbi, address::Address},
+    };
+    use pretty_assertions::assert_eq;
+    use std::path::PathBuf;
+
+    const ADDRESS_1: &str = "0x4a2ce054e3e94155f7092f7365b212f7f45105b74819c623744ebcc5d065c6ac";
+    const ADDRESS_2: &str = "0xb9bc445e5696c966dcf7e5d1237bd03c04e3ba6929bdaedfeebc7aae784c3a0b";
+
+    fn selected_contract(name: &str, networks: Vec<(Network, &str)>) -> SelectedContract {
+        SelectedContract {
+            name: name.to_string(),
+            abi: FuelAbi::parse(PathBuf::from(format!(
+                "{}/test/abis/greeter-abi.json",
+                env!("CARGO_MANIFEST_DIR")
+            )))
+            .unwrap(),
+            selected_events: vec![EventConfig {
+                name: "NewGreeting".to_string(),
+                type_: None,
+                log_id: None,
+                field_selection: None,
+            }],
+            networks: networks
+                .into_iter()
+                .map(|(network, address)| {
+                    ContractImportNetworkSelection::new(network, Address::new(address).unwrap())
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn fuel_contract_import_merges_networks_into_config() {
+        let selection = ContractImportSelection {
+            contracts: vec![
+                selected_contract(
+                    "Greeter",
+                    vec![(Network::Mainnet, ADDRESS_1), (Network::Testnet, ADDRESS_1)],
+                ),
+                selected_contract("Token", vec![(Network::Testnet, ADDRESS_2)]),
+            ],
+        };
+        let init_config = InitConfig {
+            name: "my-indexer".to_string(),
+            directory: "my-indexer".to_string(),
+            ecosystem: Ecosystem::Fuel {
+                init_flow: super::fuel::InitFlow::ContractImport(selection.clone()),
+            },
+            language: Language::TypeScript,
+            api_token: None,
+        };
+
+        let contract_config = |name: &str| ContractConfig {
+            abi_file_path: format!("abis/{name}-abi.json"),
+            handler: "src/EventHandlers.ts".to_string(),
+            events: vec![EventConfig {
+                name: "NewGreeting".to_string(),
+                type_: None,
+                log_id: None,
+                field_selection: None,
+            }],
+        };
+        let network_config = |id: u64, contracts| NetworkConfig {
+            id,
+            start_block: 0,
+            end_block: None,
+            hyperfuel_config: None,
+            fuel_core: None,
+            confirmed_block_threshold: None,
+            contracts,
+        };
+        let network_contract = |name: &str, address: &str, config| NetworkContract {
+            name: name.to_string(),
+            address: vec![address.to_string()].into(),
+            config,
+            start_block: None,
+        };
+
+        assert_eq!(
+            selection.to_human_config(&init_config).unwrap(),
+            HumanConfig {
+                name: "my-indexer".to_string(),
+                description: None,
+                ecosystem: EcosystemTag::Fuel,
+                schema: None,
+                output: None,
+                contracts: Some(vec![GlobalContract {
+                    name: "Greeter".to_string(),
+                    config: contract_config("greeter"),
+                }]),
+                predicates: None,
+                scripts: None,
+                rollback_on_reorg: None,
+                save_full_history: None,
+                field_selection: None,
+                raw_events: None,
+                preload_handlers: Some(true),
+                allow_unknown_types: None,
+                networks: vec![
+                    network_config(
+                        0,
+                        vec![
+                            network_contract("Greeter", ADDRESS_1, None),
+                            network_contract("Token", ADDRESS_2, Some(contract_config("token"))),
+                        ]
+                    ),
+                    network_config(9889, vec![network_contract("Greeter", ADDRESS_1, None)]),
+                ],
+            }
+        );
+    }
+}
@@ -12,7 +12,7 @@ use crate::{
     clap_definitions::evm::NetworkOrChainId,
     cli_args::interactive_init::validation::filter_duplicate_events,
     config_parsing::{
-        chain_helpers::{HypersyncNetwork, Network, NetworkWithExplorer},
+        chain_registry::{ChainMetadata, ChainRegistry},
         contract_import::{
             contract_import,
             converters::{self, ContractImportNetworkSelection, SelectedContract},
@@ -25,7 +25,6 @@ use crate::{
 };
 use anyhow::{Context, Result};
 use inquire::{validator::Validation, CustomType, Select, Text};
-use serde::{Deserialize, Serialize};
 use std::{env, fmt, path::PathBuf};
 use strum::IntoEnumIterator;
 
@@ -88,10 +87,10 @@ impl ContractImportArgs {
 
     async fn get_selected_contract(
         &self,
-        network: &NetworkWithExplorer,
+        network: &ChainMetadata,
         contract_address: Address,
     ) -> anyhow::Result<SelectedContract> {
-        let result = match contract_import(network, &contract_address, 0).await {
+        let result = match contract_import(network.id, &contract_address, 0).await {
             Ok(ContractImportResult::Contract(contract_data)) => Ok(contract_data),
             Ok(ContractImportResult::NotVerified) => {
                 Err("Failed to find the verified contract on a block explorer.".to_string())
@@ -114,7 +113,7 @@ impl ContractImportArgs {
                     ..self.clone()
                 })
                 .get_contract_import_selection_from_local_import_args(&LocalImportArgs {
-                    blockchain: Some(NetworkOrChainId::ChainId(*network as u64)),
+                    blockchain: Some(NetworkOrChainId::ChainId(network.id)),
                     ..LocalImportArgs::default()
                 })
                 .await;
@@ -126,7 +125,7 @@ impl ContractImportArgs {
             abi_events = prompt_abi_events_selection(abi_events)?;
         }
 
-        let network_kind = get_converter_network_u64(*network as u64, &None, &None)?;
+        let network_kind = get_converter_network_u64(network.id, &None, &None)?;
 
         let network_selection = ContractImportNetworkSelection::new(network_kind, contract_address);
 
@@ -160,7 +159,7 @@ impl ContractImportArgs {
 
         // Use the version with the flags set properly
         let selected_contract = args_with_flags
-            .get_selected_contract(&network_with_explorer, chosen_contract_address)
+            .get_selected_contract(network_with_explorer, chosen_contract_address)
             .await
             .context("Failed getting SelectedContract from explorer")?;
 
@@ -195,10 +194,10 @@ impl ContractImportArgs {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Debug, Clone)]
 enum NetworkSelection {
     EnterNetworkId,
-    Network(HypersyncNetwork),
+    Network(ChainMetadata),
 }
 
 impl fmt::Display for NetworkSelection {
@@ -221,13 +220,12 @@ fn prompt_for_network_id(
     already_selected_ids: Vec<u64>,
 ) -> Result<converters::NetworkKind> {
     //Select one of our supported networks
-    let networks = HypersyncNetwork::iter()
+    let networks = ChainRegistry::global()?
+        .iter_hypersync_chains()
         //Don't allow selection of networks that have been previously
         //selected.
-        .filter(|n| {
-            let network_id = *n as u64;
-            !already_selected_ids.contains(&network_id)
-        })
+        .filter(|chain| !already_selected_ids.contains(&chain.id))
+        .cloned()
         .map(NetworkSelection::Network)
         .collect::<Vec<_>>();
 
@@ -254,10 +252,8 @@ fn prompt_for_network_id(
             //for an rpc url
             get_converter_network_u64(network_id, opt_rpc_url, opt_start_block)?
         }
-        //If a supported network choice was selected. We should be able to
-        //parse it back to a supported network since it was serialized as a
-        //string
-        NetworkSelection::Network(network) => converters::NetworkKind::Supported(network),
+        //If a supported network choice was selected, it's indexed with HyperSync
+        NetworkSelection::Network(chain) => converters::NetworkKind::Supported(chain),
     };
 
     Ok(selected)
@@ -271,12 +267,13 @@ fn get_converter_network_u64(
     rpc_url: &Option<String>,
     start_block: &Option<u64>,
 ) -> Result<converters::NetworkKind> {
-    let maybe_supported_network =
-        Network::from_network_id(network_id).and_then(|n| Ok(HypersyncNetwork::try_from(n)?));
+    let maybe_supported_network = ChainRegistry::global()?
+        .get_by_id(network_id)
+        .filter(|chain| chain.hypersync_url.is_some());
 
     let network = match maybe_supported_network {
-        Ok(s) => converters::NetworkKind::Supported(s),
-        Err(_) => {
+        Some(chain) => converters::NetworkKind::Supported(chain.clone()),
+        None => {
             let rpc_url = match rpc_url {
                 Some(r) => r.clone(),
                 None => prompt_for_rpc_url()?,
@@ -321,13 +318,13 @@ fn prompt_for_rpc_url() -> Result<String> {
 }
 
 impl ExplorerImportArgs {
-    ///Either take the NetworkWithExplorer value from the cli args or prompt
+    ///Either take the network with an explorer from the cli args or prompt
     ///for a user to select one.
-    fn get_network_with_explorer(&self) -> Result<NetworkWithExplorer> {
-        let chosen_network = match &self.blockchain {
-            Some(chain) => *chain,
+    fn get_network_with_explorer(&self) -> Result<&'static ChainMetadata> {
+        let chosen_network = match self.blockchain {
+            Some(chain) => chain,
             None => {
-                let options = NetworkWithExplorer::iter().collect();
+                let options = ChainRegistry::global()?.iter_explorer_chains().collect();
 
                 Select::new(
                     "Which blockchain would you like to import a contract from?",
@@ -2,15 +2,24 @@ use std::path::PathBuf;
 
 use crate::{
     clap_definitions::fuel::{
-        ContractImportArgs, InitFlow as ClapInitFlow, LocalImportArgs, LocalOrExplorerImport,
-        TemplateArgs,
+        ContractImportArgs, ExplorerImportArgs, InitFlow as ClapInitFlow, LocalImportArgs,
+        LocalOrExplorerImport, TemplateArgs,
     },
     config_parsing::human_config::fuel::EventConfig,
-    fuel::abi::{FuelAbi, BURN_EVENT_NAME, CALL_EVENT_NAME, MINT_EVENT_NAME, TRANSFER_EVENT_NAME},
-    init_config::fuel::{ContractImportSelection, InitFlow, Network, SelectedContract, Template},
+    fuel::{
+        abi::{FuelAbi, BURN_EVENT_NAME, CALL_EVENT_NAME, MINT_EVENT_NAME, TRANSFER_EVENT_NAME},
+        address::Address,
+        contract_import::{
+            contract_import, get_abi_registry_url_from_env, ContractData, ContractImportResult,
+        },
+    },
+    init_config::fuel::{
+        ContractImportNetworkSelection, ContractImportSelection, InitFlow, Network,
+        SelectedContract, Template,
+    },
 };
 use anyhow::{Context, Result};
-use inquire::{validator::Validation, Select};
+use inquire::{validator::Validation, Select, Text};
 use strum::IntoEnumIterator;
 
 use super::shared_prompts::{
@@ -43,14 +52,51 @@ pub fn prompt_template_init_flow(args: TemplateArgs) -> Result<InitFlow> {
 }
 
 ///Takes either the "local" or "explorer" subcommand from the cli args
-///or prompts for a choice from the user (not supported by Fuel yet)
-fn get_local_or_explorer_import(args: &ContractImportArgs) -> LocalOrExplorerImport {
+///or prompts for a choice from the user
+fn get_local_or_explorer_import(args: &ContractImportArgs) -> Result<LocalOrExplorerImport> {
     match &args.local_or_explorer {
-        Some(v) => v.clone(),
-        None => LocalOrExplorerImport::Local(LocalImportArgs {
-            abi_file: None,
-            contract_name: None,
-        }),
+        Some(v) => Ok(v.clone()),
+        None => {
+            let options = LocalOrExplorerImport::iter().collect();
+
+            Select::new(
+                "Would you like to import from an ABI registry or a local abi?",
+                options,
+            )
+            .prompt()
+            .context("Failed prompting for import from ABI registry or local abi")
+        }
+    }
+}
+
+///Takes the address passed in by cli flag or prompts for an address
+fn get_contract_address(args: &ContractImportArgs) -> Result<Address> {
+    match &args.contract_address {
+        Some(address) => Ok(address.clone()),
+        None => prompt_contract_address(None),
+    }
+}
+
+fn prompt_network(selected: &[Network]) -> Result<Network> {
+    let options = Network::iter()
+        .filter(|network| !selected.contains(network))
+        .collect();
+    Select::new("Choose network:", options)
+        .prompt()
+        .context("Failed during prompt for network")
+}
+
+///Takes the registry url from the cli args, the env or prompts for it
+fn get_abi_registry_url(explorer_import_args: &ExplorerImportArgs) -> Result<String> {
+    match explorer_import_args
+        .abi_registry_url
+        .clone()
+        .or_else(get_abi_registry_url_from_env)
+    {
+        Some(url) => Ok(url),
+        None => Text::new("What is the url of the ABI registry?")
+            .prompt()
+            .context("Failed during prompt for ABI registry url"),
     }
 }
 
@@ -93,13 +139,7 @@ fn prompt_event_selection(events: Vec<EventConfig>) -> Result<Vec<EventConfig>>
 
 impl Contract for SelectedContract {
     fn get_network_name(&self) -> Result<String> {
-        Ok(format!(
-            "Fuel {}",
-            match self.network {
-                Network::Mainnet => "Mainnet",
-                Network::Testnet => "Testnet",
-            }
-        ))
+        Ok(format!("Fuel {}", self.get_last_network()?.network))
     }
 
     fn get_name(&self) -> String {
@@ -107,32 +147,34 @@ impl Contract for SelectedContract {
     }
 
     fn add_address(&mut self) -> Result<()> {
-        let address = prompt_contract_address(Some(&self.addresses))?;
-        self.addresses.push(address);
+        let last_network = self.get_last_network_mut()?;
+        let address = prompt_contract_address(Some(&last_network.addresses))?;
+        last_network.addresses.push(address);
         Ok(())
     }
 
     fn add_network(&mut self) -> Result<()> {
-        todo!("Fuel supports only one network at the moment")
+        let selected_networks = self
+            .networks
+            .iter()
+            .map(|n| n.network.clone())
+            .collect::<Vec<_>>();
+        let network = prompt_network(&selected_networks)?;
+        let address = prompt_contract_address(None)?;
+        self.networks
+            .push(ContractImportNetworkSelection::new(network, address));
+        Ok(())
     }
 }
 
-//Constructs SelectedContract via local prompt. Uses abis and manual
-//network/contract config
-async fn get_contract_import_selection(args: ContractImportArgs) -> Result<SelectedContract> {
-    let local_or_explorer_import = get_local_or_explorer_import(&args);
-    let LocalOrExplorerImport::Local(local_import_args) = local_or_explorer_import;
-
-    let abi_path_string =
-        get_abi_path_string(&local_import_args).context("Failed getting Fuel ABI path")?;
-    let abi = FuelAbi::parse(PathBuf::from(&abi_path_string)).context("Failed parsing Fuel ABI")?;
-
+fn get_abi_events(abi: &FuelAbi, all_events: bool) -> Result<Vec<EventConfig>> {
     let mut selected_events: Vec<EventConfig> = abi
         .get_logs()
         .iter()
         .map(|log| EventConfig {
             name: log.event_name.clone(),
             log_id: Some(log.id.clone()),
+            field_selection: None,
             type_: None,
         })
         .collect();
@@ -147,30 +189,112 @@ async fn get_contract_import_selection(args: ContractImportArgs) -> Result<Selec
     selected_events.extend(event_names.iter().map(|&name| EventConfig {
         name: name.to_string(),
         log_id: None,
+        field_selection: None,
         type_: None,
     }));
-    if !args.all_events {
+    if !all_events {
         selected_events = prompt_event_selection(selected_events)?;
     }
+    Ok(selected_events)
+}
 
-    let name = get_contract_name(&local_import_args).context("Failed getting contract name")?;
+//Constructs SelectedContract via local prompt. Uses abis and manual
+//network/contract config
+fn get_contract_import_selection_from_local_import_args(
+    args: &ContractImportArgs,
+    local_import_args: &LocalImportArgs,
+    network: Option<Network>,
+) -> Result<SelectedContract> {
+    let abi_path_string =
+        get_abi_path_string(local_import_args).context("Failed getting Fuel ABI path")?;
+    let abi = FuelAbi::parse(PathBuf::from(&abi_path_string)).context("Failed parsing Fuel ABI")?;
 
-    let choose_from_networks =
-        Select::new("Choose network:", vec![Network::Mainnet, Network::Testnet])
-            .prompt()
-            .context("Failed during prompt for network")?;
+    let selected_events = get_abi_events(&abi, args.all_events)?;
+
+    let name = get_contract_name(local_import_args).context("Failed getting contract name")?;
+
+    let network = match network {
+        Some(network) => network,
+        None => prompt_network(&[])?,
+    };
+
+    let address = get_contract_address(args)?;
+
+    Ok(SelectedContract {
+        name,
+        abi,
+        selected_events,
+        networks: vec![ContractImportNetworkSelection::new(network, address)],
+    })
+}
+
+///Constructs SelectedContract with the ABI fetched from the ABI registry.
+async fn get_contract_import_selection_from_explorer_import_args(
+    args: &ContractImportArgs,
+    explorer_import_args: &ExplorerImportArgs,
+) -> Result<SelectedContract> {
+    let registry_url = get_abi_registry_url(explorer_import_args)?;
+
+    let network = match &explorer_import_args.blockchain {
+        Some(network) => network.clone(),
+        None => prompt_network(&[])?,
+    };
 
-    let addresses = vec![prompt_contract_address(None)?];
+    let address = get_contract_address(args)?;
+
+    let result = match contract_import(&registry_url, &network, &address, 0).await {
+        Ok(ContractImportResult::Contract(contract_data)) => Ok(contract_data),
+        Ok(ContractImportResult::NotFound) => {
+            Err("Failed to find the contract ABI in the registry.".to_string())
+        }
+        Err(e) => Err(format!(
+            "Failed getting the contract ABI with the following error:\n{:?}",
+            e
+        )),
+    };
+
+    let ContractData { abi, name } = match result {
+        Ok(contract_data) => contract_data,
+        Err(err) => {
+            println!("{err}\nUse the Local ABI import option instead.");
+            return get_contract_import_selection_from_local_import_args(
+                &ContractImportArgs {
+                    contract_address: Some(address),
+                    ..args.clone()
+                },
+                &LocalImportArgs::default(),
+                Some(network),
+            );
+        }
+    };
+
+    let selected_events = get_abi_events(&abi, args.all_events)?;
+
+    let name = match name {
+        Some(name) => name,
+        None => prompt_contract_name()?,
+    };
 
     Ok(SelectedContract {
         name,
-        addresses,
         abi,
         selected_events,
-        network: choose_from_networks,
+        networks: vec![ContractImportNetworkSelection::new(network, address)],
     })
 }
 
+async fn get_contract_import_selection(args: ContractImportArgs) -> Result<SelectedContract> {
+    match get_local_or_explorer_import(&args)? {
+        LocalOrExplorerImport::Explorer(explorer_import_args) => {
+            get_contract_import_selection_from_explorer_import_args(&args, &explorer_import_args)
+                .await
+        }
+        LocalOrExplorerImport::Local(local_import_args) => {
+            get_contract_import_selection_from_local_import_args(&args, &local_import_args, None)
+        }
+    }
+}
+
 //Constructs SelectedContract via local prompt. Uses abis and manual
 //network/contract config
 async fn prompt_selected_contracts(args: ContractImportArgs) -> Result<Vec<SelectedContract>> {
@@ -182,7 +306,7 @@ async fn prompt_selected_contracts(args: ContractImportArgs) -> Result<Vec<Selec
         prompt_to_continue_adding(
             &mut contracts,
             || get_contract_import_selection(ContractImportArgs::default()),
-            false,
+            true,
         )
         .await?
     }
@@ -5,9 +5,20 @@ async fn execute_command(
     cmd: &str,
     args: Vec<&str>,
     current_dir: &Path,
+) -> anyhow::Result<std::process::ExitStatus> {
+    execute_command_with_env(cmd, args, current_dir, vec![]).await
+}
+
+///Same as execute_command, but with additional env variables for the child process
+async fn execute_command_with_env(
+    cmd: &str,
+    args: Vec<&str>,
+    current_dir: &Path,
+    envs: Vec<(&str, String)>,
 ) -> anyhow::Result<std::process::ExitStatus> {
     tokio::process::Command::new(cmd)
         .args(&args)
+        .envs(envs)
         .current_dir(current_dir)
         .stdin(std::process::Stdio::null()) //passes null on any stdinprompt
         .kill_on_drop(true) //needed so that dropped threads calling this will also drop
@@ -29,6 +40,35 @@ async fn execute_command(
         ))
 }
 
+///Same as execute_command, but captures the output instead of printing it.
+///Fails when the command exits with an error.
+async fn execute_command_with_output(
+    cmd: &str,
+    args: Vec<&str>,
+    current_dir: &Path,
+) -> anyhow::Result<String> {
+    let output = tokio::process::Command::new(cmd)
+        .args(&args)
+        .current_dir(current_dir)
+        .stdin(std::process::Stdio::null())
+        .kill_on_drop(true)
+        .output()
+        .await
+        .context(format!("Failed to run command {} {}", cmd, args.join(" ")))?;
+
+    if !output.status.success() {
+        return Err(anyhow::anyhow!(
+            "Command {} {} exited with {}: {}",
+            cmd,
+            args.join(" "),
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
 pub mod rescript {
     use super::execute_command;
     use anyhow::Result;
@@ -43,7 +83,11 @@ pub mod rescript {
 pub mod codegen {
     use super::{execute_command, rescript};
     use crate::{
-        config_parsing::system_config::SystemConfig, hbs_templating, template_dirs::TemplateDirs,
+        config_parsing::system_config::SystemConfig,
+        hbs_templating::{
+            self, codegen_manifest::CodegenManifest, templates_override::TemplatesOverride,
+        },
+        template_dirs::TemplateDirs,
     };
     use anyhow::{self, Context, Result};
     use std::path::Path;
@@ -109,41 +153,75 @@ pub mod codegen {
 
     async fn run_post_codegen_command_sequence(
         project_paths: &ParsedProjectPaths,
+        should_install_packages: bool,
     ) -> anyhow::Result<std::process::ExitStatus> {
-        println!("Installing packages... ");
-        let exit1 = pnpm_install(project_paths).await?;
-        if !exit1.success() {
-            return Ok(exit1);
+        if should_install_packages {
+            println!("Installing packages... ");
+            let exit1 = pnpm_install(project_paths).await?;
+            if !exit1.success() {
+                return Ok(exit1);
+            }
+        } else {
+            println!("Generated package.json is unchanged. Skipping package installation");
         }
 
         println!("Generating HyperIndex code...");
         let exit3 = rescript::build(&project_paths.generated)
             .await
             .context("Failed running rescript build")?;
-        if !exit3.success() {
-            return Ok(exit3);
-        }
 
         Ok(exit3)
     }
 
     pub async fn run_codegen(config: &SystemConfig) -> anyhow::Result<()> {
-        let template_dirs = TemplateDirs::new();
-        fs::create_dir_all(&config.parsed_project_paths.generated).await?;
+        let generated_dir = &config.parsed_project_paths.generated;
+        fs::create_dir_all(generated_dir).await?;
 
         let template = hbs_templating::codegen_templates::ProjectTemplate::from_config(config)
             .context("Failed creating project template")?;
 
-        template_dirs
+        let package_json_path = generated_dir.join("package.json");
+        let previous_package_json = fs::read(&package_json_path).await.ok();
+        let previous_manifest = CodegenManifest::read(generated_dir);
+
+        let templates_override = TemplatesOverride::read(&config.parsed_project_paths)
+            .context("Failed reading templates override")?;
+        let shadowed_files = templates_override
+            .as_ref()
+            .map(|templates_override| templates_override.get_shadowed_files(generated_dir))
+            .unwrap_or_default();
+
+        let mut generated_files = TemplateDirs::new()
             .get_codegen_static_dir()?
-            .extract(&config.parsed_project_paths.generated)
+            .extract_except(generated_dir, &shadowed_files)
             .context("Failed extracting static codegen files")?;
 
-        template
-            .generate_templates(&config.parsed_project_paths)
-            .context("Failed generating dynamic codegen files")?;
+        generated_files.extend(
+            template
+                .generate_templates(&config.parsed_project_paths, &shadowed_files)
+                .context("Failed generating dynamic codegen files")?,
+        );
+
+        if let Some(templates_override) = templates_override {
+            generated_files.extend(
+                templates_override
+                    .generate(&template, generated_dir)
+                    .context("Failed generating templates override")?,
+            );
+        }
+
+        let manifest = CodegenManifest::from_generated_files(generated_dir, &generated_files)
+            .context("Failed creating codegen manifest")?;
+        manifest
+            .remove_stale_files(&previous_manifest, generated_dir)
+            .context("Failed removing stale generated files")?;
+        manifest.write(generated_dir)?;
 
-        run_post_codegen_command_sequence(&config.parsed_project_paths)
+        let should_install_packages = previous_package_json.is_none()
+            || previous_package_json != fs::read(&package_json_path).await.ok()
+            || !generated_dir.join("node_modules").exists();
+
+        run_post_codegen_command_sequence(&config.parsed_project_paths, should_install_packages)
             .await
             .context("Failed running post codegen command sequence")?;
 
@@ -152,26 +230,29 @@ pub mod codegen {
 }
 
 pub mod start {
-    use super::execute_command;
+    use super::execute_command_with_env;
     use crate::config_parsing::system_config::SystemConfig;
     use anyhow::anyhow;
 
     pub async fn start_indexer(
         config: &SystemConfig,
-        should_open_hasura: bool,
+        env_vars: Vec<(&str, String)>,
+        hasura_console_url: Option<&str>,
     ) -> anyhow::Result<()> {
-        if should_open_hasura {
-            println!("Opening Hasura console at http://localhost:8080 ...");
-            if open::that_detached("http://localhost:8080").is_err() {
+        if let Some(hasura_url) = hasura_console_url {
+            println!("Opening Hasura console at {hasura_url} ...");
+            if open::that_detached(hasura_url).is_err() {
                 println!(
-                    "Unable to open http://localhost:8080 in your browser automatically for you. \
-                     You can open that link yourself to view hasura"
+                    "Unable to open {hasura_url} in your browser automatically for you. You can \
+                     open that link yourself to view hasura"
                 );
             }
         }
         let cmd = "npm";
         let args = vec!["run", "start"];
-        let exit = execute_command(cmd, args, &config.parsed_project_paths.generated).await?;
+        let exit =
+            execute_command_with_env(cmd, args, &config.parsed_project_paths.generated, env_vars)
+                .await?;
 
         if !exit.success() {
             return Err(anyhow!(
@@ -187,26 +268,28 @@ pub mod start {
     }
 }
 pub mod docker {
-    use super::execute_command;
-    use crate::config_parsing::system_config::SystemConfig;
+    use super::execute_command_with_env;
+    use crate::config_parsing::{local_env_config::LocalEnvConfig, system_config::SystemConfig};
 
     pub async fn docker_compose_up_d(
         config: &SystemConfig,
+        local_env_config: &LocalEnvConfig,
     ) -> anyhow::Result<std::process::ExitStatus> {
         let cmd = "docker";
         let args = vec!["compose", "up", "-d"];
         let current_dir = &config.parsed_project_paths.generated;
 
-        execute_command(cmd, args, current_dir).await
+        execute_command_with_env(cmd, args, current_dir, local_env_config.get_env_vars()).await
     }
     pub async fn docker_compose_down_v(
         config: &SystemConfig,
+        local_env_config: &LocalEnvConfig,
     ) -> anyhow::Result<std::process::ExitStatus> {
         let cmd = "docker";
         let args = vec!["compose", "down", "-v"];
         let current_dir = &config.parsed_project_paths.generated;
 
-        execute_command(cmd, args, current_dir).await
+        execute_command_with_env(cmd, args, current_dir, local_env_config.get_env_vars()).await
     }
 }
 
@@ -215,48 +298,60 @@ pub mod db_migrate {
 
     use std::process::ExitStatus;
 
-    use super::execute_command;
-    use crate::{config_parsing::system_config::SystemConfig, persisted_state::PersistedState};
+    use super::execute_command_with_env;
+    use crate::{
+        config_parsing::{local_env_config::LocalEnvConfig, system_config::SystemConfig},
+        persisted_state::PersistedState,
+    };
 
     pub async fn run_up_migrations(
         config: &SystemConfig,
+        local_env_config: &LocalEnvConfig,
         persisted_state: &PersistedState,
     ) -> anyhow::Result<()> {
         let args = vec!["db-up"];
         let current_dir = &config.parsed_project_paths.generated;
-        let exit = execute_command("pnpm", args, current_dir).await?;
+        let exit =
+            execute_command_with_env("pnpm", args, current_dir, local_env_config.get_env_vars())
+                .await?;
 
         if !exit.success() {
             return Err(anyhow!("Failed to run db migrations"));
         }
 
         persisted_state
-            .upsert_to_db()
+            .upsert_to_db(local_env_config)
             .await
             .context("Failed to upsert persisted state table")?;
         Ok(())
     }
 
-    pub async fn run_drop_schema(config: &SystemConfig) -> anyhow::Result<ExitStatus> {
+    pub async fn run_drop_schema(
+        config: &SystemConfig,
+        local_env_config: &LocalEnvConfig,
+    ) -> anyhow::Result<ExitStatus> {
         let args = vec!["db-down"];
         let current_dir = &config.parsed_project_paths.generated;
-        execute_command("pnpm", args, current_dir).await
+        execute_command_with_env("pnpm", args, current_dir, local_env_config.get_env_vars()).await
     }
 
     pub async fn run_db_setup(
         config: &SystemConfig,
+        local_env_config: &LocalEnvConfig,
         persisted_state: &PersistedState,
     ) -> anyhow::Result<()> {
         let args = vec!["db-setup"];
         let current_dir = &config.parsed_project_paths.generated;
-        let exit = execute_command("pnpm", args, current_dir).await?;
+        let exit =
+            execute_command_with_env("pnpm", args, current_dir, local_env_config.get_env_vars())
+                .await?;
 
         if !exit.success() {
             return Err(anyhow!("Failed to run db migrations"));
         }
 
         persisted_state
-            .upsert_to_db()
+            .upsert_to_db(local_env_config)
             .await
             .context("Failed to upsert persisted state table")?;
         Ok(())
@@ -280,3 +375,26 @@ pub mod benchmark {
         Ok(())
     }
 }
+
+pub mod doctor {
+    use super::execute_command_with_output;
+    use anyhow::Result;
+    use std::path::Path;
+
+    pub async fn get_node_version(current_dir: &Path) -> Result<String> {
+        execute_command_with_output("node", vec!["--version"], current_dir).await
+    }
+
+    pub async fn get_npm_version(current_dir: &Path) -> Result<String> {
+        execute_command_with_output("npm", vec!["--version"], current_dir).await
+    }
+
+    pub async fn get_pnpm_version(current_dir: &Path) -> Result<String> {
+        execute_command_with_output("pnpm", vec!["--version"], current_dir).await
+    }
+
+    pub async fn get_docker_compose_version(current_dir: &Path) -> Result<String> {
+        execute_command_with_output("docker", vec!["compose", "version", "--short"], current_dir)
+            .await
+    }
+}
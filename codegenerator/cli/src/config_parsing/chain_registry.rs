@@ -0,0 +1,323 @@
+use super::chain_helpers::ChainTier;
+use crate::constants::DEFAULT_CONFIRMED_BLOCK_THRESHOLD;
+use anyhow::{anyhow, Context, Result};
+use itertools::Itertools;
+use serde::Deserialize;
+use std::{env, fmt, fs, sync::OnceLock};
+
+///Chain metadata bundled with the CLI. Kept sorted by name.
+const BUNDLED_CHAINS_JSON: &str = include_str!("chains.json");
+
+///Path to a json file in the same format as the bundled chains.json.
+///Entries override bundled chains with the same id, new ids are added.
+pub const CHAINS_FILE_ENV_VAR: &str = "ENVIO_CHAINS_FILE";
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ChainMetadata {
+    pub id: u64,
+    pub name: String,
+    #[serde(default)]
+    pub tier: Option<ChainTier>,
+    ///When set, the chain is indexed with HyperSync by default
+    #[serde(default)]
+    pub hypersync_url: Option<String>,
+    ///When set, contracts on the chain can be imported from a block explorer
+    #[serde(default)]
+    pub explorer_url: Option<String>,
+    #[serde(default)]
+    pub confirmed_block_threshold: Option<i32>,
+    ///The last block of a chain which doesn't produce blocks anymore
+    #[serde(default)]
+    pub finite_end_block: Option<u64>,
+    ///The network name used in subgraph manifests
+    #[serde(default)]
+    pub graph_name: Option<String>,
+}
+
+impl ChainMetadata {
+    pub fn get_confirmed_block_threshold(&self) -> i32 {
+        self.confirmed_block_threshold
+            .unwrap_or(DEFAULT_CONFIRMED_BLOCK_THRESHOLD)
+    }
+
+    pub fn get_pretty_name(&self) -> String {
+        match &self.tier {
+            Some(tier) => format!("{} {}", self.name, tier.get_icon()),
+            None => self.name.clone(),
+        }
+    }
+}
+
+impl fmt::Display for ChainMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.get_pretty_name())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainRegistry {
+    chains: Vec<ChainMetadata>,
+}
+
+impl ChainRegistry {
+    fn parse_chains(json: &str) -> Result<Vec<ChainMetadata>> {
+        let chains: Vec<ChainMetadata> = serde_json::from_str(json)?;
+        if let Some(id) = chains.iter().map(|chain| chain.id).duplicates().next() {
+            return Err(anyhow!("The chain id {} is defined more than once", id));
+        }
+        Ok(chains)
+    }
+
+    fn new(mut chains: Vec<ChainMetadata>) -> Result<Self> {
+        if let Some(name) = chains.iter().map(|chain| &chain.name).duplicates().next() {
+            return Err(anyhow!(
+                "The chain name \"{}\" is used by more than one chain id",
+                name
+            ));
+        }
+        chains.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(Self { chains })
+    }
+
+    pub fn bundled() -> Result<Self> {
+        let chains = Self::parse_chains(BUNDLED_CHAINS_JSON)
+            .context("Failed parsing bundled chains.json")?;
+        Self::new(chains)
+    }
+
+    ///Replaces chains with the same id and adds the new ones
+    pub fn extend_from_json(self, json: &str) -> Result<Self> {
+        let overrides = Self::parse_chains(json)?;
+        let mut chains: Vec<ChainMetadata> = self
+            .chains
+            .into_iter()
+            .filter(|chain| !overrides.iter().any(|o| o.id == chain.id))
+            .collect();
+        chains.extend(overrides);
+        Self::new(chains)
+    }
+
+    ///Bundled chains extended with the file from ENVIO_CHAINS_FILE if it's set
+    pub fn load() -> Result<Self> {
+        let registry = Self::bundled()?;
+        match env::var(CHAINS_FILE_ENV_VAR) {
+            Ok(path) => {
+                let json = fs::read_to_string(&path).context(format!(
+                    "EE119: Failed reading the chains file at {path} (set by \
+                     {CHAINS_FILE_ENV_VAR})"
+                ))?;
+                registry.extend_from_json(&json).context(format!(
+                    "EE119: Invalid chains file at {path} (set by {CHAINS_FILE_ENV_VAR})"
+                ))
+            }
+            Err(_) => Ok(registry),
+        }
+    }
+
+    ///The registry shared by the whole process. Loaded on first use.
+    pub fn global() -> Result<&'static Self> {
+        static REGISTRY: OnceLock<ChainRegistry> = OnceLock::new();
+        if let Some(registry) = REGISTRY.get() {
+            return Ok(registry);
+        }
+        let registry = Self::load()?;
+        Ok(REGISTRY.get_or_init(|| registry))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ChainMetadata> {
+        self.chains.iter()
+    }
+
+    pub fn iter_hypersync_chains(&self) -> impl Iterator<Item = &ChainMetadata> {
+        self.iter().filter(|chain| chain.hypersync_url.is_some())
+    }
+
+    pub fn iter_explorer_chains(&self) -> impl Iterator<Item = &ChainMetadata> {
+        self.iter().filter(|chain| chain.explorer_url.is_some())
+    }
+
+    pub fn get_by_id(&self, id: u64) -> Option<&ChainMetadata> {
+        self.iter().find(|chain| chain.id == id)
+    }
+
+    pub fn get_by_name(&self, name: &str) -> Option<&ChainMetadata> {
+        self.iter().find(|chain| chain.name == name)
+    }
+
+    pub fn get_by_graph_name(&self, graph_name: &str) -> Option<&ChainMetadata> {
+        self.iter()
+            .find(|chain| chain.graph_name.as_deref() == Some(graph_name))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ChainRegistry, BUNDLED_CHAINS_JSON};
+    use crate::config_parsing::chain_helpers::ChainTier;
+    use itertools::Itertools;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn bundled_chains_are_defined_in_alphabetical_order() {
+        let names = ChainRegistry::parse_chains(BUNDLED_CHAINS_JSON)
+            .unwrap()
+            .into_iter()
+            .map(|chain| chain.name)
+            .collect::<Vec<_>>();
+        let names_sorted = names.iter().cloned().sorted().collect::<Vec<_>>();
+        assert_eq!(
+            names_sorted, names,
+            "Chains should be defined in alphabetical order (sorry to be picky)"
+        );
+    }
+
+    #[test]
+    fn bundled_chain_names_are_kebab_case() {
+        let registry = ChainRegistry::bundled().unwrap();
+        for chain in registry.iter() {
+            assert!(
+                chain
+                    .name
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-'),
+                "Invalid chain name {}",
+                chain.name
+            );
+        }
+    }
+
+    #[test]
+    fn bundled_chain_metadata() {
+        let registry = ChainRegistry::bundled().unwrap();
+
+        let mainnet = registry.get_by_name("ethereum-mainnet").unwrap();
+        assert_eq!(mainnet.id, 1);
+        assert_eq!(mainnet.tier, Some(ChainTier::Gold));
+        assert_eq!(
+            mainnet.hypersync_url.as_deref(),
+            Some("https://1.hypersync.xyz")
+        );
+        assert_eq!(mainnet.graph_name.as_deref(), Some("mainnet"));
+        assert_eq!(
+            mainnet.get_confirmed_block_threshold(),
+            96,
+            "Should cover the lag of the finalized block"
+        );
+        assert_eq!(mainnet.to_string(), "ethereum-mainnet 🥇");
+
+        let optimism = registry.get_by_id(10).unwrap();
+        assert_eq!(optimism.get_confirmed_block_threshold(), 0);
+
+        let goerli = registry.get_by_name("goerli").unwrap();
+        assert_eq!(goerli.finite_end_block, Some(10_387_962));
+        assert_eq!(goerli.hypersync_url, None);
+        assert_eq!(goerli.to_string(), "goerli");
+    }
+
+    #[test]
+    fn extends_bundled_chains() {
+        let registry = ChainRegistry::bundled()
+            .unwrap()
+            .extend_from_json(
+                r#"[
+                    {"id": 1, "name": "ethereum-mainnet", "hypersync_url": "https://eth.example.com"},
+                    {"id": 123456, "name": "my-private-chain", "confirmed_block_threshold": 5}
+                ]"#,
+            )
+            .unwrap();
+
+        let mainnet = registry.get_by_id(1).unwrap();
+        assert_eq!(
+            mainnet.hypersync_url.as_deref(),
+            Some("https://eth.example.com")
+        );
+        assert_eq!(mainnet.tier, None, "Overrides replace the whole entry");
+
+        let private_chain = registry.get_by_name("my-private-chain").unwrap();
+        assert_eq!(private_chain.id, 123456);
+        assert_eq!(private_chain.get_confirmed_block_threshold(), 5);
+        assert_eq!(registry.iter().count(), 122);
+        assert!(registry
+            .iter()
+            .map(|chain| &chain.name)
+            .tuple_windows()
+            .all(|(a, b)| a < b));
+    }
+
+    #[test]
+    fn fails_to_extend_with_conflicting_chains() {
+        let bundled = ChainRegistry::bundled().unwrap();
+        assert!(bundled
+            .clone()
+            .extend_from_json(r#"[{"id": 123456, "name": "ethereum-mainnet"}]"#)
+            .is_err());
+        assert!(bundled
+            .clone()
+            .extend_from_json(r#"[{"id": 123456, "name": "a"}, {"id": 123456, "name": "b"}]"#)
+            .is_err());
+        assert!(bundled
+            .extend_from_json(r#"[{"id": 123456, "name": "a", "rpc": "http://localhost"}]"#)
+            .is_err());
+    }
+
+    #[test]
+    fn graph_names() {
+        /*List of networks supported by graph found here:
+         * https://github.com/graphprotocol/graph-tooling/blob/main/packages/cli/src/protocols/index.ts#L94-L132
+         */
+        let supported_graph_networks = vec![
+            "mainnet",
+            "rinkeby",
+            "goerli",
+            "poa-core",
+            "poa-sokol",
+            "gnosis",
+            "matic",
+            "mumbai",
+            "fantom",
+            "fantom-testnet",
+            "bsc",
+            "chapel",
+            "clover",
+            "avalanche",
+            "fuji",
+            "celo",
+            "celo-alfajores",
+            "fuse",
+            "moonbeam",
+            "moonriver",
+            "mbase",
+            "arbitrum-one",
+            "arbitrum-goerli",
+            "arbitrum-sepolia",
+            "optimism",
+            "optimism-goerli",
+            "aurora",
+            "aurora-testnet",
+            "base-testnet",
+            "base",
+            "zksync-era",
+            "zksync-era-testnet",
+            "sepolia",
+            "polygon-zkevm-testnet",
+            "polygon-zkevm",
+            "scroll-sepolia",
+            "scroll",
+        ];
+
+        let registry = ChainRegistry::bundled().unwrap();
+
+        assert_eq!(registry.get_by_graph_name("matic").unwrap().id, 137);
+
+        for chain in registry.iter() {
+            if let Some(graph_name) = &chain.graph_name {
+                assert!(
+                    supported_graph_networks.contains(&graph_name.as_str()),
+                    "expected {} to be included",
+                    graph_name
+                )
+            }
+        }
+    }
+}
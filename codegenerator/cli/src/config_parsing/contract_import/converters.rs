@@ -1,4 +1,4 @@
-use crate::{config_parsing::chain_helpers::HypersyncNetwork, evm::address::Address};
+use crate::{config_parsing::chain_registry::ChainMetadata, evm::address::Address};
 use anyhow::{Context, Result};
 use std::fmt::{self, Display};
 
@@ -50,7 +50,7 @@ impl SelectedContract {
 
 #[derive(Clone, Debug)]
 pub enum NetworkKind {
-    Supported(HypersyncNetwork),
+    Supported(ChainMetadata),
     Unsupported {
         network_id: u64,
         rpc_url: String,
@@ -61,7 +61,7 @@ pub enum NetworkKind {
 impl NetworkKind {
     pub fn get_network_id(&self) -> u64 {
         match self {
-            Self::Supported(n) => *n as u64,
+            Self::Supported(chain) => chain.id,
             Self::Unsupported { network_id, .. } => *network_id,
         }
     }
@@ -82,7 +82,7 @@ impl NetworkKind {
 impl Display for NetworkKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self {
-            Self::Supported(n) => write!(f, "{}", n),
+            Self::Supported(chain) => write!(f, "{}", chain),
             Self::Unsupported { network_id, .. } => write!(f, "{}", network_id),
         }
     }
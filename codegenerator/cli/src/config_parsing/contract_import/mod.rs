@@ -3,8 +3,7 @@ pub mod converters;
 use std::env;
 
 use crate::{
-    cli_args::interactive_init::validation::filter_duplicate_events,
-    config_parsing::chain_helpers::NetworkWithExplorer, evm::address::Address,
+    cli_args::interactive_init::validation::filter_duplicate_events, evm::address::Address,
 };
 use anyhow::{anyhow, Context};
 use async_recursion::async_recursion;
@@ -39,14 +38,14 @@ enum ContractImportResponse {
 
 #[async_recursion]
 pub async fn contract_import(
-    network: &NetworkWithExplorer,
+    chain_id: u64,
     address: &Address,
     retry: u64,
 ) -> anyhow::Result<ContractImportResult> {
     let api_url = env::var("ENVIO_API_URL").unwrap_or("https://envio.dev/api".to_string());
     let response: reqwest::Response = match reqwest::get(format!(
         "{api_url}/hyperindex/contract-import?chain={}&address={}",
-        *network as u64,
+        chain_id,
         address.to_checksum_hex_string()
     ))
     .await
@@ -58,7 +57,7 @@ pub async fn contract_import(
                 return Err(anyhow!("Failed to fetch contract import. {}", err));
             }
             tokio::time::sleep(Duration::from_secs(2)).await;
-            return contract_import(network, address, retry + 1).await;
+            return contract_import(chain_id, address, retry + 1).await;
         }
     };
 
@@ -0,0 +1,323 @@
+use super::human_config::evm::Headers;
+use anyhow::{anyhow, Context, Result};
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::json;
+use std::{
+    fmt,
+    future::Future,
+    time::{Duration, Instant},
+};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndpointStatus {
+    ///None when the endpoint doesn't expose the chain id
+    pub chain_id: Option<u64>,
+    pub head_block: u64,
+    ///Round trip of the head block request
+    pub latency: Duration,
+}
+
+impl EndpointStatus {
+    ///Fails when the endpoint serves a different chain than the configured one
+    pub fn check_chain_id(&self, expected_chain_id: u64) -> Result<()> {
+        match self.chain_id {
+            Some(chain_id) if chain_id != expected_chain_id => Err(anyhow!(
+                "The endpoint serves the chain id {} instead of the configured {}",
+                chain_id,
+                expected_chain_id
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for EndpointStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(chain_id) = self.chain_id {
+            write!(f, "chain id {}, ", chain_id)?;
+        }
+        write!(
+            f,
+            "head block {}, latency {}ms",
+            self.head_block,
+            self.latency.as_millis()
+        )
+    }
+}
+
+///The outcome of checking a single endpoint of a network
+#[derive(Debug)]
+pub struct EndpointCheck {
+    pub network_id: u64,
+    ///The kind of the endpoint and its url. Eg. "RPC https://eth.llamarpc.com"
+    pub name: String,
+    pub result: Result<EndpointStatus>,
+}
+
+impl EndpointCheck {
+    pub async fn run(
+        network_id: u64,
+        name: String,
+        status: impl Future<Output = Result<EndpointStatus>>,
+    ) -> Self {
+        let result = status.await.and_then(|status| {
+            status.check_chain_id(network_id)?;
+            Ok(status)
+        });
+        EndpointCheck {
+            network_id,
+            name,
+            result,
+        }
+    }
+}
+
+impl fmt::Display for EndpointCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Network {} {}: ", self.network_id, self.name)?;
+        match &self.result {
+            Ok(status) => write!(f, "{}", status),
+            Err(err) => write!(f, "{:#}", err),
+        }
+    }
+}
+
+fn make_client() -> Result<reqwest::Client> {
+    reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .context("Failed creating http client")
+}
+
+async fn get_json<T: DeserializeOwned>(request: reqwest::RequestBuilder) -> Result<T> {
+    let response = request.send().await.context("Failed sending request")?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(anyhow!("Request failed with status {}", status));
+    }
+    response.json().await.context("Failed parsing response")
+}
+
+#[derive(Deserialize)]
+struct JsonRpcError {
+    code: i64,
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct JsonRpcResponse {
+    result: Option<String>,
+    error: Option<JsonRpcError>,
+}
+
+fn parse_hex_quantity(value: &str) -> Result<u64> {
+    let digits = value
+        .strip_prefix("0x")
+        .ok_or_else(|| anyhow!("Expected a hex quantity, got \"{}\"", value))?;
+    u64::from_str_radix(digits, 16).context(format!("Invalid hex quantity \"{}\"", value))
+}
+
+async fn call_rpc(
+    client: &reqwest::Client,
+    url: &str,
+    headers: Option<&Headers>,
+    method: &str,
+) -> Result<u64> {
+    let mut request = client.post(url).json(&json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": method,
+        "params": [],
+    }));
+    for (name, value) in headers.into_iter().flatten() {
+        request = request.header(name, value);
+    }
+    let response: JsonRpcResponse = get_json(request)
+        .await
+        .context(format!("Failed calling {}", method))?;
+    match (response.result, response.error) {
+        (_, Some(error)) => Err(anyhow!(
+            "{} returned the error {}: {}",
+            method,
+            error.code,
+            error.message
+        )),
+        (Some(result), None) => parse_hex_quantity(&result),
+        (None, None) => Err(anyhow!("{} returned an empty result", method)),
+    }
+}
+
+///Requests the chain id and the latest block number from an EVM RPC
+pub async fn check_rpc(url: &str, headers: Option<&Headers>) -> Result<EndpointStatus> {
+    let client = make_client()?;
+    let chain_id = call_rpc(&client, url, headers, "eth_chainId").await?;
+    let started_at = Instant::now();
+    let head_block = call_rpc(&client, url, headers, "eth_blockNumber").await?;
+    Ok(EndpointStatus {
+        chain_id: Some(chain_id),
+        head_block,
+        latency: started_at.elapsed(),
+    })
+}
+
+#[derive(Deserialize)]
+struct HypersyncChainId {
+    chain_id: u64,
+}
+
+#[derive(Deserialize)]
+struct HypersyncHeight {
+    height: u64,
+}
+
+fn hypersync_request(
+    client: &reqwest::Client,
+    url: &str,
+    path: &str,
+    api_token: Option<&str>,
+) -> reqwest::RequestBuilder {
+    let request = client.get(format!("{}/{}", url.trim_end_matches('/'), path));
+    match api_token {
+        Some(api_token) => request.bearer_auth(api_token),
+        None => request,
+    }
+}
+
+///Requests the chain id and the height from a HyperSync endpoint
+pub async fn check_hypersync(url: &str, api_token: Option<&str>) -> Result<EndpointStatus> {
+    let client = make_client()?;
+    let chain_id: HypersyncChainId =
+        get_json(hypersync_request(&client, url, "chain_id", api_token))
+            .await
+            .context("Failed getting the chain id")?;
+    let started_at = Instant::now();
+    let height: HypersyncHeight = get_json(hypersync_request(&client, url, "height", api_token))
+        .await
+        .context("Failed getting the height")?;
+    Ok(EndpointStatus {
+        chain_id: Some(chain_id.chain_id),
+        head_block: height.height,
+        latency: started_at.elapsed(),
+    })
+}
+
+///HyperFuel doesn't expose a chain id, so only the height is requested
+pub async fn check_hyperfuel(url: &str) -> Result<EndpointStatus> {
+    let client = make_client()?;
+    let started_at = Instant::now();
+    let height: HypersyncHeight = get_json(hypersync_request(&client, url, "height", None))
+        .await
+        .context("Failed getting the height")?;
+    Ok(EndpointStatus {
+        chain_id: None,
+        head_block: height.height,
+        latency: started_at.elapsed(),
+    })
+}
+
+fn parse_fuel_core_height(response: &serde_json::Value) -> Result<u64> {
+    if let Some(message) = response
+        .pointer("/errors/0/message")
+        .and_then(|message| message.as_str())
+    {
+        return Err(anyhow!("The query returned the error: {}", message));
+    }
+    response
+        .pointer("/data/chain/latestBlock/height")
+        .and_then(|height| height.as_str())
+        .ok_or_else(|| anyhow!("The response doesn't have the latest block height"))?
+        .parse()
+        .context("Invalid latest block height")
+}
+
+///Requests the latest block height from a fuel-core GraphQL API.
+///Custom Fuel networks can use any id, so the chain id isn't compared
+pub async fn check_fuel_core(url: &str) -> Result<EndpointStatus> {
+    let client = make_client()?;
+    let started_at = Instant::now();
+    let response: serde_json::Value = get_json(client.post(url).json(&json!({
+        "query": "query { chain { latestBlock { height } } }",
+    })))
+    .await
+    .context("Failed getting the latest block")?;
+    Ok(EndpointStatus {
+        chain_id: None,
+        head_block: parse_fuel_core_height(&response)?,
+        latency: started_at.elapsed(),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_fuel_core_height, parse_hex_quantity, EndpointCheck, EndpointStatus};
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[test]
+    fn parses_hex_quantities() {
+        assert_eq!(parse_hex_quantity("0x1").unwrap(), 1);
+        assert_eq!(parse_hex_quantity("0x1312d00").unwrap(), 20_000_000);
+        assert!(parse_hex_quantity("1").is_err());
+        assert!(parse_hex_quantity("0xzz").is_err());
+    }
+
+    #[test]
+    fn parses_fuel_core_height() {
+        assert_eq!(
+            parse_fuel_core_height(&json!({
+                "data": {"chain": {"latestBlock": {"height": "1024"}}}
+            }))
+            .unwrap(),
+            1024
+        );
+        assert!(parse_fuel_core_height(&json!({
+            "data": null,
+            "errors": [{"message": "Unknown field"}]
+        }))
+        .is_err());
+        assert!(parse_fuel_core_height(&json!({"data": {"chain": null}})).is_err());
+    }
+
+    #[test]
+    fn checks_chain_id() {
+        let status = |chain_id| EndpointStatus {
+            chain_id,
+            head_block: 100,
+            latency: Duration::from_millis(10),
+        };
+        assert!(status(Some(1)).check_chain_id(1).is_ok());
+        assert!(status(Some(10)).check_chain_id(1).is_err());
+        assert!(status(None).check_chain_id(1).is_ok());
+    }
+
+    #[tokio::test]
+    async fn reports_endpoint_checks() {
+        let status = EndpointStatus {
+            chain_id: Some(10),
+            head_block: 100,
+            latency: Duration::from_millis(25),
+        };
+
+        let check = EndpointCheck::run(10, "RPC https://rpc.example.com".to_string(), async {
+            Ok(status.clone())
+        })
+        .await;
+        assert_eq!(check.result.as_ref().unwrap(), &status);
+        assert_eq!(
+            check.to_string(),
+            "Network 10 RPC https://rpc.example.com: chain id 10, head block 100, latency 25ms"
+        );
+
+        let check = EndpointCheck::run(1, "RPC https://rpc.example.com".to_string(), async {
+            Ok(status.clone())
+        })
+        .await;
+        assert_eq!(
+            check.to_string(),
+            "Network 1 RPC https://rpc.example.com: The endpoint serves the chain id 10 instead \
+             of the configured 1"
+        );
+    }
+}
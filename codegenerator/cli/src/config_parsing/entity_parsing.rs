@@ -9,7 +9,10 @@ use crate::{
     constants::project_paths::DEFAULT_SCHEMA_PATH,
     hbs_templating::codegen_templates::DerivedFieldTemplate,
     project_paths::{path_utils, ParsedProjectPaths},
-    rescript_types::{RescriptSchemaMode, RescriptTypeIdent},
+    rescript_types::{
+        RescriptRecordField, RescriptSchemaMode, RescriptTypeDecl, RescriptTypeExpr,
+        RescriptTypeIdent,
+    },
     utils::{text::Capitalize, unique_hashmap},
 };
 use anyhow::{anyhow, Context};
@@ -31,11 +34,13 @@ use subenum::subenum;
 pub struct Schema {
     pub entities: HashMap<String, Entity>,
     pub enums: HashMap<String, GraphQLEnum>,
+    pub embedded_types: HashMap<String, EmbeddedType>,
 }
 
 enum TypeDef<'a> {
     Entity(&'a Entity),
     Enum,
+    Embedded(&'a EmbeddedType),
 }
 
 impl Schema {
@@ -43,10 +48,15 @@ impl Schema {
         Schema {
             entities: HashMap::new(),
             enums: HashMap::new(),
+            embedded_types: HashMap::new(),
         }
     }
 
-    pub fn new(entities: Vec<Entity>, enums: Vec<GraphQLEnum>) -> anyhow::Result<Self> {
+    pub fn new(
+        entities: Vec<Entity>,
+        enums: Vec<GraphQLEnum>,
+        embedded_types: Vec<EmbeddedType>,
+    ) -> anyhow::Result<Self> {
         let entities = unique_hashmap::from_vec_no_duplicates(
             entities.into_iter().map(|e| (e.name.clone(), e)).collect(),
         )
@@ -55,12 +65,24 @@ impl Schema {
             enums.into_iter().map(|e| (e.name.clone(), e)).collect(),
         )
         .context("Found enums with duplicate names")?;
+        let embedded_types = unique_hashmap::from_vec_no_duplicates(
+            embedded_types
+                .into_iter()
+                .map(|e| (e.name.clone(), e))
+                .collect(),
+        )
+        .context("Found embedded types with duplicate names")?;
 
-        Self { entities, enums }.validate()
+        Self {
+            entities,
+            enums,
+            embedded_types,
+        }
+        .validate()
     }
 
     fn from_document(document: Document<String>) -> anyhow::Result<Self> {
-        let entities = document
+        let (embedded_objects, entity_objects): (Vec<_>, Vec<_>) = document
             .definitions
             .iter()
             .filter_map(|d| match d {
@@ -71,10 +93,20 @@ impl Schema {
                 TypeDefinition::Object(obj) => Some(obj),
                 _ => None,
             })
+            .partition(|obj| EmbeddedType::is_embedded_object(obj));
+
+        let entities = entity_objects
+            .into_iter()
             .map(|obj| Entity::from_object(obj))
             .collect::<anyhow::Result<Vec<Entity>>>()
             .context("Failed constructing entities in schema from document")?;
 
+        let embedded_types = embedded_objects
+            .into_iter()
+            .map(|obj| EmbeddedType::from_object(obj))
+            .collect::<anyhow::Result<Vec<EmbeddedType>>>()
+            .context("Failed constructing embedded types in schema from document")?;
+
         let enums = document
             .definitions
             .iter()
@@ -90,7 +122,7 @@ impl Schema {
             .collect::<anyhow::Result<Vec<GraphQLEnum>>>()
             .context("Failed constructing enums in schema from document")?;
 
-        Self::new(entities, enums)
+        Self::new(entities, enums, embedded_types)
     }
 
     pub fn parse_from_file(
@@ -124,8 +156,11 @@ impl Schema {
         self.check_enum_type_defs()?
             .check_schema_for_reserved_words()?
             .check_duplicate_naming_between_enums_and_entities()?
+            .check_duplicate_naming_of_embedded_types()?
             .check_related_type_defs_exist()?
-            .validate_entity_field_types()
+            .check_full_text_index_names()?
+            .validate_entity_field_types()?
+            .validate_embedded_types()
     }
 
     fn get_all_enum_type_names(&self) -> Vec<String> {
@@ -137,11 +172,18 @@ impl Schema {
     fn get_all_entity_type_names(&self) -> Vec<String> {
         self.entities.keys().cloned().collect()
     }
+    fn get_all_embedded_type_names(&self) -> Vec<String> {
+        self.embedded_types.keys().cloned().collect()
+    }
     fn get_all_entity_field_names(&self) -> Vec<String> {
         self.entities
             .values()
-            .flat_map(|v| v.fields.values())
-            .map(|v| v.name.clone())
+            .flat_map(|v| {
+                v.fields
+                    .values()
+                    .map(|f| f.name.clone())
+                    .chain(v.computed_fields.iter().map(|f| f.name.clone()))
+            })
             .collect()
     }
 
@@ -161,6 +203,7 @@ impl Schema {
             self.get_all_enum_values(),
             self.get_all_entity_type_names(),
             self.get_all_entity_field_names(),
+            self.get_all_embedded_type_names(),
         ]
         .concat();
 
@@ -190,15 +233,66 @@ impl Schema {
         }
     }
 
+    fn check_duplicate_naming_of_embedded_types(self) -> anyhow::Result<Self> {
+        let duplicate_names = self
+            .get_all_embedded_type_names()
+            .into_iter()
+            .filter(|k| self.entities.contains_key(k) || self.enums.contains_key(k))
+            .sorted()
+            .collect::<Vec<_>>();
+        if !duplicate_names.is_empty() {
+            Err(anyhow!(
+                "EE214: Schema contains the following embedded types with the same name as an \
+                 enum or entity, all type definitions must be unique in the schema: {}",
+                duplicate_names.join(", ")
+            ))
+        } else {
+            Ok(self)
+        }
+    }
+
+    /// Full text indexes are exposed as root level search functions in Hasura,
+    /// so their names should be unique across the schema
+    fn check_full_text_index_names(self) -> anyhow::Result<Self> {
+        let mut names = HashSet::new();
+        for entity in self.entities.values().sorted_by_key(|e| &e.name) {
+            for full_text_index in &entity.full_text_indexes {
+                if self.entities.contains_key(&full_text_index.name)
+                    || !names.insert(&full_text_index.name)
+                {
+                    return Err(anyhow!(
+                        "EE219: The @fulltext name '{}' on entity '{}' is already used by another \
+                         entity or full text index. Please use a unique name.",
+                        full_text_index.name,
+                        entity.name
+                    ));
+                }
+            }
+        }
+        Ok(self)
+    }
+
+    /// Whether an entity, enum or embedded type with the name is defined in the schema
+    pub fn has_type_def(&self, name: &str) -> bool {
+        self.entities.contains_key(name)
+            || self.enums.contains_key(name)
+            || self.embedded_types.contains_key(name)
+    }
+
     fn try_get_type_def(&self, name: &String) -> anyhow::Result<TypeDef> {
-        match (self.entities.get(name), self.enums.get(name)) {
-            (None, None) => Err(anyhow!("No type definition '{}' exists in schema", name)),
-            (Some(_), Some(_)) => Err(anyhow!(
-                "Both an enum and an entity type definition '{}' exist in schema",
+        match (
+            self.entities.get(name),
+            self.enums.get(name),
+            self.embedded_types.get(name),
+        ) {
+            (None, None, None) => Err(anyhow!("No type definition '{}' exists in schema", name)),
+            (Some(entity), None, None) => Ok(TypeDef::Entity(entity)),
+            (None, Some(_), None) => Ok(TypeDef::Enum),
+            (None, None, Some(embedded_type)) => Ok(TypeDef::Embedded(embedded_type)),
+            _ => Err(anyhow!(
+                "Multiple type definitions '{}' exist in schema",
                 name
             )),
-            (Some(entity), None) => Ok(TypeDef::Entity(entity)),
-            (None, Some(_)) => Ok(TypeDef::Enum),
         }
     }
 
@@ -220,6 +314,11 @@ impl Schema {
                                 "Cannot derive field {derived_from_field} from enum {name}. \
                                  derivedFrom is intended to be used with Entity type definitions"
                             ))?,
+                            TypeDef::Embedded(_) => Err(anyhow!(
+                                "Cannot derive field {derived_from_field} from embedded type \
+                                 {name}. derivedFrom is intended to be used with Entity type \
+                                 definitions"
+                            ))?,
                             TypeDef::Entity(derived_entity) => {
                                 match derived_entity.fields.get(derived_from_field) {
                                     None => Err(anyhow!(
@@ -257,6 +356,24 @@ impl Schema {
         }
         Ok(self)
     }
+
+    /// Embedded types can only be composed of scalars, enums and other embedded types.
+    /// Since they are generated as plain records, references between them can't be cyclic.
+    fn validate_embedded_types(self) -> anyhow::Result<Self> {
+        for embedded_type in self.embedded_types.values().sorted_by_key(|e| &e.name) {
+            embedded_type.validate_field_types(&self)?;
+            embedded_type.check_no_cycles(&self, &mut vec![])?;
+        }
+        Ok(self)
+    }
+
+    /// Embedded types sorted by name, for deterministic codegen
+    pub fn get_embedded_types(&self) -> Vec<&EmbeddedType> {
+        self.embedded_types
+            .values()
+            .sorted_by_key(|e| &e.name)
+            .collect()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -325,11 +442,167 @@ impl GraphQLEnum {
     }
 }
 
+/// A non-entity object type marked with @embedded. It doesn't get a table of its own,
+/// the value is stored as JSONB in the column of the entity field using it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmbeddedType {
+    pub name: String,
+    pub fields: Vec<EmbeddedField>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EmbeddedField {
+    pub name: String,
+    pub field_type: UserDefinedFieldType,
+}
+
+impl EmbeddedType {
+    const DIRECTIVE_NAME: &'static str = "embedded";
+
+    fn is_embedded_object(obj: &ObjectType<String>) -> bool {
+        obj.directives
+            .iter()
+            .any(|directive| directive.name == Self::DIRECTIVE_NAME)
+    }
+
+    fn from_object(obj: &ObjectType<String>) -> anyhow::Result<Self> {
+        let name = obj.name.clone();
+
+        if let Some(directive) = obj
+            .directives
+            .iter()
+            .find(|directive| directive.name != Self::DIRECTIVE_NAME)
+        {
+            return Err(anyhow!(
+                "EE222: The @{} directive is not supported on embedded type '{name}'",
+                directive.name
+            ));
+        }
+
+        let fields = obj
+            .fields
+            .iter()
+            .map(|field| {
+                if let Some(directive) = field.directives.first() {
+                    return Err(anyhow!(
+                        "EE222: The @{} directive is not supported on the field '{}' of \
+                         embedded type '{name}'",
+                        directive.name,
+                        field.name
+                    ));
+                }
+                Ok(EmbeddedField {
+                    name: field.name.clone(),
+                    field_type: UserDefinedFieldType::from_obj_field_type(
+                        &field.field_type,
+                        &PgTypeModifications::default(),
+                    ),
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Self::new(name, fields)
+    }
+
+    fn new(name: String, fields: Vec<EmbeddedField>) -> anyhow::Result<Self> {
+        if fields.is_empty() {
+            return Err(anyhow!(
+                "EE222: Embedded type '{name}' must have at least one field"
+            ));
+        }
+        let mut field_names = HashSet::new();
+        for field in &fields {
+            if !field_names.insert(RescriptRecordField::to_valid_res_name(&field.name)) {
+                return Err(anyhow!(
+                    "EE222: Embedded type '{name}' has multiple fields named '{}'",
+                    field.name
+                ));
+            }
+        }
+        Ok(Self { name, fields })
+    }
+
+    fn validate_field_types(&self, schema: &Schema) -> anyhow::Result<()> {
+        for field in &self.fields {
+            field.field_type.validate_type(schema).context(format!(
+                "Invalid field '{}' of embedded type '{}'",
+                field.name, self.name
+            ))?;
+            if let GqlScalar::Custom(type_name) = field.field_type.get_underlying_scalar() {
+                if let TypeDef::Entity(_) = schema.try_get_type_def(&type_name)? {
+                    return Err(anyhow!(
+                        "EE222: The field '{}' of embedded type '{}' references the entity \
+                         '{type_name}'. Embedded types can't reference entities, please store \
+                         the entity id as an ID field instead.",
+                        field.name,
+                        self.name
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn check_no_cycles<'a>(
+        &'a self,
+        schema: &'a Schema,
+        path: &mut Vec<&'a str>,
+    ) -> anyhow::Result<()> {
+        if path.contains(&self.name.as_str()) {
+            return Err(anyhow!(
+                "EE222: Embedded types can't reference themselves. Found a cycle: {} -> {}",
+                path.join(" -> "),
+                self.name
+            ));
+        }
+        path.push(&self.name);
+        for field in &self.fields {
+            if let GqlScalar::Custom(type_name) = field.field_type.get_underlying_scalar() {
+                if let Some(embedded_type) = schema.embedded_types.get(&type_name) {
+                    embedded_type.check_no_cycles(schema, path)?;
+                }
+            }
+        }
+        path.pop();
+        Ok(())
+    }
+
+    /// The name of the generated record type and its schema prefix
+    pub fn get_rescript_type_name(&self) -> String {
+        self.name.uncapitalize()
+    }
+
+    pub fn to_rescript_type_decl(&self, schema: &Schema) -> anyhow::Result<RescriptTypeDecl> {
+        let record_fields = self
+            .fields
+            .iter()
+            .map(|field| {
+                Ok(RescriptRecordField::new(
+                    field.name.clone(),
+                    field.field_type.to_rescript_type(schema)?,
+                ))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()
+            .context(format!(
+                "Failed getting rescript type of embedded type '{}'",
+                self.name
+            ))?;
+
+        Ok(RescriptTypeDecl::new(
+            self.get_rescript_type_name(),
+            RescriptTypeExpr::Record(record_fields),
+            vec![],
+        ))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Entity {
     pub name: String,
     pub fields: HashMap<String, Field>,
     pub multi_field_indexes: Vec<MultiFieldIndex>,
+    pub full_text_indexes: Vec<FullTextIndex>,
+    pub computed_fields: Vec<ComputedField>,
 }
 
 impl Entity {
@@ -337,6 +610,8 @@ impl Entity {
         name: &str,
         fields: Vec<Field>,
         multi_field_indexes: Vec<MultiFieldIndex>,
+        full_text_indexes: Vec<FullTextIndex>,
+        computed_fields: Vec<ComputedField>,
     ) -> anyhow::Result<Self> {
         let fields = unique_hashmap::from_vec_no_duplicates(
             fields.into_iter().map(|f| (f.name.clone(), f)).collect(),
@@ -373,10 +648,35 @@ impl Entity {
             }
         }
 
+        let full_text_indexes = full_text_indexes
+            .into_iter()
+            .map(|full_text_index| full_text_index.validate(&fields))
+            .collect::<anyhow::Result<Vec<_>>>()
+            .context(format!("Invalid full text indexes on Entity {name}"))?;
+
+        if let Some(computed_field) = computed_fields
+            .iter()
+            .find(|computed_field| fields.contains_key(&computed_field.name))
+        {
+            return Err(anyhow!(
+                "EE220: The @computed field '{}' on Entity {name} has the same name as a stored \
+                 field. Please use a unique name.",
+                computed_field.name
+            ));
+        }
+
+        let computed_fields = computed_fields
+            .into_iter()
+            .map(|computed_field| computed_field.validate(&fields))
+            .collect::<anyhow::Result<Vec<_>>>()
+            .context(format!("Invalid computed fields on Entity {name}"))?;
+
         Ok(Self {
             name: name.to_string(),
             fields,
             multi_field_indexes,
+            full_text_indexes,
+            computed_fields,
         })
     }
 
@@ -424,18 +724,46 @@ impl Entity {
                 "Failed parsing multi field indexes on entity {name}"
             ))?;
 
-        // Map each field in the ObjectType to a Field, passing the indexed status
-        let fields = obj
-            .fields
+        let full_text_indexes = obj
+            .directives
             .iter()
+            .filter(|directive| directive.name == "fulltext")
+            .map(FullTextIndex::from_directive)
+            .collect::<anyhow::Result<Vec<_>>>()
+            .context(format!("Failed parsing full text indexes on entity {name}"))?;
+
+        // Computed fields aren't stored, so they are kept separately from the entity fields
+        let (computed_obj_fields, obj_fields): (Vec<_>, Vec<_>) =
+            obj.fields.iter().partition(|field| {
+                field
+                    .directives
+                    .iter()
+                    .any(|directive| directive.name == "computed")
+            });
+
+        let computed_fields = computed_obj_fields
+            .into_iter()
+            .map(ComputedField::from_obj_field)
+            .collect::<anyhow::Result<Vec<_>>>()
+            .context(format!("Failed parsing computed fields on entity {name}"))?;
+
+        // Map each field in the ObjectType to a Field, passing the indexed status
+        let fields = obj_fields
+            .into_iter()
             .map(
                 Field::from_obj_field, // Pass the indexed status to the field constructor
             )
             .collect::<anyhow::Result<Vec<Field>>>()
             .context(format!("Failed parsing fields on entity {name}"))?;
 
-        let entity = Self::new(name, fields, multi_field_indexes)
-            .context(format!("Failed constructing entity {name}",))?;
+        let entity = Self::new(
+            name,
+            fields,
+            multi_field_indexes,
+            full_text_indexes,
+            computed_fields,
+        )
+        .context(format!("Failed constructing entity {name}",))?;
 
         // Here, store indexed information somewhere within your entity structure or handle them accordingly
         Ok(entity)
@@ -484,7 +812,7 @@ impl Entity {
                         |e| Some(Err(e)),
                         |type_def| match type_def {
                             TypeDef::Entity(entity) => Some(Ok((field, entity))),
-                            TypeDef::Enum => None,
+                            TypeDef::Enum | TypeDef::Embedded(_) => None,
                         },
                     )
                 } else {
@@ -858,6 +1186,308 @@ impl Field {
     }
 }
 
+/// Text search configurations available in Postgres by default
+const FULL_TEXT_SEARCH_LANGUAGES: &[&str] = &[
+    "simple",
+    "arabic",
+    "armenian",
+    "basque",
+    "catalan",
+    "danish",
+    "dutch",
+    "english",
+    "finnish",
+    "french",
+    "german",
+    "greek",
+    "hindi",
+    "hungarian",
+    "indonesian",
+    "irish",
+    "italian",
+    "lithuanian",
+    "nepali",
+    "norwegian",
+    "portuguese",
+    "romanian",
+    "russian",
+    "serbian",
+    "spanish",
+    "swedish",
+    "tamil",
+    "turkish",
+    "yiddish",
+];
+
+///A full text search index defined with the @fulltext directive on an entity.
+///Stored as a tsvector generated column with a GIN index and exposed in Hasura
+///as a search function with the same name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct FullTextIndex {
+    pub name: String,
+    pub language: String,
+    pub fields: Vec<String>,
+}
+
+impl FullTextIndex {
+    fn from_directive(directive: &Directive<String>) -> anyhow::Result<Self> {
+        let usage_example = "Eg. @fulltext(name: \"tokenSearch\", language: \"english\", \
+                             fields: [\"name\", \"symbol\"])";
+        let mut name = None;
+        let mut language = None;
+        let mut fields = None;
+        for (arg_name, arg_value) in &directive.arguments {
+            match (arg_name.as_str(), arg_value) {
+                ("name", Value::String(value)) => name = Some(value.clone()),
+                ("language", Value::String(value)) => language = Some(value.clone()),
+                ("fields", Value::List(values)) => {
+                    let field_names = values
+                        .iter()
+                        .map(|v| match v {
+                            Value::String(field_name) => Ok(field_name.clone()),
+                            _ => Err(anyhow!(
+                                "EE218: Listed @fulltext field should be a string. {usage_example}"
+                            )),
+                        })
+                        .collect::<anyhow::Result<Vec<_>>>()?;
+                    fields = Some(field_names);
+                }
+                (arg_name, _) => {
+                    return Err(anyhow!(
+                        "EE218: Invalid @fulltext argument '{arg_name}'. {usage_example}"
+                    ))
+                }
+            }
+        }
+
+        match (name, language, fields) {
+            (Some(name), Some(language), Some(fields)) => Ok(Self {
+                name,
+                language,
+                fields,
+            }),
+            _ => Err(anyhow!(
+                "EE218: The @fulltext directive requires name, language and fields arguments. \
+                 {usage_example}"
+            )),
+        }
+    }
+
+    fn validate(self, fields: &HashMap<String, Field>) -> anyhow::Result<Self> {
+        let is_valid_name = self
+            .name
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic())
+            && self
+                .name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !is_valid_name {
+            return Err(anyhow!(
+                "EE219: Invalid @fulltext name '{}'. The name should start with a letter and only \
+                 contain letters, numbers and underscores.",
+                self.name
+            ));
+        }
+        if fields.contains_key(&self.name) {
+            return Err(anyhow!(
+                "EE219: The @fulltext name '{}' clashes with a field of the entity. Please use a \
+                 different name.",
+                self.name
+            ));
+        }
+        if !FULL_TEXT_SEARCH_LANGUAGES.contains(&self.language.as_str()) {
+            return Err(anyhow!(
+                "EE219: Unsupported @fulltext language '{}'. Supported languages are: {}",
+                self.language,
+                FULL_TEXT_SEARCH_LANGUAGES.join(", ")
+            ));
+        }
+        if self.fields.is_empty() {
+            return Err(anyhow!(
+                "EE219: The @fulltext directive '{}' should include at least one field.",
+                self.name
+            ));
+        }
+
+        let mut field_names_set = HashSet::new();
+        for field_name in &self.fields {
+            if !field_names_set.insert(field_name) {
+                return Err(anyhow!(
+                    "EE219: Field '{field_name}' is listed multiple times in the @fulltext \
+                     directive '{}'",
+                    self.name
+                ));
+            }
+            let field = fields.get(field_name).ok_or_else(|| {
+                anyhow!(
+                    "EE219: Field '{field_name}' listed in the @fulltext directive '{}' does not \
+                     exist in entity.",
+                    self.name
+                )
+            })?;
+            //Derived from fields are considered arrays
+            if field.field_type.is_array()
+                || field.field_type.get_underlying_scalar() != GqlScalar::String
+            {
+                return Err(anyhow!(
+                    "EE219: Field '{field_name}' listed in the @fulltext directive '{}' should be \
+                     of type String, but it is '{}'.",
+                    self.name,
+                    field.field_type
+                ));
+            }
+        }
+        Ok(self)
+    }
+}
+
+///A field defined with the @computed(sql: "...") directive.
+///It isn't stored in the entity table, but is resolved at query time by Hasura
+///using a generated sql function. Columns are referenced with {field} placeholders.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ComputedField {
+    pub name: String,
+    pub field_type: UserDefinedFieldType,
+    pub sql: String,
+}
+
+impl ComputedField {
+    fn from_obj_field(field: &ObjField<String>) -> anyhow::Result<Self> {
+        let (computed_directives, other_directives): (Vec<_>, Vec<_>) = field
+            .directives
+            .iter()
+            .partition(|directive| directive.name == "computed");
+
+        if computed_directives.len() > 1 {
+            return Err(anyhow!(
+                "EE202: Cannot use more than one of the same directive on field {}",
+                field.name
+            ));
+        }
+        if let Some(other_directive) = other_directives.first() {
+            return Err(anyhow!(
+                "EE220: The @computed field '{}' cannot be used with the @{} directive",
+                field.name,
+                other_directive.name
+            ));
+        }
+
+        let sql = match computed_directives
+            .first()
+            .and_then(|d| d.arguments.iter().find(|(key, _)| key == "sql"))
+        {
+            Some((_, Value::String(sql))) if !sql.trim().is_empty() => sql.trim().to_string(),
+            _ => {
+                return Err(anyhow!(
+                    "EE220: The @computed directive on field '{}' requires a non empty sql \
+                     argument. Eg. @computed(sql: \"{reserveUSD} / {totalSupply}\")",
+                    field.name
+                ))
+            }
+        };
+
+        let field_type = UserDefinedFieldType::from_obj_field_type(
+            &field.field_type,
+            &PgTypeModifications::default(),
+        );
+        if field_type.is_array()
+            || matches!(field_type.get_underlying_scalar(), GqlScalar::Custom(_))
+        {
+            return Err(anyhow!(
+                "EE220: The @computed field '{}' should be a non list scalar type, but it is '{}'",
+                field.name,
+                field_type
+            ));
+        }
+
+        Ok(Self {
+            name: field.name.clone(),
+            field_type,
+            sql,
+        })
+    }
+
+    fn validate(self, fields: &HashMap<String, Field>) -> anyhow::Result<Self> {
+        if self.name == "id" {
+            return Err(anyhow!("EE220: The 'id' field cannot be computed"));
+        }
+        self.map_column_refs(|column| match fields.get(column) {
+            Some(field) if !field.field_type.is_derived_from() => Ok(column.to_string()),
+            Some(_) => Err(anyhow!(
+                "EE221: The @computed field '{}' references '{}' which is a @derivedFrom field. \
+                 Only fields stored on the entity can be referenced.",
+                self.name,
+                column
+            )),
+            None if column == "db_write_timestamp" => Ok(column.to_string()),
+            None => Err(anyhow!(
+                "EE221: The @computed field '{}' references '{}' which doesn't exist on the \
+                 entity.",
+                self.name,
+                column
+            )),
+        })?;
+        Ok(self)
+    }
+
+    ///Returns the sql expression with column references replaced by
+    ///quoted columns of the given row argument. Eg. reserveUSD -> entity."reserveUSD"
+    pub fn get_sql_expression(
+        &self,
+        entity: &Entity,
+        schema: &Schema,
+        row_arg: &str,
+    ) -> anyhow::Result<String> {
+        self.map_column_refs(|column| {
+            let db_column = match entity.fields.get(column) {
+                Some(field) if field.field_type.is_entity_field(schema)? => format!("{column}_id"),
+                _ => column.to_string(),
+            };
+            Ok(format!("{row_arg}.\"{db_column}\""))
+        })
+    }
+
+    ///Maps every {field} placeholder of the sql expression with the provided function.
+    ///The rest of the sql is kept as is, including braces inside string literals.
+    fn map_column_refs(
+        &self,
+        mut map_column: impl FnMut(&str) -> anyhow::Result<String>,
+    ) -> anyhow::Result<String> {
+        let mut output = String::new();
+        let mut chars = self.sql.chars();
+        let mut is_string_literal = false;
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if !is_string_literal => {
+                    let mut column = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(next) => column.push(next),
+                            None => {
+                                return Err(anyhow!(
+                                    "EE221: Unterminated {{ in @computed sql of field '{}'",
+                                    self.name
+                                ))
+                            }
+                        }
+                    }
+                    output.push_str(&map_column(column.trim())?);
+                }
+                _ => {
+                    if c == '\'' {
+                        is_string_literal = !is_string_literal;
+                    }
+                    output.push(c);
+                }
+            }
+        }
+        Ok(output)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct MultiFieldIndex(Vec<String>);
 
@@ -1014,6 +1644,9 @@ impl UserDefinedFieldType {
                     Self::Single(GqlScalar::Timestamp) => {
                         Err(anyhow!("Arrays of timestamps are not yet supported."))
                     }
+                    Self::Single(GqlScalar::Date) => {
+                        Err(anyhow!("Arrays of dates are not yet supported."))
+                    }
                     _ => field_type.validate_type(schema),
                 },
                 Self::Single(gql_scalar) => Err(anyhow!(
@@ -1128,16 +1761,26 @@ impl UserDefinedFieldType {
 
     pub fn from_ethabi_type(abi_type: &EthAbiParamType) -> anyhow::Result<Self> {
         match abi_type {
+            //Int8 is a signed 64 bit integer so uint64 doesn't fit into it
+            EthAbiParamType::Uint(size) if *size < 64 => {
+                Ok(Self::NonNullType(Box::new(Self::Single(GqlScalar::Int8))))
+            }
+            EthAbiParamType::Int(size) if *size <= 64 => {
+                Ok(Self::NonNullType(Box::new(Self::Single(GqlScalar::Int8))))
+            }
             EthAbiParamType::Uint(_size) | EthAbiParamType::Int(_size) => Ok(Self::NonNullType(
                 Box::new(Self::Single(GqlScalar::BigInt(None))),
             )),
             EthAbiParamType::Bool => Ok(Self::NonNullType(Box::new(Self::Single(
                 GqlScalar::Boolean,
             )))),
-            EthAbiParamType::Address
-            | EthAbiParamType::Bytes
-            | EthAbiParamType::String
-            | EthAbiParamType::FixedBytes(_) => {
+            EthAbiParamType::Address => Ok(Self::NonNullType(Box::new(Self::Single(
+                GqlScalar::Address,
+            )))),
+            EthAbiParamType::FixedBytes(32) => Ok(Self::NonNullType(Box::new(Self::Single(
+                GqlScalar::Bytes32,
+            )))),
+            EthAbiParamType::Bytes | EthAbiParamType::String | EthAbiParamType::FixedBytes(_) => {
                 Ok(Self::NonNullType(Box::new(Self::Single(GqlScalar::String))))
             }
             EthAbiParamType::Array(abi_type) | EthAbiParamType::FixedArray(abi_type, _) => {
@@ -1356,6 +1999,14 @@ pub enum GqlScalar {
     Bytes,
     #[subenum(AdditionalGqlScalar)]
     Json,
+    #[subenum(AdditionalGqlScalar)]
+    Int8,
+    #[subenum(AdditionalGqlScalar)]
+    Date,
+    #[subenum(AdditionalGqlScalar)]
+    Address,
+    #[subenum(AdditionalGqlScalar)]
+    Bytes32,
     Custom(String),
 }
 
@@ -1400,6 +2051,10 @@ impl GqlScalar {
             "Timestamp" => GqlScalar::Timestamp,
             "Bytes" => GqlScalar::Bytes,
             "Json" => GqlScalar::Json,
+            "Int8" => GqlScalar::Int8,
+            "Date" => GqlScalar::Date,
+            "Address" => GqlScalar::Address,
+            "Bytes32" => GqlScalar::Bytes32,
             name => GqlScalar::Custom(name.to_string()),
         }
     }
@@ -1420,9 +2075,15 @@ impl GqlScalar {
                 PGPrimitive::Numeric(Some((*precision, *scale)))
             }
             GqlScalar::Timestamp => PGPrimitive::Timestamp,
+            GqlScalar::Int8 => PGPrimitive::BigInt,
+            GqlScalar::Date => PGPrimitive::Date,
+            GqlScalar::Address => PGPrimitive::Char(42), // 0x prefixed 20 bytes hex
+            // The value isn't validated, so TEXT avoids padding shorter strings
+            GqlScalar::Bytes32 => PGPrimitive::Text,
             GqlScalar::Custom(name) => match schema.try_get_type_def(name)? {
                 TypeDef::Entity(_) => PGPrimitive::Text,
                 TypeDef::Enum => PGPrimitive::Enum(name.clone()),
+                TypeDef::Embedded(_) => PGPrimitive::JsonB,
             },
         };
         Ok(converted)
@@ -1440,9 +2101,18 @@ impl GqlScalar {
             GqlScalar::Json => RescriptTypeIdent::Json,
             GqlScalar::Boolean => RescriptTypeIdent::Bool,
             GqlScalar::Timestamp => RescriptTypeIdent::Timestamp,
+            // Int8 can overflow js numbers so it's represented as bigint
+            GqlScalar::Int8 => RescriptTypeIdent::BigInt,
+            GqlScalar::Date => RescriptTypeIdent::Timestamp,
+            GqlScalar::Address => RescriptTypeIdent::ChecksumAddress,
+            GqlScalar::Bytes32 => RescriptTypeIdent::String,
             GqlScalar::Custom(name) => match schema.try_get_type_def(name)? {
                 TypeDef::Entity(_) => RescriptTypeIdent::ID,
                 TypeDef::Enum => RescriptTypeIdent::SchemaEnum(name.to_capitalized_options()),
+                TypeDef::Embedded(embedded_type) => RescriptTypeIdent::TypeApplication {
+                    name: embedded_type.get_rescript_type_name(),
+                    type_params: vec![],
+                },
             },
         };
         Ok(res_type)
@@ -1452,7 +2122,7 @@ impl GqlScalar {
         let opt_entity_name = match self {
             Self::Custom(name) => match schema.try_get_type_def(name)? {
                 TypeDef::Entity(entity) => Some(entity.name.clone()),
-                TypeDef::Enum => None,
+                TypeDef::Enum | TypeDef::Embedded(_) => None,
             },
             _ => None,
         };
@@ -1464,7 +2134,8 @@ impl GqlScalar {
 #[cfg(test)]
 mod tests {
     use super::{
-        anyhow, Entity, Field, FieldType, GqlScalar, GraphQLEnum, Schema, UserDefinedFieldType,
+        anyhow, Entity, Field, FieldType, FullTextIndex, GqlScalar, GraphQLEnum, Schema,
+        UserDefinedFieldType,
     };
     use crate::config_parsing::postgres_types::Primitive as PGPrimitive;
     use graphql_parser::schema::{parse_schema, Definition, Document, ObjectType, TypeDefinition};
@@ -1728,8 +2399,8 @@ type TestEntity {
     #[test]
     fn gql_type_to_rescript_type_entity() {
         let test_entity_string = String::from("TestEntity");
-        let test_entity = Entity::new(&test_entity_string, vec![], vec![]).unwrap();
-        let schema = Schema::new(vec![test_entity], vec![]).unwrap();
+        let test_entity = Entity::new(&test_entity_string, vec![], vec![], vec![], vec![]).unwrap();
+        let schema = Schema::new(vec![test_entity], vec![], vec![]).unwrap();
         let rescript_type = UserDefinedFieldType::Single(GqlScalar::Custom(test_entity_string))
             .to_rescript_type(&schema)
             .expect("expected rescript type string");
@@ -1741,7 +2412,7 @@ type TestEntity {
     fn gql_type_to_rescript_type_enum() {
         let name = String::from("TestEnum");
         let test_enum = GraphQLEnum::new(name.clone(), vec![]).unwrap();
-        let schema = Schema::new(vec![], vec![test_enum]).unwrap();
+        let schema = Schema::new(vec![], vec![test_enum], vec![]).unwrap();
         let rescript_type = UserDefinedFieldType::Single(GqlScalar::Custom(name))
             .to_rescript_type(&schema)
             .expect("expected rescript type string");
@@ -1821,7 +2492,7 @@ type TestEntity {
         let test_enum = GraphQLEnum::new(name.clone(), vec!["TEST_VALUE".to_string()]).unwrap();
         let field_type =
             get_field_type_helper_with_additional("TestEnum!", vec![test_enum.clone()]);
-        let schema = Schema::new(vec![], vec![test_enum]).unwrap();
+        let schema = Schema::new(vec![], vec![test_enum], vec![]).unwrap();
         let pg_primitive = field_type
             .to_user_defined_field_type()
             .to_underlying_postgres_primitive(&schema)
@@ -1936,6 +2607,86 @@ type TestEntity {
         assert_eq!(pg_field.linked_entity, None);
     }
 
+    #[test]
+    fn test_get_postgres_field_additional_scalars() {
+        let schema_str = r#"
+type TestEntity {
+  id: ID!
+  count: Int8!
+  day: Date!
+  owner: Address!
+  txHash: Bytes32
+}
+        "#;
+        let gql_doc = setup_document(schema_str).unwrap();
+        let schema = Schema::from_document(gql_doc).unwrap();
+        let entity = schema.entities.get("TestEntity").unwrap();
+        let get_pg_field = |name: &str| {
+            entity
+                .fields
+                .get(name)
+                .unwrap()
+                .get_postgres_field(&schema, entity)
+                .expect("Failed to get postgres field")
+                .unwrap()
+        };
+
+        let count = get_pg_field("count");
+        assert_eq!(count.field_type, PGPrimitive::BigInt);
+        assert_eq!(count.res_schema_code, "BigInt.schema");
+
+        let day = get_pg_field("day");
+        assert_eq!(day.field_type, PGPrimitive::Date);
+        assert_eq!(day.res_schema_code, "Utils.Schema.dbDate");
+
+        let owner = get_pg_field("owner");
+        assert_eq!(owner.field_type, PGPrimitive::Char(42));
+        assert_eq!(owner.field_type.to_string(), "Custom(\"CHARACTER(42)\")");
+        assert_eq!(owner.res_schema_code, "Address.Evm.checksumSchema");
+
+        let tx_hash = get_pg_field("txHash");
+        assert_eq!(tx_hash.field_type, PGPrimitive::Text);
+        assert!(tx_hash.is_nullable);
+        assert_eq!(tx_hash.res_schema_code, "S.null(S.string)");
+    }
+
+    #[test]
+    fn test_date_arrays_are_not_supported() {
+        let schema_str = r#"
+type TestEntity {
+  id: ID!
+  days: [Date!]!
+}
+        "#;
+        let gql_doc = setup_document(schema_str).unwrap();
+        let result = Schema::from_document(gql_doc);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_from_ethabi_type_uses_additional_scalars() {
+        use ethers::abi::ParamType;
+
+        let gql_type = |abi_type: ParamType| {
+            UserDefinedFieldType::from_ethabi_type(&abi_type)
+                .unwrap()
+                .to_string()
+        };
+
+        assert_eq!(gql_type(ParamType::Address), "Address!");
+        assert_eq!(gql_type(ParamType::FixedBytes(32)), "Bytes32!");
+        assert_eq!(gql_type(ParamType::FixedBytes(4)), "String!");
+        assert_eq!(gql_type(ParamType::Int(64)), "Int8!");
+        assert_eq!(gql_type(ParamType::Uint(32)), "Int8!");
+        assert_eq!(gql_type(ParamType::Uint(64)), "BigInt!");
+        assert_eq!(gql_type(ParamType::Uint(256)), "BigInt!");
+        assert_eq!(
+            gql_type(ParamType::Array(Box::new(ParamType::Address))),
+            "[Address!]!"
+        );
+    }
+
     #[test]
     fn test_get_postgres_field_with_linked_entity() {
         let schema_str = r#"
@@ -2335,6 +3086,216 @@ type TestEntity {
         assert!(err_message.contains("EE216"));
     }
 
+    #[test]
+    fn test_fulltext_directive() {
+        let schema_str = r#"
+type Token @fulltext(name: "tokenSearch", language: "english", fields: ["name", "symbol"]) {
+  id: ID!
+  name: String!
+  symbol: String
+}
+        "#;
+        let gql_doc = setup_document(schema_str).unwrap();
+        let schema = Schema::from_document(gql_doc).unwrap();
+        let entity = schema.entities.get("Token").unwrap();
+
+        assert_eq!(
+            entity.full_text_indexes,
+            vec![FullTextIndex {
+                name: "tokenSearch".to_string(),
+                language: "english".to_string(),
+                fields: vec!["name".to_string(), "symbol".to_string()],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_fulltext_directive_invalid_fields() {
+        let parse_schema_err = |schema_str: &str| {
+            let gql_doc = setup_document(schema_str).unwrap();
+            format!("{:?}", Schema::from_document(gql_doc).unwrap_err())
+        };
+
+        let missing_field_err = parse_schema_err(
+            r#"
+type Token @fulltext(name: "tokenSearch", language: "english", fields: ["label"]) {
+  id: ID!
+  name: String!
+}
+            "#,
+        );
+        assert!(missing_field_err.contains("EE219: Field 'label'"));
+
+        let non_string_field_err = parse_schema_err(
+            r#"
+type Token @fulltext(name: "tokenSearch", language: "english", fields: ["supply"]) {
+  id: ID!
+  supply: BigInt!
+}
+            "#,
+        );
+        assert!(non_string_field_err.contains("should be of type String"));
+
+        let unsupported_language_err = parse_schema_err(
+            r#"
+type Token @fulltext(name: "tokenSearch", language: "klingon", fields: ["name"]) {
+  id: ID!
+  name: String!
+}
+            "#,
+        );
+        assert!(unsupported_language_err.contains("EE219: Unsupported @fulltext language"));
+
+        let missing_args_err = parse_schema_err(
+            r#"
+type Token @fulltext(name: "tokenSearch", fields: ["name"]) {
+  id: ID!
+  name: String!
+}
+            "#,
+        );
+        assert!(missing_args_err.contains("EE218"));
+    }
+
+    #[test]
+    fn test_fulltext_directive_duplicate_names() {
+        let schema_str = r#"
+type Token @fulltext(name: "search", language: "english", fields: ["name"]) {
+  id: ID!
+  name: String!
+}
+
+type Pool @fulltext(name: "search", language: "english", fields: ["name"]) {
+  id: ID!
+  name: String!
+}
+        "#;
+        let gql_doc = setup_document(schema_str).unwrap();
+        let err_message = format!("{:?}", Schema::from_document(gql_doc).unwrap_err());
+
+        assert!(err_message.contains(
+            "EE219: The @fulltext name 'search' on entity 'Token' is already used by another \
+             entity or full text index. Please use a unique name."
+        ));
+    }
+
+    #[test]
+    fn test_computed_field() {
+        let schema_str = r#"
+type Pair {
+  id: ID!
+  token0: Token!
+  reserveUSD: BigDecimal!
+  totalSupply: BigDecimal!
+  priceUSD: BigDecimal! @computed(sql: "CASE WHEN {totalSupply} = 0 THEN NULL ELSE {reserveUSD} / {totalSupply}::numeric END")
+  hasToken: Boolean! @computed(sql: "{token0} IS NOT NULL AND lower({ token0 }) <> '{foo}'")
+}
+
+type Token {
+  id: ID!
+}
+        "#;
+        let gql_doc = setup_document(schema_str).unwrap();
+        let schema = Schema::from_document(gql_doc).unwrap();
+        let entity = schema.entities.get("Pair").unwrap();
+
+        assert!(!entity.fields.contains_key("priceUSD"));
+        let computed_fields = entity
+            .computed_fields
+            .iter()
+            .map(|computed_field| {
+                (
+                    computed_field.name.as_str(),
+                    computed_field
+                        .get_sql_expression(entity, &schema, "entity")
+                        .unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            computed_fields,
+            vec![
+                (
+                    "priceUSD",
+                    r#"CASE WHEN entity."totalSupply" = 0 THEN NULL ELSE entity."reserveUSD" / entity."totalSupply"::numeric END"#
+                        .to_string()
+                ),
+                (
+                    "hasToken",
+                    r#"entity."token0_id" IS NOT NULL AND lower(entity."token0_id") <> '{foo}'"#
+                        .to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_computed_field_with_unknown_column() {
+        let schema_str = r#"
+type Pair {
+  id: ID!
+  reserveUSD: BigDecimal!
+  priceUSD: BigDecimal! @computed(sql: "{reserveUSD} / {totalSupply}")
+}
+        "#;
+        let gql_doc = setup_document(schema_str).unwrap();
+        let err_message = format!("{:?}", Schema::from_document(gql_doc).unwrap_err());
+
+        assert!(err_message.contains(
+            "EE221: The @computed field 'priceUSD' references 'totalSupply' which doesn't exist \
+             on the entity."
+        ));
+    }
+
+    #[test]
+    fn test_computed_field_with_invalid_type() {
+        let schema_str = r#"
+type Pair {
+  id: ID!
+  reserveUSD: BigDecimal!
+  prices: [BigDecimal!]! @computed(sql: "ARRAY[{reserveUSD}]")
+}
+        "#;
+        let gql_doc = setup_document(schema_str).unwrap();
+        let err_message = format!("{:?}", Schema::from_document(gql_doc).unwrap_err());
+
+        assert!(err_message.contains("EE220"));
+    }
+
+    #[test]
+    fn test_computed_field_with_unterminated_placeholder() {
+        let schema_str = r#"
+type Pair {
+  id: ID!
+  reserveUSD: BigDecimal!
+  priceUSD: BigDecimal! @computed(sql: "{reserveUSD / 2")
+}
+        "#;
+        let gql_doc = setup_document(schema_str).unwrap();
+        let err_message = format!("{:?}", Schema::from_document(gql_doc).unwrap_err());
+
+        assert!(err_message.contains("EE221: Unterminated { in @computed sql of field 'priceUSD'"));
+    }
+
+    #[test]
+    fn test_computed_field_with_stored_field_name() {
+        let schema_str = r#"
+type Pair {
+  id: ID!
+  reserveUSD: BigDecimal!
+  reserveUSD: BigDecimal! @computed(sql: "{reserveUSD} * 2")
+}
+        "#;
+        let gql_doc = setup_document(schema_str).unwrap();
+        let err_message = format!("{:?}", Schema::from_document(gql_doc).unwrap_err());
+
+        assert!(err_message.contains(
+            "EE220: The @computed field 'reserveUSD' on Entity Pair has the same name as a stored \
+             field. Please use a unique name."
+        ));
+    }
+
     #[test]
     fn multifield_index_should_retain_original_order() {
         let schema_str = r#"
@@ -2362,4 +3323,125 @@ type TestEntity {
             vec!["b".to_string(), "a".to_string()]
         );
     }
+
+    #[test]
+    fn test_embedded_type() {
+        let schema_str = r#"
+type Metadata @embedded {
+  description: String
+  tags: [String!]!
+  royalty: Royalty!
+}
+
+type Royalty @embedded {
+  recipient: Address!
+  basisPoints: Int!
+}
+
+type Token {
+  id: ID!
+  metadata: Metadata!
+  history: [Metadata!]!
+}
+        "#;
+        let gql_doc = setup_document(schema_str).unwrap();
+        let schema = Schema::from_document(gql_doc).unwrap();
+
+        assert_eq!(schema.entities.len(), 1);
+        assert_eq!(
+            schema
+                .get_embedded_types()
+                .iter()
+                .map(|e| e.name.clone())
+                .collect::<Vec<_>>(),
+            vec!["Metadata".to_string(), "Royalty".to_string()]
+        );
+
+        let entity = schema.entities.get("Token").unwrap();
+        let metadata_field = entity.fields.get("metadata").unwrap();
+        assert_eq!(
+            metadata_field
+                .get_postgres_field(&schema, entity)
+                .unwrap()
+                .unwrap()
+                .field_type,
+            PGPrimitive::JsonB
+        );
+        assert_eq!(
+            metadata_field.field_type.to_rescript_type(&schema).unwrap(),
+            crate::rescript_types::RescriptTypeIdent::TypeApplication {
+                name: "metadata".to_string(),
+                type_params: vec![],
+            }
+        );
+        assert!(!metadata_field.field_type.is_entity_field(&schema).unwrap());
+
+        let type_decl = schema
+            .embedded_types
+            .get("Metadata")
+            .unwrap()
+            .to_rescript_type_decl(&schema)
+            .unwrap();
+        assert_eq!(
+            type_decl.to_string(),
+            "type metadata = {description: option<string>, tags: array<string>, royalty: \
+             royalty}"
+        );
+    }
+
+    #[test]
+    fn test_invalid_embedded_types() {
+        let parse_schema_err = |schema_str: &str| {
+            let gql_doc = setup_document(schema_str).unwrap();
+            format!("{:?}", Schema::from_document(gql_doc).unwrap_err())
+        };
+
+        let entity_reference_err = parse_schema_err(
+            r#"
+type Metadata @embedded {
+  owner: User!
+}
+
+type User {
+  id: ID!
+}
+            "#,
+        );
+        assert!(entity_reference_err.contains("EE222: The field 'owner' of embedded type"));
+
+        let cycle_err = parse_schema_err(
+            r#"
+type A @embedded {
+  b: B
+}
+
+type B @embedded {
+  a: A
+}
+            "#,
+        );
+        assert!(cycle_err.contains("EE222: Embedded types can't reference themselves"));
+
+        let directive_err = parse_schema_err(
+            r#"
+type Metadata @embedded {
+  name: String! @index
+}
+            "#,
+        );
+        assert!(directive_err.contains("EE222: The @index directive is not supported"));
+
+        let duplicate_name_err = parse_schema_err(
+            r#"
+type Metadata @embedded {
+  name: String!
+}
+
+enum Metadata {
+  A
+}
+            "#,
+        );
+        assert!(duplicate_name_err.contains("EE214"));
+    }
 }
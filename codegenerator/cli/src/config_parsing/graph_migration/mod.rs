@@ -1,7 +1,7 @@
 use crate::{
     cli_args::init_config::Language,
     config_parsing::{
-        chain_helpers::{self, GraphNetwork},
+        chain_registry::ChainRegistry,
         human_config::{
             evm::{ContractConfig, EventConfig, HumanConfig, Network},
             NetworkContract,
@@ -45,7 +45,7 @@ pub struct Schema {
 pub struct DataSource {
     pub kind: String,
     pub name: String,
-    pub network: GraphNetwork,
+    pub network: String,
     pub source: Source,
     pub mapping: Mapping,
 }
@@ -53,7 +53,7 @@ pub struct DataSource {
 pub struct Template {
     pub kind: String,
     pub name: String,
-    pub network: GraphNetwork,
+    pub network: String,
     pub source: TemplateSource,
     pub mapping: Mapping,
 }
@@ -138,13 +138,11 @@ async fn fetch_ipfs_file(cid: &str) -> Result<String, reqwest::Error> {
 // Function to generate a hashmap of network name to contracts
 // Unnecessary to use a hashmap for subgraphs, because there is only one network per subgraph
 // But will be useful multiple subgraph IDs for same subgraph across different chains
-async fn generate_network_contract_hashmap(
-    manifest_raw: &str,
-) -> HashMap<GraphNetwork, Vec<String>> {
+async fn generate_network_contract_hashmap(manifest_raw: &str) -> HashMap<String, Vec<String>> {
     // Deserialize manifest file
     let manifest: GraphManifest = serde_yaml::from_str::<GraphManifest>(manifest_raw).unwrap();
 
-    let mut network_contracts: HashMap<GraphNetwork, Vec<String>> = HashMap::new();
+    let mut network_contracts: HashMap<String, Vec<String>> = HashMap::new();
 
     // Iterate through data sources and templates to get network name and contracts
     for data_source in manifest.data_sources {
@@ -304,10 +302,15 @@ pub async fn generate_config_from_subgraph_id(
     // Generate network contract hashmap
     let network_hashmap = generate_network_contract_hashmap(&manifest_file_string).await;
 
+    let chain_registry = ChainRegistry::global()?;
+
     for (graph_network, contracts) in &network_hashmap {
+        let chain = chain_registry
+            .get_by_graph_name(graph_network)
+            .ok_or_else(|| anyhow!("Unsupported subgraph network \"{}\"", graph_network))?;
         // Create network object to be populated
         let mut network = Network {
-            id: chain_helpers::Network::from(*graph_network).get_network_id(),
+            id: chain.id,
             hypersync_config: None,
             // TODO: update to the final rpc url
             rpc_config: None,
@@ -315,6 +318,7 @@ pub async fn generate_config_from_subgraph_id(
             start_block: 0,
             end_block: None,
             confirmed_block_threshold: None,
+            finality: None,
             contracts: vec![],
         };
         // Iterate through contracts to get contract name, abi file path, address and event names
@@ -447,8 +451,7 @@ mod test {
     use crate::{
         cli_args::init_config::Language,
         config_parsing::{
-            chain_helpers::{GraphNetwork, Network},
-            graph_migration::get_ipfs_id_from_file_path,
+            chain_registry::ChainRegistry, graph_migration::get_ipfs_id_from_file_path,
         },
     };
     use std::{collections::HashMap, path::PathBuf};
@@ -523,8 +526,12 @@ mod test {
         let manifest_file = std::fs::read_to_string("test/configs/graph-manifest.yaml").unwrap();
         let manifest: GraphManifest =
             serde_yaml::from_str::<GraphManifest>(&manifest_file).unwrap();
+        let chain_registry = ChainRegistry::global().unwrap();
         for data_source in manifest.data_sources {
-            let chain_id = Network::from(data_source.network).get_network_id();
+            let chain_id = chain_registry
+                .get_by_graph_name(&data_source.network)
+                .unwrap()
+                .id;
             println!("chainID: {}", chain_id);
         }
     }
@@ -567,10 +574,7 @@ mod test {
         let manifest_file = std::fs::read_to_string("test/configs/graph-manifest.yaml").unwrap();
         let network_contracts = super::generate_network_contract_hashmap(&manifest_file).await;
         let mut network_contracts_expected = HashMap::new();
-        network_contracts_expected.insert(
-            GraphNetwork::EthereumMainnet,
-            vec!["FiatTokenV1".to_string()],
-        );
+        network_contracts_expected.insert("mainnet".to_string(), vec!["FiatTokenV1".to_string()]);
         assert_eq!(network_contracts, network_contracts_expected);
     }
 
@@ -118,12 +118,23 @@ impl Display for HumanConfig {
     }
 }
 
+impl HumanConfig {
+    ///The serialized config used for the config hash
+    pub fn to_hash_string(&self) -> String {
+        match self {
+            HumanConfig::Evm(config) => config.to_hash_string(),
+            HumanConfig::Fuel(config) => config.to_string(),
+        }
+    }
+}
+
 pub mod evm {
     use super::{GlobalContract, NetworkContract, NetworkId};
     use crate::utils::normalized_list::SingleOrList;
     use schemars::JsonSchema;
     use serde::{Deserialize, Serialize};
-    use std::fmt::Display;
+    use sha2::{Digest, Sha256};
+    use std::{collections::BTreeMap, fmt::Display};
     use strum::Display;
     use subenum::subenum;
 
@@ -221,6 +232,37 @@ pub mod evm {
         }
     }
 
+    impl HumanConfig {
+        ///Same as to_string, but with the header values replaced by their digests,
+        ///so the credentials in them don't end up in the config hash, while
+        ///changing them still makes the generated sources be updated
+        pub fn to_hash_string(&self) -> String {
+            let mut networks = self.networks.clone();
+            for network in networks.iter_mut() {
+                if let Some(headers) = network
+                    .hypersync_config
+                    .as_mut()
+                    .and_then(|hypersync_config| hypersync_config.headers.as_mut())
+                {
+                    digest_header_values(headers);
+                }
+                let rpcs: &mut [Rpc] = match network.rpc.as_mut() {
+                    Some(NetworkRpc::Single(rpc)) => std::slice::from_mut(rpc),
+                    Some(NetworkRpc::List(rpcs)) => rpcs.as_mut_slice(),
+                    Some(NetworkRpc::Url(_)) | None => &mut [],
+                };
+                for headers in rpcs.iter_mut().filter_map(|rpc| rpc.headers.as_mut()) {
+                    digest_header_values(headers);
+                }
+            }
+
+            let mut config = serde_yaml::to_value(self).expect("Failed to serialize config");
+            config["networks"] =
+                serde_yaml::to_value(networks).expect("Failed to serialize networks");
+            serde_yaml::to_string(&config).expect("Failed to serialize config")
+        }
+    }
+
     #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, JsonSchema)]
     #[serde(deny_unknown_fields)]
     pub struct FieldSelection {
@@ -343,6 +385,23 @@ pub mod evm {
         HypersyncClient,
     }
 
+    pub type Headers = BTreeMap<String, String>;
+
+    ///Replaces the header values, which usually contain credentials, in printed output
+    pub const REDACTED_HEADER_VALUE: &str = "<redacted>";
+
+    ///Keyed with the header name, so equal values of different headers get different digests
+    fn digest_header_values(headers: &mut Headers) {
+        for (name, value) in headers.iter_mut() {
+            let digest = Sha256::new()
+                .chain_update(name.to_lowercase())
+                .chain_update([0u8])
+                .chain_update(value.as_bytes())
+                .finalize();
+            *value = format!("sha256:{:x}", digest);
+        }
+    }
+
     #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
     #[serde(deny_unknown_fields)]
     pub struct HypersyncConfig {
@@ -352,6 +411,15 @@ pub mod evm {
                            endpoint for the network)"
         )]
         pub url: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "HTTP headers to send with every request to the HyperSync endpoint. \
+                           Only the \"Authorization: Bearer <token>\" header is supported, \
+                           since HyperSync authenticates with an API token. Other headers, like \
+                           a tenant header, can only be sent to RPCs. Use ${ENV_VAR} to keep the \
+                           credentials out of the config file."
+        )]
+        pub headers: Option<Headers>,
     }
 
     #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, JsonSchema)]
@@ -417,6 +485,13 @@ pub mod evm {
                            in the future."
         )]
         Fallback,
+        #[schemars(
+            description = "Use a WebSocket RPC (ws:// or wss://) to detect new blocks with an \
+                           eth_subscribe(\"newHeads\") subscription. It only lowers the latency \
+                           at the head of the chain, the data is still fetched by the main \
+                           data-source."
+        )]
+        Realtime,
     }
 
     #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
@@ -430,6 +505,30 @@ pub mod evm {
         )]
         #[serde(rename = "for")]
         pub source_for: For,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "The share of requests this RPC gets relative to the other RPCs for \
+                           sync. Setting it on any of them enables load balancing between the \
+                           sync RPCs, where the ones that keep failing get fewer requests. \
+                           (default: 1)"
+        )]
+        pub weight: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "Limits the number of requests per second sent to the RPC, so it \
+                           doesn't respond with rate limit errors"
+        )]
+        pub max_requests_per_second: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(description = "The maximum number of blocks to request logs for at once")]
+        pub max_block_range: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "HTTP headers to send with every request to the RPC. Use ${ENV_VAR} \
+                           to keep the credentials out of the config file. Not supported for \
+                           realtime RPCs."
+        )]
+        pub headers: Option<Headers>,
         #[serde(flatten, skip_serializing_if = "Option::is_none")]
         #[schemars(description = "Options for RPC data-source indexing.")]
         pub sync_config: Option<RpcSyncConfig>,
@@ -443,6 +542,29 @@ pub mod evm {
         List(Vec<Rpc>),
     }
 
+    #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, JsonSchema, Display)]
+    #[serde(rename_all = "lowercase")]
+    #[strum(serialize_all = "lowercase")]
+    pub enum FinalityTag {
+        #[schemars(
+            description = "Blocks at or below the \"finalized\" block of the RPC can't be \
+                           reorged."
+        )]
+        Finalized,
+        #[schemars(
+            description = "Blocks at or below the \"safe\" block of the RPC are unlikely to be \
+                           reorged. Closer to the head than \"finalized\"."
+        )]
+        Safe,
+    }
+
+    #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
+    #[serde(untagged)]
+    pub enum Finality {
+        Tag(FinalityTag),
+        Blocks(u32),
+    }
+
     #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
     #[serde(deny_unknown_fields)]
     pub struct Network {
@@ -472,6 +594,16 @@ pub mod evm {
                            in case of reorgs."
         )]
         pub confirmed_block_threshold: Option<i32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "How far from the head a block can still be reorged. Use \"finalized\" \
+                           or \"safe\" to detect the depth from the RPC block tags on start, or \
+                           a number of blocks. The tags require an RPC and fall back to the \
+                           chain default when the RPC doesn't support them. When \
+                           confirmed_block_threshold is also set, the detected depth doesn't go \
+                           below it."
+        )]
+        pub finality: Option<Finality>,
         #[schemars(description = "The block at which the indexer should start ingesting data")]
         pub start_block: u64,
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -562,12 +694,45 @@ pub mod fuel {
                            contracts across multiple chains."
         )]
         pub contracts: Option<Vec<GlobalContract<ContractConfig>>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "Predicates to index the spends of. They are indexed on every network of \
+                           the project and are available as the Spend event of the predicate."
+        )]
+        pub predicates: Option<Vec<PredicateConfig>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "Scripts to index the runs and logs of. They are indexed on every \
+                           network of the project and the runs are available as the Run event of \
+                           the script."
+        )]
+        pub scripts: Option<Vec<ScriptConfig>>,
         #[schemars(
             description = "Configuration of the blockchain networks that the project is deployed \
                            on."
         )]
         pub networks: Vec<Network>,
         #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "A flag to indicate if the indexer should rollback to the last known \
+                           valid block on a reorg. This requires an extra block query for every \
+                           HyperFuel batch (default: false)"
+        )]
+        pub rollback_on_reorg: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "A flag to indicate if the indexer should save the full history of \
+                           events. This is useful for debugging but will increase the size of the \
+                           database (default: false)"
+        )]
+        pub save_full_history: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "Select the block and transaction fields to include in all events \
+                           globally"
+        )]
+        pub field_selection: Option<FieldSelection>,
+        #[serde(skip_serializing_if = "Option::is_none")]
         #[schemars(
             description = "If true, the indexer will store the raw event data in the database. \
                            This is useful for debugging, but will increase the size of the \
@@ -580,6 +745,12 @@ pub mod fuel {
             description = "Makes handlers run twice to enable preload optimisations. Removes handlerWithLoader API, since it's not needed. (recommended, default: false)"
         )]
         pub preload_handlers: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "If true, ABI types which can't be decoded are indexed as unknown \
+                           values instead of failing the codegen (default: false)"
+        )]
+        pub allow_unknown_types: Option<bool>,
     }
 
     impl Display for HumanConfig {
@@ -609,6 +780,36 @@ pub mod fuel {
         pub url: String,
     }
 
+    #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
+    #[serde(rename_all = "lowercase")]
+    pub enum For {
+        #[schemars(
+            description = "Use fuel-core as the main data-source for both historical sync and \
+                           real-time chain indexing."
+        )]
+        Sync,
+        #[schemars(
+            description = "Use fuel-core as a backup for HyperFuel. It acts as a fallback when \
+                           real-time indexing stalls."
+        )]
+        Fallback,
+    }
+
+    #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
+    #[serde(deny_unknown_fields)]
+    pub struct FuelCoreConfig {
+        #[schemars(description = "URL of the fuel-core GraphQL endpoint. Eg. \
+                           http://127.0.0.1:4000/v1/graphql")]
+        pub url: String,
+        #[serde(rename = "for", skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "Determines if fuel-core is used for historical sync and real-time \
+                           chain indexing, or as a fallback. (default: fallback when the network \
+                           has a HyperFuel endpoint, otherwise sync)"
+        )]
+        pub source_for: Option<For>,
+    }
+
     #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
     #[serde(deny_unknown_fields)]
     pub struct Network {
@@ -622,6 +823,20 @@ pub mod fuel {
         #[serde(skip_serializing_if = "Option::is_none")]
         #[schemars(description = "Optional HyperFuel Config for additional fine-tuning")]
         pub hyperfuel_config: Option<HyperfuelConfig>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "Optional fuel-core GraphQL endpoint. Required for networks without a \
+                           HyperFuel endpoint, like a local fuel-core node. Predicates, scripts \
+                           and block or transaction field selection aren't supported by \
+                           fuel-core, even when it's used as a fallback."
+        )]
+        pub fuel_core: Option<FuelCoreConfig>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "The number of blocks from the head that the indexer should account for \
+                           in case of reorgs."
+        )]
+        pub confirmed_block_threshold: Option<i32>,
         #[schemars(description = "All the contracts that should be indexed on the given network")]
         pub contracts: Vec<NetworkContract<ContractConfig>>,
     }
@@ -640,6 +855,47 @@ pub mod fuel {
         pub events: Vec<EventConfig>,
     }
 
+    #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
+    #[serde(deny_unknown_fields)]
+    pub struct PredicateConfig {
+        #[schemars(description = "A unique project-wide name for this predicate (no spaces)")]
+        pub name: String,
+        #[schemars(
+            description = "The bytecode root of the predicate. It's the same as the address \
+                           owning the coins spent by the predicate."
+        )]
+        pub root: String,
+        #[schemars(description = "Relative path (from config) to a json abi.")]
+        pub abi_file_path: String,
+        #[schemars(
+            description = "The relative path to a file where handlers are registered for the \
+                           given predicate"
+        )]
+        pub handler: String,
+    }
+
+    #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
+    #[serde(deny_unknown_fields)]
+    pub struct ScriptConfig {
+        #[schemars(description = "A unique project-wide name for this script (no spaces)")]
+        pub name: String,
+        #[schemars(description = "The bytecode root of the script.")]
+        pub root: String,
+        #[schemars(description = "Relative path (from config) to a json abi.")]
+        pub abi_file_path: String,
+        #[schemars(
+            description = "The relative path to a file where handlers are registered for the \
+                           given script"
+        )]
+        pub handler: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "A list of logged types from the script ABI that should be indexed in \
+                           addition to the Run event"
+        )]
+        pub events: Option<Vec<EventConfig>>,
+    }
+
     #[derive(Debug, Serialize, Clone, Deserialize, PartialEq, JsonSchema, Display)]
     #[serde(rename_all = "camelCase", deny_unknown_fields)]
     pub enum EventType {
@@ -669,6 +925,79 @@ pub mod fuel {
                            logged struct/enum name."
         )]
         pub log_id: Option<String>,
+        #[serde(rename = "field_selection", skip_serializing_if = "Option::is_none")]
+        #[schemars(
+            description = "Select the block and transaction fields to include in the specific \
+                           event"
+        )]
+        pub field_selection: Option<FieldSelection>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, JsonSchema)]
+    #[serde(deny_unknown_fields)]
+    pub struct FieldSelection {
+        #[schemars(
+            description = "The transaction fields to include in the event, or in all events if \
+                           applied globally"
+        )]
+        pub transaction_fields: Option<Vec<TransactionField>>,
+        #[schemars(
+            description = "The block fields to include in the event, or in all events if applied \
+                           globally"
+        )]
+        pub block_fields: Option<Vec<BlockField>>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Display, JsonSchema)]
+    #[serde(rename_all = "camelCase", deny_unknown_fields)]
+    pub enum TransactionField {
+        InputAssetIds,
+        InputContracts,
+        InputContractUtxoId,
+        InputContractBalanceRoot,
+        InputContractStateRoot,
+        InputContractTxPointerBlockHeight,
+        InputContractTxPointerTxIndex,
+        InputContract,
+        GasPrice,
+        GasLimit,
+        Maturity,
+        MintAmount,
+        MintAssetId,
+        TxPointerBlockHeight,
+        TxPointerTxIndex,
+        TxType,
+        OutputContractInputIndex,
+        OutputContractBalanceRoot,
+        OutputContractStateRoot,
+        Witnesses,
+        ReceiptsRoot,
+        Status,
+        Time,
+        Reason,
+        Script,
+        ScriptData,
+        BytecodeWitnessIndex,
+        BytecodeLength,
+        Salt,
+        //The transaction id is available by default
+        // Id,
+    }
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Display, JsonSchema)]
+    #[serde(rename_all = "camelCase", deny_unknown_fields)]
+    pub enum BlockField {
+        DaHeight,
+        TransactionsCount,
+        MessageReceiptCount,
+        TransactionsRoot,
+        PrevRoot,
+        ApplicationHash,
+        //These values are available by default on the block
+        //so no need to allow users to configure these values
+        // Id,
+        // Height,
+        // Time,
     }
 }
 
@@ -873,13 +1202,21 @@ address: ["0x2E645469f354BB4F5c8a05B3b30A929361cf77eC"]
             output: None,
             ecosystem: fuel::EcosystemTag::Fuel,
             contracts: None,
+            predicates: None,
+            scripts: None,
+            rollback_on_reorg: None,
+            save_full_history: None,
+            field_selection: None,
             raw_events: None,
             preload_handlers: None,
+            allow_unknown_types: None,
             networks: vec![fuel::Network {
                 id: 0,
                 start_block: 0,
                 end_block: None,
                 hyperfuel_config: None,
+                fuel_core: None,
+                confirmed_block_threshold: None,
                 contracts: vec![NetworkContract {
                     name: "Greeter".to_string(),
                     address: "0x4a2ce054e3e94155f7092f7365b212f7f45105b74819c623744ebcc5d065c6ac"
@@ -893,11 +1230,13 @@ address: ["0x2E645469f354BB4F5c8a05B3b30A929361cf77eC"]
                             fuel::EventConfig {
                                 name: "NewGreeting".to_string(),
                                 log_id: None,
+                                field_selection: None,
                                 type_: None,
                             },
                             fuel::EventConfig {
                                 name: "ClearGreeting".to_string(),
                                 log_id: None,
+                                field_selection: None,
                                 type_: None,
                             },
                         ],
@@ -919,8 +1258,14 @@ address: ["0x2E645469f354BB4F5c8a05B3b30A929361cf77eC"]
             output: None,
             ecosystem: fuel::EcosystemTag::Fuel,
             contracts: None,
+            predicates: None,
+            scripts: None,
+            rollback_on_reorg: None,
+            save_full_history: None,
+            field_selection: None,
             raw_events: None,
             preload_handlers: None,
+            allow_unknown_types: None,
             networks: vec![],
         };
 
@@ -970,10 +1315,54 @@ address: ["0x2E645469f354BB4F5c8a05B3b30A929361cf77eC"]
                 rpc: None,
                 start_block: 2_000,
                 confirmed_block_threshold: None,
+                finality: None,
                 end_block: Some(2_000_000),
                 contracts: vec![]
             },
             de
         );
     }
+
+    #[test]
+    fn redacts_header_values_from_the_hash_string() {
+        let config_with_token = |token: &str| -> HumanConfig {
+            serde_yaml::from_str(&format!(
+                r#"
+name: config
+networks:
+  - id: 1
+    start_block: 0
+    hypersync_config:
+      url: https://eth.hypersync.xyz
+      headers:
+        Authorization: Bearer {token}
+    rpc:
+      - url: https://eth.com
+        for: fallback
+        headers:
+          Authorization: Bearer {token}
+          X-Tenant: {token}
+    contracts: []
+"#
+            ))
+            .unwrap()
+        };
+        let config = config_with_token("secret");
+
+        assert!(
+            config.to_string().contains("Bearer secret"),
+            "The config should be serialized as is, so init writes the headers"
+        );
+        let hash_string = config.to_hash_string();
+        assert!(
+            !hash_string.contains("secret"),
+            "Header values shouldn't be in the hash string: {hash_string}"
+        );
+        assert!(hash_string.contains("sha256:"), "{hash_string}");
+        assert_ne!(
+            hash_string,
+            config_with_token("rotated").to_hash_string(),
+            "Changing a header value should change the config hash"
+        );
+    }
 }
@@ -1,35 +1,30 @@
-use anyhow::Context;
-
-use super::chain_helpers::{HypersyncNetwork, Network};
-
-pub fn network_to_hypersync_url(network: &HypersyncNetwork) -> String {
-    format!("https://{}.hypersync.xyz", *network as u64)
-}
-
-pub fn get_default_hypersync_endpoint(chain_id: u64) -> anyhow::Result<String> {
-    let network_name = Network::from_network_id(chain_id)
-        .context(format!("Getting network name from id ({})", chain_id))?;
-
-    let network = HypersyncNetwork::try_from(network_name).context(format!(
-        "Unsupported network (name: {}, id: {}) provided for hypersync",
-        network_name, chain_id
-    ))?;
-
-    Ok(network_to_hypersync_url(&network))
+use super::chain_registry::ChainRegistry;
+
+///The HyperSync url of the chain from the chain registry. None if the chain
+///isn't supported by HyperSync.
+pub fn get_default_hypersync_endpoint(chain_id: u64) -> anyhow::Result<Option<String>> {
+    Ok(ChainRegistry::global()?
+        .get_by_id(chain_id)
+        .and_then(|chain| chain.hypersync_url.clone()))
 }
 
 #[cfg(test)]
 mod test {
 
-    use crate::config_parsing::hypersync_endpoints::get_default_hypersync_endpoint;
-
-    use super::HypersyncNetwork;
-    use strum::IntoEnumIterator;
+    use crate::config_parsing::{
+        chain_registry::ChainRegistry, hypersync_endpoints::get_default_hypersync_endpoint,
+    };
 
     #[test]
     fn all_supported_chain_ids_return_a_hypersync_endpoint() {
-        for network in HypersyncNetwork::iter() {
-            let _ = get_default_hypersync_endpoint(network as u64).unwrap();
+        for chain in ChainRegistry::global().unwrap().iter_hypersync_chains() {
+            let _ = get_default_hypersync_endpoint(chain.id).unwrap().unwrap();
         }
     }
+
+    #[test]
+    fn unsupported_chain_ids_return_no_hypersync_endpoint() {
+        assert_eq!(get_default_hypersync_endpoint(5).unwrap(), None);
+        assert_eq!(get_default_hypersync_endpoint(987654321).unwrap(), None);
+    }
 }
@@ -1,10 +1,13 @@
 pub mod chain_helpers;
+pub mod chain_registry;
 pub mod contract_import;
+pub mod endpoint_check;
 pub mod entity_parsing;
 pub mod event_parsing;
 pub mod graph_migration;
 pub mod human_config;
 pub mod hypersync_endpoints;
+pub mod local_env_config;
 pub mod postgres_types;
 pub mod system_config;
 pub mod validation;
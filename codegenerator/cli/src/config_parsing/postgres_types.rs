@@ -7,11 +7,14 @@ pub enum Primitive {
     Boolean,
     Text,
     Integer,
+    BigInt,
     Numeric(Option<(u32, u32)>), // (precision, scale)
     DoublePrecision,
     Serial,
     JsonB,
     Timestamp,
+    Date,
+    Char(u32), // fixed length
     Enum(String),
 }
 
@@ -21,6 +24,7 @@ impl Primitive {
             Self::Boolean => "Boolean".to_string(),
             Self::Text => "Text".to_string(),
             Self::Integer => "Integer".to_string(),
+            Self::BigInt => "BigInt".to_string(),
             Self::Numeric(None) => "Numeric".to_string(),
             Self::Numeric(Some((precision, scale))) => {
                 format!("Custom(\"NUMERIC({}, {})\")", precision, scale)
@@ -28,6 +32,8 @@ impl Primitive {
             Self::Serial => "Serial".to_string(),
             Self::JsonB => "JsonB".to_string(),
             Self::Timestamp => "Timestamp".to_string(),
+            Self::Date => "Date".to_string(),
+            Self::Char(length) => format!("Custom(\"CHARACTER({})\")", length),
             Self::DoublePrecision => "DoublePrecision".to_string(),
             Self::Enum(enum_name) => format!("Custom(Enums.{enum_name}.config.name)"),
         }
@@ -1,13 +1,21 @@
 use super::{
-    chain_helpers::get_confirmed_block_threshold_from_id,
+    chain_helpers::{
+        get_confirmed_block_threshold_from_id, get_fuel_confirmed_block_threshold_from_id,
+    },
+    endpoint_check::{self, EndpointCheck},
     entity_parsing::{Entity, GraphQLEnum, Schema},
     human_config::{
         self,
         evm::{
-            EventConfig as EvmEventConfig, EventDecoder, For, HumanConfig as EvmConfig,
-            Network as EvmNetwork, NetworkRpc, Rpc,
+            EventConfig as EvmEventConfig, EventDecoder, Finality, FinalityTag, For, Headers,
+            HumanConfig as EvmConfig, Network as EvmNetwork, NetworkRpc, Rpc,
+            REDACTED_HEADER_VALUE,
+        },
+        fuel::{
+            EventConfig as FuelEventConfig, For as FuelCoreFor, HumanConfig as FuelConfig,
+            Network as FuelNetwork, PredicateConfig as FuelPredicateConfig,
+            ScriptConfig as FuelScriptConfig,
         },
-        fuel::{EventConfig as FuelEventConfig, HumanConfig as FuelConfig},
         HumanConfig,
     },
     hypersync_endpoints,
@@ -17,7 +25,13 @@ use crate::{
     config_parsing::human_config::evm::{RpcBlockField, RpcTransactionField},
     constants::{links, project_paths::DEFAULT_SCHEMA_PATH},
     evm::abi::AbiOrNestedAbi,
-    fuel::abi::{FuelAbi, BURN_EVENT_NAME, CALL_EVENT_NAME, MINT_EVENT_NAME, TRANSFER_EVENT_NAME},
+    fuel::{
+        abi::{
+            FuelAbi, BURN_EVENT_NAME, CALL_EVENT_NAME, MINT_EVENT_NAME, RUN_EVENT_NAME,
+            SPEND_EVENT_NAME, TRANSFER_EVENT_NAME,
+        },
+        address::Address as FuelAddress,
+    },
     project_paths::{path_utils, ParsedProjectPaths},
     rescript_types::RescriptTypeIdent,
     utils::unique_hashmap,
@@ -29,7 +43,10 @@ use itertools::Itertools;
 use regex::Regex;
 use std::{
     collections::{HashMap, HashSet},
-    env, fs,
+    env,
+    fmt::Display,
+    fs,
+    hash::Hash,
     path::{Component, Path, PathBuf},
 };
 
@@ -524,6 +541,106 @@ impl SystemConfig {
         Ok(filtered_unique_abi_files)
     }
 
+    ///Replaces the values of the configured headers, which usually contain credentials,
+    ///so the parts of the config derived from them can be printed
+    pub fn redact_headers(&mut self) {
+        let redact_rpc_headers = |rpc: &mut Rpc| {
+            if let Some(headers) = rpc.headers.as_mut() {
+                headers
+                    .values_mut()
+                    .for_each(|value| *value = REDACTED_HEADER_VALUE.to_string());
+            }
+        };
+
+        for network in self.networks.values_mut() {
+            if let DataSource::Evm { main, rpcs, .. } = &mut network.sync_source {
+                match main {
+                    MainEvmDataSource::HyperSync {
+                        api_token: Some(api_token),
+                        ..
+                    } => *api_token = REDACTED_HEADER_VALUE.to_string(),
+                    MainEvmDataSource::HyperSync {
+                        api_token: None, ..
+                    } => (),
+                    MainEvmDataSource::Rpc(rpc) => redact_rpc_headers(rpc),
+                }
+                rpcs.iter_mut().for_each(redact_rpc_headers);
+            }
+        }
+    }
+
+    ///Requests the chain id and the head block from the HyperSync, HyperFuel and RPC
+    ///endpoints of every network. Realtime RPCs are skipped, since they are WebSocket
+    ///endpoints. A check fails when the endpoint is unreachable or serves another chain.
+    pub async fn check_endpoints(&self) -> Vec<EndpointCheck> {
+        let mut checks = vec![];
+        for network in self.get_networks() {
+            match &network.sync_source {
+                DataSource::Evm { main, rpcs, .. } => {
+                    if let MainEvmDataSource::HyperSync {
+                        hypersync_endpoint_url,
+                        api_token,
+                    } = main
+                    {
+                        checks.push(
+                            EndpointCheck::run(
+                                network.id,
+                                format!("HyperSync {hypersync_endpoint_url}"),
+                                endpoint_check::check_hypersync(
+                                    hypersync_endpoint_url,
+                                    api_token.as_deref(),
+                                ),
+                            )
+                            .await,
+                        );
+                    }
+                    for rpc in rpcs.iter().filter(|rpc| rpc.source_for != For::Realtime) {
+                        checks.push(
+                            EndpointCheck::run(
+                                network.id,
+                                format!("RPC {}", rpc.url),
+                                endpoint_check::check_rpc(&rpc.url, rpc.headers.as_ref()),
+                            )
+                            .await,
+                        );
+                    }
+                }
+                DataSource::Fuel {
+                    main,
+                    fuel_core_fallback_url,
+                } => {
+                    let fuel_core_url = match main {
+                        MainFuelDataSource::HyperFuel {
+                            hypersync_endpoint_url,
+                        } => {
+                            checks.push(
+                                EndpointCheck::run(
+                                    network.id,
+                                    format!("HyperFuel {hypersync_endpoint_url}"),
+                                    endpoint_check::check_hyperfuel(hypersync_endpoint_url),
+                                )
+                                .await,
+                            );
+                            fuel_core_fallback_url.as_ref()
+                        }
+                        MainFuelDataSource::FuelCore { url } => Some(url),
+                    };
+                    if let Some(url) = fuel_core_url {
+                        checks.push(
+                            EndpointCheck::run(
+                                network.id,
+                                format!("fuel-core {url}"),
+                                endpoint_check::check_fuel_core(url),
+                            )
+                            .await,
+                        );
+                    }
+                }
+            }
+        }
+        checks
+    }
+
     pub fn from_human_config(
         human_config: HumanConfig,
         schema: Schema,
@@ -678,11 +795,12 @@ impl SystemConfig {
                         })
                         .collect();
 
+                    let (confirmed_block_threshold, finality) = get_evm_finality(network)?;
+
                     let network = Network {
                         id: network.id,
-                        confirmed_block_threshold: network
-                            .confirmed_block_threshold
-                            .unwrap_or(get_confirmed_block_threshold_from_id(network.id)),
+                        confirmed_block_threshold,
+                        finality,
                         start_block: network.start_block,
                         end_block: network.end_block,
                         sync_source,
@@ -725,6 +843,24 @@ impl SystemConfig {
                 })
             }
             HumanConfig::Fuel(ref fuel_config) => {
+                //The fallback source should support everything the sync source indexes,
+                //so fuel-core limitations apply to both of them
+                let mut has_fuel_core_src = false;
+                for network in &fuel_config.networks {
+                    if let DataSource::Fuel {
+                        main: MainFuelDataSource::FuelCore { .. },
+                        ..
+                    }
+                    | DataSource::Fuel {
+                        fuel_core_fallback_url: Some(_),
+                        ..
+                    } = DataSource::from_fuel_network_config(network)?
+                    {
+                        has_fuel_core_src = true;
+                    }
+                }
+                let allow_unknown_types = fuel_config.allow_unknown_types.unwrap_or(false);
+
                 //Add all global contracts
                 if let Some(global_contracts) = &fuel_config.contracts {
                     for g_contract in global_contracts {
@@ -732,6 +868,8 @@ impl SystemConfig {
                             &g_contract.config.events,
                             &g_contract.config.abi_file_path,
                             &final_project_paths,
+                            has_fuel_core_src,
+                            allow_unknown_types,
                         )
                         .context(format!(
                             "Failed parsing abi types for events in global contract {}",
@@ -751,6 +889,91 @@ impl SystemConfig {
                     }
                 }
 
+                //Predicates and scripts aren't deployed, so they are
+                //indexed by their bytecode root on every network
+                let mut program_network_contracts: Vec<NetworkContract> = vec![];
+
+                for predicate in fuel_config.predicates.iter().flatten() {
+                    if has_fuel_core_src {
+                        return Err(anyhow!(
+                            "Predicate {} can't be indexed using fuel-core as the sync or fallback \
+                             source. Please use only HyperFuel for the predicate indexing.",
+                            predicate.name
+                        ));
+                    }
+                    let (events, fuel_abi) = Event::from_fuel_predicate_config(
+                        predicate,
+                        &final_project_paths,
+                        allow_unknown_types,
+                    )
+                    .context(format!(
+                        "Failed parsing abi types for predicate {}",
+                        predicate.name,
+                    ))?;
+
+                    let contract = Contract::new(
+                        predicate.name.clone(),
+                        predicate.handler.clone(),
+                        events,
+                        Abi::fuel(fuel_abi),
+                    )?;
+
+                    unique_hashmap::try_insert(&mut contracts, contract.name.clone(), contract)
+                        .context(format!(
+                            "Failed inserting predicate {}. Predicates, scripts and contracts \
+                             should have unique names",
+                            predicate.name
+                        ))?;
+
+                    program_network_contracts.push(NetworkContract {
+                        name: predicate.name.clone(),
+                        addresses: vec![parse_fuel_bytecode_root(
+                            &predicate.root,
+                            &predicate.name,
+                        )?],
+                        start_block: None,
+                    });
+                }
+
+                for script in fuel_config.scripts.iter().flatten() {
+                    if has_fuel_core_src {
+                        return Err(anyhow!(
+                            "Script {} can't be indexed using fuel-core as the sync or fallback \
+                             source. Please use only HyperFuel for the script indexing.",
+                            script.name
+                        ));
+                    }
+                    let (events, fuel_abi) = Event::from_fuel_script_config(
+                        script,
+                        &final_project_paths,
+                        allow_unknown_types,
+                    )
+                    .context(format!(
+                        "Failed parsing abi types for script {}",
+                        script.name,
+                    ))?;
+
+                    let contract = Contract::new(
+                        script.name.clone(),
+                        script.handler.clone(),
+                        events,
+                        Abi::fuel(fuel_abi),
+                    )?;
+
+                    unique_hashmap::try_insert(&mut contracts, contract.name.clone(), contract)
+                        .context(format!(
+                            "Failed inserting script {}. Predicates, scripts and contracts \
+                             should have unique names",
+                            script.name
+                        ))?;
+
+                    program_network_contracts.push(NetworkContract {
+                        name: script.name.clone(),
+                        addresses: vec![parse_fuel_bytecode_root(&script.root, &script.name)?],
+                        start_block: None,
+                    });
+                }
+
                 for network in &fuel_config.networks {
                     for contract in network.contracts.clone() {
                         //Add values for local contract
@@ -760,6 +983,8 @@ impl SystemConfig {
                                     &l_contract.events,
                                     &l_contract.abi_file_path,
                                     &final_project_paths,
+                                    has_fuel_core_src,
+                                    allow_unknown_types,
                                 )
                                 .context(format!(
                                     "Failed parsing abi types for events in contract {} on \
@@ -802,21 +1027,7 @@ impl SystemConfig {
                         }
                     }
 
-                    let sync_source = DataSource::Fuel {
-                        hypersync_endpoint_url: match &network.hyperfuel_config {
-                            Some(config) => config.url.clone(),
-                            None => match network.id {
-                                0 => "https://fuel-testnet.hypersync.xyz".to_string(),
-                                9889 => "https://fuel.hypersync.xyz".to_string(),
-                                _ => {
-                                    return Err(anyhow!(
-                                        "Fuel network id {} is not supported",
-                                        network.id
-                                    ))
-                                }
-                            },
-                        },
-                    };
+                    let sync_source = DataSource::from_fuel_network_config(network)?;
 
                     let contracts: Vec<NetworkContract> = network
                         .contracts
@@ -827,13 +1038,17 @@ impl SystemConfig {
                             addresses: c.address.into(),
                             start_block: c.start_block,
                         })
+                        .chain(program_network_contracts.iter().cloned())
                         .collect();
 
                     let network = Network {
                         id: network.id,
                         start_block: network.start_block,
                         end_block: network.end_block,
-                        confirmed_block_threshold: 0,
+                        confirmed_block_threshold: network
+                            .confirmed_block_threshold
+                            .unwrap_or(get_fuel_confirmed_block_threshold_from_id(network.id)),
+                        finality: None,
                         sync_source,
                         contracts,
                     };
@@ -852,10 +1067,18 @@ impl SystemConfig {
                     networks,
                     contracts,
                     unordered_multichain_mode: false,
-                    rollback_on_reorg: false,
-                    save_full_history: false,
+                    rollback_on_reorg: fuel_config.rollback_on_reorg.unwrap_or(false),
+                    save_full_history: fuel_config.save_full_history.unwrap_or(false),
                     schema,
-                    field_selection: FieldSelection::fuel(),
+                    field_selection: FieldSelection::try_from_fuel_config_field_selection(
+                        fuel_config.field_selection.clone().unwrap_or(
+                            human_config::fuel::FieldSelection {
+                                transaction_fields: None,
+                                block_fields: None,
+                            },
+                        ),
+                        has_fuel_core_src,
+                    )?,
                     enable_raw_events: fuel_config.raw_events.unwrap_or(false),
                     preload_handlers: fuel_config.preload_handlers.unwrap_or(false),
                     human_config,
@@ -864,13 +1087,29 @@ impl SystemConfig {
         }
     }
 
+    fn read_human_config_string(project_paths: &ParsedProjectPaths) -> Result<String> {
+        std::fs::read_to_string(&project_paths.config).context(format!(
+            "EE104: Failed to resolve config path {0}. Make sure you're in the correct \
+             directory and that a config file with the name {0} exists. I can configure another path by using the --config flag.",
+            &project_paths.config.to_str().unwrap_or("{unknown}"),
+        ))
+    }
+
+    ///Names of the environment variables referenced in the config file. Fails when
+    ///a variable without a default isn't set in the environment or the .env file.
+    pub fn get_config_env_variables(project_paths: &ParsedProjectPaths) -> Result<Vec<String>> {
+        let human_config_string = Self::read_human_config_string(project_paths)?;
+        let mut env_state = EnvState::new(&project_paths.project_root);
+        let mut names = Vec::new();
+        interpolation::interpolate_config_variables(human_config_string, |name| {
+            names.push(name.to_string());
+            env_state.var(name)
+        })?;
+        Ok(names.into_iter().unique().collect())
+    }
+
     pub fn parse_from_project_files(project_paths: &ParsedProjectPaths) -> Result<Self> {
-        let human_config_string =
-            std::fs::read_to_string(&project_paths.config).context(format!(
-                "EE104: Failed to resolve config path {0}. Make sure you're in the correct \
-                 directory and that a config file with the name {0} exists. I can configure another path by using the --config flag.",
-                &project_paths.config.to_str().unwrap_or("{unknown}"),
-            ))?;
+        let human_config_string = Self::read_human_config_string(project_paths)?;
 
         let mut env_state = EnvState::new(&project_paths.project_root);
         let human_config_string =
@@ -929,7 +1168,10 @@ type ServerUrl = String;
 /// for ConfigYAML, so we don't break backward compatibility
 #[derive(Debug, Clone, PartialEq)]
 pub enum MainEvmDataSource {
-    HyperSync { hypersync_endpoint_url: ServerUrl },
+    HyperSync {
+        hypersync_endpoint_url: ServerUrl,
+        api_token: Option<String>,
+    },
     Rpc(Rpc),
 }
 
@@ -941,10 +1183,17 @@ pub enum DataSource {
         rpcs: Vec<Rpc>,
     },
     Fuel {
-        hypersync_endpoint_url: ServerUrl,
+        main: MainFuelDataSource,
+        fuel_core_fallback_url: Option<ServerUrl>,
     },
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub enum MainFuelDataSource {
+    HyperFuel { hypersync_endpoint_url: ServerUrl },
+    FuelCore { url: ServerUrl },
+}
+
 // Check if the given URL is valid in terms of formatting
 fn parse_url(url: &str) -> Option<String> {
     // Check URL format
@@ -956,7 +1205,78 @@ fn parse_url(url: &str) -> Option<String> {
     Some(trimmed_url)
 }
 
+// Same as parse_url but for WebSocket URLs
+fn parse_ws_url(url: &str) -> Option<String> {
+    if !url.starts_with("ws://") && !url.starts_with("wss://") {
+        return None;
+    }
+    let trimmed_url = url.trim_end_matches('/').to_string();
+    Some(trimmed_url)
+}
+
 impl DataSource {
+    fn from_fuel_network_config(network: &FuelNetwork) -> Result<Self> {
+        let hyperfuel_url = match &network.hyperfuel_config {
+            Some(config) => Some(config.url.clone()),
+            None => match network.id {
+                0 => Some("https://fuel-testnet.hypersync.xyz".to_string()),
+                9889 => Some("https://fuel.hypersync.xyz".to_string()),
+                _ => None,
+            },
+        };
+
+        let fuel_core_url = match &network.fuel_core {
+            Some(fuel_core) => Some(parse_url(&fuel_core.url).ok_or(anyhow!(
+                "EE112: The fuel-core url \"{}\" is incorrect format. The url needs to start \
+                 with either http:// or https://",
+                fuel_core.url
+            ))?),
+            None => None,
+        };
+        let fuel_core_for = network.fuel_core.as_ref().map(|fuel_core| {
+            fuel_core.source_for.clone().unwrap_or(match hyperfuel_url {
+                Some(_) => FuelCoreFor::Fallback,
+                None => FuelCoreFor::Sync,
+            })
+        });
+
+        let (main, fuel_core_fallback_url) = match (hyperfuel_url, fuel_core_url, fuel_core_for) {
+            (_, Some(url), Some(FuelCoreFor::Sync)) => {
+                if network.hyperfuel_config.is_some() {
+                    Err(anyhow!(
+                        "EE113: Fuel network {} has both hyperfuel_config and fuel_core for \
+                         sync defined. Please remove one of them or use fuel_core for fallback",
+                        network.id
+                    ))?
+                }
+                (MainFuelDataSource::FuelCore { url }, None)
+            }
+            (Some(url), fuel_core_url, _) => {
+                let hypersync_endpoint_url = parse_url(&url).ok_or(anyhow!(
+                    "EE106: The HyperFuel URL \"{}\" is in incorrect format. The URL needs to \
+                     start with either http:// or https://",
+                    url
+                ))?;
+                (
+                    MainFuelDataSource::HyperFuel {
+                        hypersync_endpoint_url,
+                    },
+                    fuel_core_url,
+                )
+            }
+            (None, _, _) => Err(anyhow!(
+                "EE113: Fuel network id {} doesn't have a default HyperFuel endpoint. Please \
+                 set hyperfuel_config or a fuel_core url to use for sync",
+                network.id
+            ))?,
+        };
+
+        Ok(Self::Fuel {
+            main,
+            fuel_core_fallback_url,
+        })
+    }
+
     fn from_evm_network_config(
         network: EvmNetwork,
         event_decoder: Option<EventDecoder>,
@@ -967,19 +1287,22 @@ impl DataSource {
         };
         let hypersync_endpoint_url = match &network.hypersync_config {
             Some(config) => Some(config.url.to_string()),
-            None => match hypersync_endpoints::get_default_hypersync_endpoint(network.id) {
-                Ok(url) => Some(url),
-                Err(_) => None,
-            },
+            None => hypersync_endpoints::get_default_hypersync_endpoint(network.id)?,
         };
         let raw_rpcs = match (network.rpc_config, network.rpc) {
             (Some(_), Some(_)) => Err(anyhow!("EE106: Cannot define both rpc and deprecated rpc_config for the same network, please only use the rpc option. Read more in our docs https://docs.envio.dev/docs/configuration-file"))?,
             (None, Some(NetworkRpc::Url(url))) => vec![Rpc {
                 url: url.to_string(),
                 source_for: match hypersync_endpoint_url {
+                  // A WebSocket url can't be used for fetching data
+                  _ if parse_ws_url(&url).is_some() => For::Realtime,
                   Some(_) => For::Fallback,
                   None => For::Sync,
                 },
+                weight: None,
+                max_requests_per_second: None,
+                max_block_range: None,
+                headers: None,
                 sync_config: None,
             }],
             (None, Some(NetworkRpc::Single(rpc))) => vec![rpc],
@@ -991,6 +1314,10 @@ impl DataSource {
               .map(|url| Rpc {
                   url: url.to_string(),
                   source_for: For::Sync,
+                  weight: None,
+                  max_requests_per_second: None,
+                  max_block_range: None,
+                  headers: None,
                   sync_config: rpc_config.sync_config.clone(),
               })
               .collect()
@@ -1000,13 +1327,33 @@ impl DataSource {
 
         let mut rpcs = vec![];
         for rpc in raw_rpcs.iter() {
-            match parse_url(rpc.url.as_str()) {
-              None => return Err(anyhow!("EE109: The RPC url \"{}\" is incorrect format. The RPC url needs to start with either http:// or https://", rpc.url)),
-              Some(url) => rpcs.push(Rpc {
-                  url,
-                  ..rpc.clone()
-              })
+            for (option_name, value) in [
+                ("weight", rpc.weight),
+                ("max_requests_per_second", rpc.max_requests_per_second),
+                ("max_block_range", rpc.max_block_range),
+            ] {
+                if value == Some(0) {
+                    return Err(anyhow!(
+                        "EE117: The {} of the RPC \"{}\" must be greater than 0",
+                        option_name,
+                        rpc.url
+                    ));
+                }
             }
+
+            if rpc.source_for == For::Realtime && rpc.headers.is_some() {
+                return Err(anyhow!(
+                    "EE118: Headers aren't supported for the realtime RPC \"{}\". Please remove \
+                     them or use an RPC for sync instead",
+                    rpc.url
+                ));
+            }
+
+            let url = match rpc.source_for {
+              For::Realtime => parse_ws_url(rpc.url.as_str()).ok_or(anyhow!("EE116: The realtime RPC url \"{}\" is incorrect format. The realtime RPC url needs to start with either ws:// or wss://", rpc.url))?,
+              For::Sync | For::Fallback => parse_url(rpc.url.as_str()).ok_or(anyhow!("EE109: The RPC url \"{}\" is incorrect format. The RPC url needs to start with either http:// or https://. WebSocket urls are only supported with \"for: realtime\"", rpc.url))?,
+            };
+            rpcs.push(Rpc { url, ..rpc.clone() })
         }
 
         let rpc_for_sync = rpcs.iter().find(|rpc| rpc.source_for == For::Sync);
@@ -1031,8 +1378,18 @@ impl DataSource {
                   url
                 ))?;
 
+                let api_token = match network
+                    .hypersync_config
+                    .as_ref()
+                    .and_then(|config| config.headers.as_ref())
+                {
+                    Some(headers) => get_hypersync_api_token(headers)?,
+                    None => None,
+                };
+
                 MainEvmDataSource::HyperSync {
                     hypersync_endpoint_url: parsed_url,
+                    api_token,
                 }
             }
         };
@@ -1045,6 +1402,74 @@ impl DataSource {
     }
 }
 
+///Returns the confirmed block threshold and the finality tag. The tag
+///is used by the indexer to detect the reorg depth from the RPC on start,
+///while the threshold is the fallback when no RPC supports it.
+fn get_evm_finality(network: &EvmNetwork) -> Result<(i32, Option<FinalityConfig>)> {
+    match (network.confirmed_block_threshold, &network.finality) {
+        (Some(_), Some(Finality::Blocks(_))) => Err(anyhow!(
+            "EE120: Cannot define both confirmed_block_threshold and a number of finality blocks \
+             for the network {}. Please only use one of them.",
+            network.id
+        )),
+        (_, Some(Finality::Tag(tag))) if network.rpc.is_none() && network.rpc_config.is_none() => {
+            Err(anyhow!(
+                "EE120: The \"{}\" finality of the network {} is read from the RPC block tags, \
+                 but the network doesn't have an RPC. Please add an rpc to the network or use \
+                 confirmed_block_threshold instead.",
+                tag,
+                network.id
+            ))
+        }
+        (Some(threshold), Some(Finality::Tag(tag))) => Ok((
+            threshold,
+            Some(FinalityConfig {
+                tag: *tag,
+                min_confirmed_block_threshold: Some(threshold),
+            }),
+        )),
+        (Some(threshold), None) => Ok((threshold, None)),
+        (None, Some(Finality::Blocks(blocks))) => {
+            let threshold = i32::try_from(*blocks).context(format!(
+                "EE120: The finality of the network {} is too large",
+                network.id
+            ))?;
+            Ok((threshold, None))
+        }
+        (None, Some(Finality::Tag(tag))) => Ok((
+            get_confirmed_block_threshold_from_id(network.id)?,
+            Some(FinalityConfig {
+                tag: *tag,
+                min_confirmed_block_threshold: None,
+            }),
+        )),
+        (None, None) => Ok((get_confirmed_block_threshold_from_id(network.id)?, None)),
+    }
+}
+
+///The HyperSync client authenticates only with a bearer token,
+///so it's the only header which can be passed to it
+fn get_hypersync_api_token(headers: &Headers) -> Result<Option<String>> {
+    let mut api_token = None;
+    for (name, value) in headers {
+        match value.strip_prefix("Bearer ") {
+            Some(token) if name.eq_ignore_ascii_case("authorization") => {
+                api_token = Some(token.to_string())
+            }
+            _ => {
+                return Err(anyhow!(
+                    "EE118: The \"{}\" header isn't supported for HyperSync. HyperSync \
+                     authenticates with an API token, so only the \"Authorization: Bearer \
+                     <token>\" header can be set in hypersync_config. Other headers can only be \
+                     sent to RPCs.",
+                    name
+                ))
+            }
+        }
+    }
+    Ok(api_token)
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Network {
     pub id: u64,
@@ -1052,9 +1477,18 @@ pub struct Network {
     pub start_block: u64,
     pub end_block: Option<u64>,
     pub confirmed_block_threshold: i32,
+    pub finality: Option<FinalityConfig>,
     pub contracts: Vec<NetworkContract>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FinalityConfig {
+    pub tag: FinalityTag,
+    ///The confirmed_block_threshold set together with the tag.
+    ///The detected reorg depth doesn't go below it.
+    pub min_confirmed_block_threshold: Option<i32>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct NetworkContract {
     pub name: ContractNameKey,
@@ -1227,6 +1661,24 @@ pub enum FuelEventKind {
     Burn,
     Transfer,
     Call,
+    //The type idents are for the decoded main function arguments
+    PredicateSpend(RescriptTypeIdent),
+    ScriptRun(RescriptTypeIdent),
+    ScriptLogData(RescriptTypeIdent),
+}
+
+//Bytecode roots are used the same way as contract addresses,
+//so they should be normalized the same way
+fn parse_fuel_bytecode_root(root: &str, program_name: &str) -> Result<String> {
+    match FuelAddress::new(root) {
+        Ok(address) => Ok(address.to_string()),
+        Err(_) => Err(anyhow!(
+            "EE114: The bytecode root \"{}\" of {} is invalid. It should be a 32 bytes hex \
+             string starting with 0x.",
+            root,
+            program_name
+        )),
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -1351,19 +1803,91 @@ impl Event {
         ))
     }
 
+    fn parse_fuel_abi(abi_file_path: &str, project_paths: &ParsedProjectPaths) -> Result<FuelAbi> {
+        let abi_path: PathBuf = path_utils::get_config_path_relative_to_root(
+            project_paths,
+            PathBuf::from(&abi_file_path),
+        )
+        .context("Failed to get path to ABI relative to the root of the project")?;
+        FuelAbi::parse(abi_path).context("Failed to parse ABI".to_string())
+    }
+
+    pub fn from_fuel_predicate_config(
+        predicate_config: &FuelPredicateConfig,
+        project_paths: &ParsedProjectPaths,
+        allow_unknown_types: bool,
+    ) -> Result<(Vec<Self>, FuelAbi)> {
+        let fuel_abi = Self::parse_fuel_abi(&predicate_config.abi_file_path, project_paths)?;
+        if !allow_unknown_types {
+            fuel_abi.check_main_args_types(&format!("{SPEND_EVENT_NAME}.data"))?;
+        }
+
+        let events = vec![Event {
+            name: SPEND_EVENT_NAME.to_string(),
+            kind: EventKind::Fuel(FuelEventKind::PredicateSpend(
+                fuel_abi.get_main_args_type()?,
+            )),
+            sighash: "predicate_spend".to_string(),
+            field_selection: None,
+        }];
+
+        Ok((events, fuel_abi))
+    }
+
+    pub fn from_fuel_script_config(
+        script_config: &FuelScriptConfig,
+        project_paths: &ParsedProjectPaths,
+        allow_unknown_types: bool,
+    ) -> Result<(Vec<Self>, FuelAbi)> {
+        let (log_events, fuel_abi) = Self::from_fuel_events_config(
+            script_config.events.as_deref().unwrap_or_default(),
+            &script_config.abi_file_path,
+            project_paths,
+            // Scripts are validated to not use fuel-core as a source
+            false,
+            allow_unknown_types,
+        )?;
+        if !allow_unknown_types {
+            fuel_abi.check_main_args_types(RUN_EVENT_NAME)?;
+        }
+
+        let mut events = vec![Event {
+            name: RUN_EVENT_NAME.to_string(),
+            kind: EventKind::Fuel(FuelEventKind::ScriptRun(fuel_abi.get_main_args_type()?)),
+            sighash: "script_run".to_string(),
+            field_selection: None,
+        }];
+
+        for event in log_events {
+            match event.kind {
+                EventKind::Fuel(FuelEventKind::LogData(data_type)) => events.push(Event {
+                    kind: EventKind::Fuel(FuelEventKind::ScriptLogData(data_type)),
+                    ..event
+                }),
+                _ => {
+                    return Err(anyhow!(
+                        "Event '{}' of script {} is not a logged type. Only logged types can be \
+                         indexed for scripts.",
+                        event.name,
+                        script_config.name
+                    ))
+                }
+            }
+        }
+
+        Ok((events, fuel_abi))
+    }
+
     pub fn from_fuel_events_config(
         events_config: &[FuelEventConfig],
         abi_file_path: &str,
         project_paths: &ParsedProjectPaths,
+        has_fuel_core_src: bool,
+        allow_unknown_types: bool,
     ) -> Result<(Vec<Self>, FuelAbi)> {
         use human_config::fuel::EventType;
 
-        let abi_path: PathBuf = path_utils::get_config_path_relative_to_root(
-            project_paths,
-            PathBuf::from(&abi_file_path),
-        )
-        .context("Failed to get path to ABI relative to the root of the project")?;
-        let fuel_abi = FuelAbi::parse(abi_path).context("Failed to parse ABI".to_string())?;
+        let fuel_abi = Self::parse_fuel_abi(abi_file_path, project_paths)?;
 
         let mut events = vec![];
 
@@ -1392,6 +1916,16 @@ impl Event {
                     event_type
                 ));
             }
+            let field_selection = match event_config.field_selection {
+                Some(ref selection_config) => {
+                    Some(FieldSelection::try_from_fuel_config_field_selection(
+                        selection_config.clone(),
+                        has_fuel_core_src,
+                    )?)
+                }
+                None => None,
+            };
+
             let event = match event_type {
                 EventType::LogData => {
                     let log = match &event_config.log_id {
@@ -1406,36 +1940,39 @@ impl Event {
                         }
                         Some(log_id) => fuel_abi.get_log(log_id)?,
                     };
+                    if !allow_unknown_types {
+                        fuel_abi.check_log_types(&log, &event_config.name)?;
+                    }
                     Event {
                         name: event_config.name.clone(),
                         kind: EventKind::Fuel(FuelEventKind::LogData(log.data_type)),
                         sighash: log.id,
-                        field_selection: None,
+                        field_selection: field_selection.clone(),
                     }
                 }
                 EventType::Mint => Event {
                     name: event_config.name.clone(),
                     kind: EventKind::Fuel(FuelEventKind::Mint),
                     sighash: "mint".to_string(),
-                    field_selection: None,
+                    field_selection: field_selection.clone(),
                 },
                 EventType::Burn => Event {
                     name: event_config.name.clone(),
                     kind: EventKind::Fuel(FuelEventKind::Burn),
                     sighash: "burn".to_string(),
-                    field_selection: None,
+                    field_selection: field_selection.clone(),
                 },
                 EventType::Transfer => Event {
                     name: event_config.name.clone(),
                     kind: EventKind::Fuel(FuelEventKind::Transfer),
                     sighash: "transfer".to_string(),
-                    field_selection: None,
+                    field_selection: field_selection.clone(),
                 },
                 EventType::Call => Event {
                     name: event_config.name.clone(),
                     kind: EventKind::Fuel(FuelEventKind::Call),
                     sighash: "call".to_string(),
-                    field_selection: None,
+                    field_selection: field_selection.clone(),
                 },
             };
 
@@ -1506,18 +2043,14 @@ impl FieldSelection {
         )
     }
 
-    pub fn try_from_config_field_selection(
-        field_selection_cfg: human_config::evm::FieldSelection,
-        // For validating transaction field selection with rpc
-        has_rpc_sync_src: bool,
-    ) -> Result<Self> {
-        use human_config::evm::BlockField;
-        use human_config::evm::TransactionField;
-
-        let transaction_fields = field_selection_cfg.transaction_fields.unwrap_or_default();
-        let block_fields = field_selection_cfg.block_fields.unwrap_or_default();
-
-        //Validate no duplicates in field selection
+    fn validate_no_duplicates<Tx, Block>(
+        transaction_fields: &[Tx],
+        block_fields: &[Block],
+    ) -> Result<()>
+    where
+        Tx: Display + Eq + Hash,
+        Block: Display + Eq + Hash,
+    {
         let tx_duplicates: Vec<_> = transaction_fields.iter().duplicates().collect();
 
         if !tx_duplicates.is_empty() {
@@ -1536,6 +2069,114 @@ impl FieldSelection {
             ));
         }
 
+        Ok(())
+    }
+
+    pub fn try_from_fuel_config_field_selection(
+        field_selection_cfg: human_config::fuel::FieldSelection,
+        // fuel-core source provides only the default fields
+        has_fuel_core_src: bool,
+    ) -> Result<Self> {
+        use human_config::fuel::BlockField;
+        use human_config::fuel::TransactionField;
+
+        let transaction_fields = field_selection_cfg.transaction_fields.unwrap_or_default();
+        let block_fields = field_selection_cfg.block_fields.unwrap_or_default();
+
+        Self::validate_no_duplicates(&transaction_fields, &block_fields)?;
+
+        if has_fuel_core_src && !(transaction_fields.is_empty() && block_fields.is_empty()) {
+            return Err(anyhow!(
+                "The following selected fields are unavailable for indexing via fuel-core: {}",
+                transaction_fields
+                    .iter()
+                    .map(|f| f.to_string())
+                    .chain(block_fields.iter().map(|f| f.to_string()))
+                    .join(", ")
+            ));
+        }
+
+        let Self {
+            transaction_fields: mut selected_transaction_fields,
+            block_fields: mut selected_block_fields,
+        } = Self::fuel();
+
+        type Res = RescriptTypeIdent;
+        type Block = BlockField;
+        type Tx = TransactionField;
+
+        for block_field in block_fields {
+            let data_type = match block_field {
+                Block::DaHeight => Res::Int,
+                Block::TransactionsCount => Res::Int,
+                Block::MessageReceiptCount => Res::Int,
+                Block::TransactionsRoot => Res::String,
+                Block::PrevRoot => Res::String,
+                Block::ApplicationHash => Res::String,
+            };
+            selected_block_fields.push(SelectedField {
+                name: block_field.to_string(),
+                data_type,
+            })
+        }
+
+        for transaction_field in transaction_fields {
+            let data_type = match transaction_field {
+                Tx::InputAssetIds => Res::option(Res::array(Res::String)),
+                Tx::InputContracts => Res::option(Res::array(Res::String)),
+                Tx::InputContractUtxoId => Res::option(Res::String),
+                Tx::InputContractBalanceRoot => Res::option(Res::String),
+                Tx::InputContractStateRoot => Res::option(Res::String),
+                Tx::InputContractTxPointerBlockHeight => Res::option(Res::Int),
+                Tx::InputContractTxPointerTxIndex => Res::option(Res::Int),
+                Tx::InputContract => Res::option(Res::String),
+                Tx::GasPrice => Res::option(Res::BigInt),
+                Tx::GasLimit => Res::option(Res::BigInt),
+                Tx::Maturity => Res::option(Res::Int),
+                Tx::MintAmount => Res::option(Res::BigInt),
+                Tx::MintAssetId => Res::option(Res::String),
+                Tx::TxPointerBlockHeight => Res::option(Res::Int),
+                Tx::TxPointerTxIndex => Res::option(Res::Int),
+                Tx::TxType => Res::Int,
+                Tx::OutputContractInputIndex => Res::option(Res::Int),
+                Tx::OutputContractBalanceRoot => Res::option(Res::String),
+                Tx::OutputContractStateRoot => Res::option(Res::String),
+                Tx::Witnesses => Res::option(Res::array(Res::String)),
+                Tx::ReceiptsRoot => Res::option(Res::String),
+                Tx::Status => Res::Int,
+                Tx::Time => Res::Int,
+                Tx::Reason => Res::option(Res::String),
+                Tx::Script => Res::option(Res::String),
+                Tx::ScriptData => Res::option(Res::String),
+                Tx::BytecodeWitnessIndex => Res::option(Res::Int),
+                Tx::BytecodeLength => Res::option(Res::Int),
+                Tx::Salt => Res::option(Res::String),
+            };
+            selected_transaction_fields.push(SelectedField {
+                name: transaction_field.to_string(),
+                data_type,
+            })
+        }
+
+        Ok(Self::new(
+            selected_transaction_fields,
+            selected_block_fields,
+        ))
+    }
+
+    pub fn try_from_config_field_selection(
+        field_selection_cfg: human_config::evm::FieldSelection,
+        // For validating transaction field selection with rpc
+        has_rpc_sync_src: bool,
+    ) -> Result<Self> {
+        use human_config::evm::BlockField;
+        use human_config::evm::TransactionField;
+
+        let transaction_fields = field_selection_cfg.transaction_fields.unwrap_or_default();
+        let block_fields = field_selection_cfg.block_fields.unwrap_or_default();
+
+        Self::validate_no_duplicates(&transaction_fields, &block_fields)?;
+
         if has_rpc_sync_src {
             let invalid_rpc_tx_fields: Vec<_> = transaction_fields
                 .iter()
@@ -1966,6 +2607,199 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_realtime_rpc_data_source() {
+        use crate::config_parsing::human_config::evm::{
+            For, Network as EvmNetwork, NetworkRpc, Rpc,
+        };
+
+        let network = |rpc: NetworkRpc| EvmNetwork {
+            id: 1,
+            hypersync_config: None,
+            rpc_config: None,
+            rpc: Some(rpc),
+            start_block: 0,
+            end_block: None,
+            confirmed_block_threshold: None,
+            finality: None,
+            contracts: vec![],
+        };
+        let rpc = |url: &str, source_for: For| Rpc {
+            url: url.to_string(),
+            source_for,
+            weight: None,
+            max_requests_per_second: None,
+            max_block_range: None,
+            headers: None,
+            sync_config: None,
+        };
+
+        assert_eq!(
+            DataSource::from_evm_network_config(
+                network(NetworkRpc::Url("wss://eth.com/ws/".to_string())),
+                None
+            )
+            .unwrap(),
+            DataSource::Evm {
+                main: MainEvmDataSource::HyperSync {
+                    hypersync_endpoint_url: "https://1.hypersync.xyz".to_string(),
+                    api_token: None,
+                },
+                is_client_decoder: true,
+                rpcs: vec![rpc("wss://eth.com/ws", For::Realtime)],
+            }
+        );
+
+        let error = DataSource::from_evm_network_config(
+            network(NetworkRpc::Single(rpc("https://eth.com", For::Realtime))),
+            None,
+        )
+        .unwrap_err();
+        assert!(error.to_string().starts_with("EE116:"), "{}", error);
+
+        let error = DataSource::from_evm_network_config(
+            network(NetworkRpc::Single(rpc("wss://eth.com", For::Sync))),
+            None,
+        )
+        .unwrap_err();
+        assert!(error.to_string().starts_with("EE109:"), "{}", error);
+    }
+
+    #[test]
+    fn test_rpc_load_balancing_options() {
+        use crate::config_parsing::human_config::evm::{
+            For, Network as EvmNetwork, NetworkRpc, Rpc,
+        };
+
+        let network = |rpcs: Vec<Rpc>| EvmNetwork {
+            id: 1,
+            hypersync_config: None,
+            rpc_config: None,
+            rpc: Some(NetworkRpc::List(rpcs)),
+            start_block: 0,
+            end_block: None,
+            confirmed_block_threshold: None,
+            finality: None,
+            contracts: vec![],
+        };
+        let rpc = |url: &str, weight: Option<u32>, max_block_range: Option<u32>| Rpc {
+            url: url.to_string(),
+            source_for: For::Sync,
+            weight,
+            max_requests_per_second: Some(10),
+            max_block_range,
+            headers: None,
+            sync_config: None,
+        };
+
+        let DataSource::Evm { rpcs, .. } = DataSource::from_evm_network_config(
+            network(vec![
+                rpc("https://eth.com", Some(3), Some(1000)),
+                rpc("https://eth.com/backup", Some(1), None),
+            ]),
+            None,
+        )
+        .unwrap() else {
+            panic!("Expected an evm data source")
+        };
+        assert_eq!(
+            rpcs,
+            vec![
+                rpc("https://eth.com", Some(3), Some(1000)),
+                rpc("https://eth.com/backup", Some(1), None),
+            ]
+        );
+
+        let error = DataSource::from_evm_network_config(
+            network(vec![rpc("https://eth.com", Some(0), None)]),
+            None,
+        )
+        .unwrap_err();
+        assert!(error.to_string().starts_with("EE117:"), "{}", error);
+    }
+
+    #[test]
+    fn test_evm_finality() {
+        use super::{get_evm_finality, FinalityConfig};
+        use crate::config_parsing::human_config::evm::{
+            Finality, FinalityTag, Network as EvmNetwork, NetworkRpc,
+        };
+
+        let network =
+            |confirmed_block_threshold: Option<i32>, finality: Option<Finality>| EvmNetwork {
+                id: 10,
+                hypersync_config: None,
+                rpc_config: None,
+                rpc: Some(NetworkRpc::Url("https://eth.com".to_string())),
+                start_block: 0,
+                end_block: None,
+                confirmed_block_threshold,
+                finality,
+                contracts: vec![],
+            };
+
+        assert_eq!(get_evm_finality(&network(None, None)).unwrap(), (0, None));
+        assert_eq!(
+            get_evm_finality(&network(Some(20), None)).unwrap(),
+            (20, None)
+        );
+        assert_eq!(
+            get_evm_finality(&network(None, Some(Finality::Blocks(30)))).unwrap(),
+            (30, None)
+        );
+        assert_eq!(
+            get_evm_finality(&network(None, Some(Finality::Tag(FinalityTag::Safe)))).unwrap(),
+            (
+                0,
+                Some(FinalityConfig {
+                    tag: FinalityTag::Safe,
+                    min_confirmed_block_threshold: None
+                })
+            ),
+            "Should use the chain default as a fallback"
+        );
+        assert_eq!(
+            get_evm_finality(&network(
+                Some(20),
+                Some(Finality::Tag(FinalityTag::Finalized))
+            ))
+            .unwrap(),
+            (
+                20,
+                Some(FinalityConfig {
+                    tag: FinalityTag::Finalized,
+                    min_confirmed_block_threshold: Some(20)
+                })
+            ),
+            "The explicit threshold should be the minimum of the detected depth"
+        );
+
+        let error = get_evm_finality(&network(Some(20), Some(Finality::Blocks(30)))).unwrap_err();
+        assert!(error.to_string().starts_with("EE120:"), "{}", error);
+
+        let error = get_evm_finality(&EvmNetwork {
+            rpc: None,
+            ..network(None, Some(Finality::Tag(FinalityTag::Finalized)))
+        })
+        .unwrap_err();
+        assert!(
+            error.to_string().starts_with("EE120:"),
+            "The block tags can't be read without an RPC: {}",
+            error
+        );
+
+        let finality: Vec<Finality> =
+            serde_yaml::from_str("[finalized, safe, 12]").expect("Should deserialize finality");
+        assert_eq!(
+            finality,
+            vec![
+                Finality::Tag(FinalityTag::Finalized),
+                Finality::Tag(FinalityTag::Safe),
+                Finality::Blocks(12)
+            ]
+        );
+    }
+
     #[test]
     fn deserializes_contract_config_with_multiple_sync_sources() {
         let config_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
@@ -1985,6 +2819,82 @@ mod test {
         assert_eq!(error.to_string(), "EE106: Cannot define both hypersync_config and rpc as a data-source for historical sync at the same time, please choose only one option or set RPC to be a fallback. Read more in our docs https://docs.envio.dev/docs/configuration-file");
     }
 
+    #[test]
+    fn test_endpoint_headers() {
+        use crate::config_parsing::human_config::evm::{
+            For, HypersyncConfig, Network as EvmNetwork, NetworkRpc, Rpc,
+        };
+
+        let network = |hypersync_headers: Option<Headers>, rpc: Rpc| EvmNetwork {
+            id: 1,
+            hypersync_config: Some(HypersyncConfig {
+                url: "https://1.hypersync.xyz".to_string(),
+                headers: hypersync_headers,
+            }),
+            rpc_config: None,
+            rpc: Some(NetworkRpc::Single(rpc)),
+            start_block: 0,
+            end_block: None,
+            confirmed_block_threshold: None,
+            finality: None,
+            contracts: vec![],
+        };
+        let rpc = |url: &str, source_for: For| Rpc {
+            url: url.to_string(),
+            source_for,
+            weight: None,
+            max_requests_per_second: None,
+            max_block_range: None,
+            headers: Some(Headers::from([
+                ("Authorization".to_string(), "Bearer secret".to_string()),
+                ("X-Tenant".to_string(), "my-tenant".to_string()),
+            ])),
+            sync_config: None,
+        };
+
+        assert_eq!(
+            DataSource::from_evm_network_config(
+                network(
+                    Some(Headers::from([(
+                        "authorization".to_string(),
+                        "Bearer hypersync-token".to_string()
+                    )])),
+                    rpc("https://eth.com", For::Fallback)
+                ),
+                None
+            )
+            .unwrap(),
+            DataSource::Evm {
+                main: MainEvmDataSource::HyperSync {
+                    hypersync_endpoint_url: "https://1.hypersync.xyz".to_string(),
+                    api_token: Some("hypersync-token".to_string()),
+                },
+                is_client_decoder: true,
+                rpcs: vec![rpc("https://eth.com", For::Fallback)],
+            }
+        );
+
+        let error = DataSource::from_evm_network_config(
+            network(
+                Some(Headers::from([(
+                    "X-Tenant".to_string(),
+                    "my-tenant".to_string(),
+                )])),
+                rpc("https://eth.com", For::Fallback),
+            ),
+            None,
+        )
+        .unwrap_err();
+        assert!(error.to_string().starts_with("EE118:"), "{}", error);
+
+        let error = DataSource::from_evm_network_config(
+            network(None, rpc("wss://eth.com", For::Realtime)),
+            None,
+        )
+        .unwrap_err();
+        assert!(error.to_string().starts_with("EE118:"), "{}", error);
+    }
+
     #[test]
     fn test_hypersync_url_trailing_slash_trimming() {
         use crate::config_parsing::human_config::evm::{HypersyncConfig, Network as EvmNetwork};
@@ -1993,12 +2903,14 @@ mod test {
             id: 1,
             hypersync_config: Some(HypersyncConfig {
                 url: "https://somechain.hypersync.xyz//".to_string(),
+                headers: None,
             }),
             rpc_config: None,
             rpc: None,
             start_block: 0,
             end_block: None,
             confirmed_block_threshold: None,
+            finality: None,
             contracts: vec![],
         };
 
@@ -2009,6 +2921,7 @@ mod test {
             DataSource::Evm {
                 main: MainEvmDataSource::HyperSync {
                     hypersync_endpoint_url: "https://somechain.hypersync.xyz".to_string(),
+                    api_token: None,
                 },
                 is_client_decoder: true,
                 rpcs: vec![],
@@ -2016,6 +2929,148 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_fuel_core_data_source() {
+        use crate::config_parsing::human_config::fuel::{
+            For as FuelCoreFor, FuelCoreConfig, Network as FuelNetwork,
+        };
+
+        let network = |id, source_for| FuelNetwork {
+            id,
+            start_block: 0,
+            end_block: None,
+            hyperfuel_config: None,
+            confirmed_block_threshold: None,
+            fuel_core: Some(FuelCoreConfig {
+                url: "http://127.0.0.1:4000/v1/graphql/".to_string(),
+                source_for,
+            }),
+            contracts: vec![],
+        };
+
+        assert_eq!(
+            DataSource::from_fuel_network_config(&network(1337, None)).unwrap(),
+            DataSource::Fuel {
+                main: MainFuelDataSource::FuelCore {
+                    url: "http://127.0.0.1:4000/v1/graphql".to_string(),
+                },
+                fuel_core_fallback_url: None,
+            }
+        );
+
+        assert_eq!(
+            DataSource::from_fuel_network_config(&network(0, None)).unwrap(),
+            DataSource::Fuel {
+                main: MainFuelDataSource::HyperFuel {
+                    hypersync_endpoint_url: "https://fuel-testnet.hypersync.xyz".to_string(),
+                },
+                fuel_core_fallback_url: Some("http://127.0.0.1:4000/v1/graphql".to_string()),
+            }
+        );
+
+        assert_eq!(
+            DataSource::from_fuel_network_config(&network(0, Some(FuelCoreFor::Sync))).unwrap(),
+            DataSource::Fuel {
+                main: MainFuelDataSource::FuelCore {
+                    url: "http://127.0.0.1:4000/v1/graphql".to_string(),
+                },
+                fuel_core_fallback_url: None,
+            }
+        );
+
+        assert!(
+            DataSource::from_fuel_network_config(&network(1337, Some(FuelCoreFor::Fallback)))
+                .is_err()
+        );
+        assert!(DataSource::from_fuel_network_config(&FuelNetwork {
+            fuel_core: None,
+            ..network(1337, None)
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_fuel_field_selection() {
+        use super::{FieldSelection, SelectedField};
+        use crate::{
+            config_parsing::human_config::fuel::{
+                BlockField, FieldSelection as FuelFieldSelection, TransactionField,
+            },
+            rescript_types::RescriptTypeIdent,
+        };
+
+        let cfg = FuelFieldSelection {
+            transaction_fields: Some(vec![
+                TransactionField::Script,
+                TransactionField::Status,
+                TransactionField::Witnesses,
+            ]),
+            block_fields: Some(vec![
+                BlockField::DaHeight,
+                BlockField::TransactionsCount,
+                BlockField::MessageReceiptCount,
+            ]),
+        };
+
+        let field_selection =
+            FieldSelection::try_from_fuel_config_field_selection(cfg.clone(), false).unwrap();
+
+        assert_eq!(
+            field_selection.transaction_fields,
+            vec![
+                SelectedField {
+                    name: "id".to_string(),
+                    data_type: RescriptTypeIdent::String,
+                },
+                SelectedField {
+                    name: "Script".to_string(),
+                    data_type: RescriptTypeIdent::option(RescriptTypeIdent::String),
+                },
+                SelectedField {
+                    name: "Status".to_string(),
+                    data_type: RescriptTypeIdent::Int,
+                },
+                SelectedField {
+                    name: "Witnesses".to_string(),
+                    data_type: RescriptTypeIdent::option(RescriptTypeIdent::array(
+                        RescriptTypeIdent::String
+                    )),
+                },
+            ]
+        );
+        assert_eq!(
+            field_selection.block_fields[field_selection.block_fields.len() - 3..].to_vec(),
+            vec![
+                SelectedField {
+                    name: "DaHeight".to_string(),
+                    data_type: RescriptTypeIdent::Int,
+                },
+                SelectedField {
+                    name: "TransactionsCount".to_string(),
+                    data_type: RescriptTypeIdent::Int,
+                },
+                SelectedField {
+                    name: "MessageReceiptCount".to_string(),
+                    data_type: RescriptTypeIdent::Int,
+                },
+            ]
+        );
+
+        assert!(
+            FieldSelection::try_from_fuel_config_field_selection(cfg, true).is_err(),
+            "Should fail when fuel-core is used for sync or fallback"
+        );
+
+        assert!(FieldSelection::try_from_fuel_config_field_selection(
+            FuelFieldSelection {
+                transaction_fields: Some(vec![TransactionField::Time, TransactionField::Time]),
+                block_fields: None,
+            },
+            false
+        )
+        .is_err());
+    }
+
     #[test]
     fn test_valid_version_numbers() {
         let valid_version_numbers = vec![
@@ -2076,6 +3131,7 @@ mod test {
                 start_block: 0,
                 end_block: None,
                 confirmed_block_threshold: None,
+                finality: None,
                 contracts: vec![],
             }],
             unordered_multichain_mode: None,
@@ -2091,6 +3147,7 @@ mod test {
         let schema = crate::config_parsing::entity_parsing::Schema {
             entities: std::collections::HashMap::new(),
             enums: std::collections::HashMap::new(),
+            embedded_types: std::collections::HashMap::new(),
         };
 
         let system_config = SystemConfig::from_human_config(
@@ -2122,6 +3179,7 @@ mod test {
                 start_block: 0,
                 end_block: None,
                 confirmed_block_threshold: None,
+                finality: None,
                 contracts: vec![],
             }],
             unordered_multichain_mode: None,
@@ -2147,4 +3205,19 @@ mod test {
             expected_custom_path
         );
     }
+
+    #[test]
+    fn parses_fuel_bytecode_root() {
+        let root = "0x4a778d0e6b6a7e1e2d1ba5b4c1e2ee3a6d1f9a8d27f6cf8e0b9e6d5e3e0e0a1b";
+        assert_eq!(
+            super::parse_fuel_bytecode_root(root, "predicate MyPredicate").unwrap(),
+            root.to_string()
+        );
+
+        let error = super::parse_fuel_bytecode_root("0x1234", "predicate MyPredicate")
+            .unwrap_err()
+            .to_string();
+        assert!(error.starts_with("EE114:"), "{}", error);
+        assert!(error.contains("predicate MyPredicate"), "{}", error);
+    }
 }
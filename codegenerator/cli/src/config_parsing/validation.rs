@@ -1,5 +1,5 @@
 use super::{
-    chain_helpers,
+    chain_registry::ChainRegistry,
     human_config::{self, evm::HumanConfig},
 };
 use crate::constants::reserved_keywords::{
@@ -145,8 +145,8 @@ impl human_config::evm::Network {
                     finite_end_block
                 ))
             };
-            if let Ok(network) = chain_helpers::Network::from_network_id(self.id) {
-                match (self.end_block, network.get_finite_end_block()) {
+            if let Some(chain) = ChainRegistry::global()?.get_by_id(self.id) {
+                match (self.end_block, chain.finite_end_block) {
                     (Some(end_block), Some(finite_end_block)) if end_block > finite_end_block => {
                         return make_err(finite_end_block)
                     }
@@ -1,12 +1,47 @@
 use crate::{
+    cli_args::clap_definitions::CodegenArgs,
     commands,
     config_parsing::system_config::SystemConfig,
+    hbs_templating::codegen_templates::ProjectTemplate,
     persisted_state::{PersistedStateExists, CURRENT_CRATE_VERSION},
     project_paths::ParsedProjectPaths,
 };
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
+
+async fn check_endpoints(config: &SystemConfig) -> Result<()> {
+    println!("Checking the configured endpoints...");
+    let checks = config.check_endpoints().await;
+    for check in &checks {
+        println!("{check}");
+    }
+    let failed_count = checks.iter().filter(|check| check.result.is_err()).count();
+    if failed_count > 0 {
+        return Err(anyhow!(
+            "EE121: {} of {} configured endpoints failed the check. Please make sure the urls \
+             are reachable and belong to the network they are configured for.",
+            failed_count,
+            checks.len()
+        ));
+    }
+    Ok(())
+}
+
+pub async fn run_codegen(
+    project_paths: &ParsedProjectPaths,
+    codegen_args: &CodegenArgs,
+) -> Result<()> {
+    if codegen_args.print_context {
+        let mut config = SystemConfig::parse_from_project_files(project_paths)
+            .context("Failed parsing config")?;
+        config.redact_headers();
+        let template =
+            ProjectTemplate::from_config(&config).context("Failed creating project template")?;
+        let context_json = serde_json::to_string_pretty(&template)
+            .context("Failed serializing codegen template context")?;
+        println!("{context_json}");
+        return Ok(());
+    }
 
-pub async fn run_codegen(project_paths: &ParsedProjectPaths) -> Result<()> {
     //Manage purging of gengerated folder
     match PersistedStateExists::get_persisted_state_file(project_paths) {
         PersistedStateExists::Exists(ps) if ps.envio_version != CURRENT_CRATE_VERSION => {
@@ -26,6 +61,10 @@ pub async fn run_codegen(project_paths: &ParsedProjectPaths) -> Result<()> {
     let config =
         SystemConfig::parse_from_project_files(project_paths).context("Failed parsing config")?;
 
+    if codegen_args.check_endpoints {
+        check_endpoints(&config).await?;
+    }
+
     commands::codegen::run_codegen(&config).await?;
 
     Ok(())
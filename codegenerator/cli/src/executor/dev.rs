@@ -1,15 +1,20 @@
 use crate::{
+    cli_args::clap_definitions::LocalEnvArgs,
     commands,
-    config_parsing::system_config::SystemConfig,
+    config_parsing::{local_env_config::LocalEnvConfig, system_config::SystemConfig},
     persisted_state::{self, PersistedState, PersistedStateExists, CURRENT_CRATE_VERSION},
     project_paths::ParsedProjectPaths,
     service_health::{self, EndpointHealth},
 };
 use anyhow::{anyhow, Context, Result};
 
-pub async fn run_dev(project_paths: ParsedProjectPaths) -> Result<()> {
+pub async fn run_dev(
+    project_paths: ParsedProjectPaths,
+    local_env_args: &LocalEnvArgs,
+) -> Result<()> {
     let config =
         SystemConfig::parse_from_project_files(&project_paths).context("Failed parsing config")?;
+    let local_env_config = &LocalEnvConfig::resolve(&project_paths.project_root, local_env_args)?;
 
     let current_state = PersistedState::get_current_state(&config)
         .context("Failed getting current indexer state")?;
@@ -64,28 +69,68 @@ pub async fn run_dev(project_paths: ParsedProjectPaths) -> Result<()> {
             .await
             .context("Failed running codegen")?;
     }
-    // if hasura healhz check returns not found assume docker isnt running and start it up {
-    let hasura_health_check_is_error = service_health::fetch_hasura_healthz().await.is_err();
-
-    let should_open_hasura_console = if hasura_health_check_is_error {
-        //Run docker commands to spin up container
-        commands::docker::docker_compose_up_d(&config)
+    let (should_open_hasura_console, postgres_health) = if local_env_config.docker {
+        // if any of the services isn't healthy assume docker isnt running and start it up
+        let postgres_is_healthy = service_health::fetch_postgres_health(local_env_config).await
+            == EndpointHealth::Healthy;
+        let hasura_is_healthy = service_health::fetch_hasura_health(&local_env_config.hasura_url)
             .await
-            .context("Failed running docker compose up after server liveness check")?;
-        true
+            == EndpointHealth::Healthy;
+
+        let should_open_hasura_console = if !postgres_is_healthy || !hasura_is_healthy {
+            //Run docker commands to spin up container
+            commands::docker::docker_compose_up_d(&config, local_env_config)
+                .await
+                .context("Failed running docker compose up after server liveness check")?;
+            true
+        } else {
+            false
+        };
+
+        (
+            should_open_hasura_console,
+            service_health::wait_for_postgres(local_env_config).await,
+        )
     } else {
-        false
+        //Without Docker, Postgres should already be running
+        let postgres_health = match service_health::fetch_postgres_health(local_env_config).await {
+            EndpointHealth::Unhealthy(reason) => EndpointHealth::Unhealthy(format!(
+                "EE123: Failed connecting to Postgres at {}:{}. Please make sure it's running, or \
+                 remove the --no-docker flag and ENVIO_DOCKER=false to start it with Docker. \
+                 Error: {}",
+                local_env_config.pg_host, local_env_config.pg_port, reason
+            )),
+            EndpointHealth::Healthy => EndpointHealth::Healthy,
+        };
+        (false, postgres_health)
     };
 
-    let hasura_health = service_health::fetch_hasura_healthz_with_retry().await;
-
-    match hasura_health {
+    match postgres_health {
         EndpointHealth::Unhealthy(err_message) => {
-            Err(anyhow!(err_message)).context("Failed to start hasura")?;
+            Err(anyhow!(err_message)).context("Failed to connect to Postgres")?;
         }
         EndpointHealth::Healthy => {
+            //The migrations only depend on Postgres, so the indexer can
+            //run without Hasura, just without tracking the tables in it
+            let hasura_is_healthy = local_env_config.hasura
+                && match service_health::wait_for_hasura(&local_env_config.hasura_url).await {
+                    EndpointHealth::Healthy => true,
+                    EndpointHealth::Unhealthy(reason) => {
+                        println!(
+                            "WARNING: Hasura isn't available. The indexer tables won't be tracked \
+                             in Hasura. Error: {reason}"
+                        );
+                        false
+                    }
+                };
+
+            println!("Services health:");
+            for service in service_health::fetch_services_health(local_env_config).await {
+                println!("  {service}");
+            }
+
             //Get the persisted state from the db
-            let persisted_state_db = PersistedStateExists::read_from_db()
+            let persisted_state_db = PersistedStateExists::read_from_db(local_env_config)
                 .await
                 .context("Failed to read persisted state from the DB")?;
 
@@ -114,16 +159,22 @@ pub async fn run_dev(project_paths: ParsedProjectPaths) -> Result<()> {
                 }
                 println!("Running db migrations");
 
-                commands::db_migrate::run_db_setup(&config, &current_state)
+                commands::db_migrate::run_db_setup(&config, local_env_config, &current_state)
                     .await
                     .context("Failed running db setup command")?;
             }
 
             println!("Starting indexer");
 
-            commands::start::start_indexer(&config, should_open_hasura_console)
-                .await
-                .context("Failed running start on the indexer")?;
+            let hasura_console_url = (should_open_hasura_console && hasura_is_healthy)
+                .then_some(local_env_config.hasura_url.as_str());
+            commands::start::start_indexer(
+                &config,
+                local_env_config.get_env_vars(),
+                hasura_console_url,
+            )
+            .await
+            .context("Failed running start on the indexer")?;
         }
     }
 
@@ -0,0 +1,206 @@
+use crate::{
+    cli_args::clap_definitions::LocalEnvArgs,
+    commands,
+    config_parsing::{
+        local_env_config::{LocalEnvConfig, PORT_OFFSET_ENV_VAR},
+        system_config::SystemConfig,
+    },
+    project_paths::ParsedProjectPaths,
+    service_health::{self, EndpointHealth},
+};
+use anyhow::{anyhow, Context, Result};
+use colored::Colorize;
+use std::{net::TcpListener, path::Path};
+
+//Should match the engines field of the generated package.json
+const MIN_NODE_MAJOR_VERSION: u32 = 18;
+
+struct Check {
+    name: String,
+    result: Result<String>,
+}
+
+impl Check {
+    fn new(name: impl Into<String>, result: Result<String>) -> Self {
+        let check = Check {
+            name: name.into(),
+            result,
+        };
+        check.print();
+        check
+    }
+
+    fn print(&self) {
+        match &self.result {
+            Ok(details) => println!("{} {}: {}", "PASS".green(), self.name, details),
+            Err(err) => println!("{} {}: {:#}", "FAIL".red(), self.name, err),
+        }
+    }
+}
+
+fn parse_node_major_version(version: &str) -> Option<u32> {
+    version
+        .trim()
+        .trim_start_matches('v')
+        .split('.')
+        .next()?
+        .parse()
+        .ok()
+}
+
+async fn check_node(current_dir: &Path) -> Result<String> {
+    let version = commands::doctor::get_node_version(current_dir).await?;
+    match parse_node_major_version(&version) {
+        Some(major) if major >= MIN_NODE_MAJOR_VERSION => Ok(version),
+        Some(_) => Err(anyhow!(
+            "Node.js {} is installed, but {}.0.0 or newer is required",
+            version,
+            MIN_NODE_MAJOR_VERSION
+        )),
+        None => Err(anyhow!("Unexpected Node.js version \"{}\"", version)),
+    }
+}
+
+fn is_port_available(port: u16) -> bool {
+    TcpListener::bind(("127.0.0.1", port)).is_ok()
+}
+
+///Passes when Hasura is already running or its port is free for envio dev to start it.
+///Without Docker, the user-specified Hasura should be running.
+async fn check_hasura(local_env_config: &LocalEnvConfig) -> Result<String> {
+    let hasura_url = &local_env_config.hasura_url;
+    let port = local_env_config.hasura_port;
+    let reason = match service_health::fetch_hasura_health(hasura_url).await {
+        EndpointHealth::Healthy => return Ok(format!("Hasura is running at {hasura_url}")),
+        EndpointHealth::Unhealthy(reason) => reason,
+    };
+    if !local_env_config.docker {
+        return Err(anyhow!(reason).context(format!("Hasura isn't available at {hasura_url}")));
+    }
+    if is_port_available(port) {
+        Ok(format!("Port {port} is available"))
+    } else {
+        Err(anyhow!(
+            "Port {port} is used by a process other than Hasura. Please stop it or set \
+             {PORT_OFFSET_ENV_VAR} before running envio dev"
+        ))
+    }
+}
+
+///Passes when Postgres accepts connections or its port is free for envio dev to start it.
+///Without Docker, Postgres should be running.
+async fn check_postgres(local_env_config: &LocalEnvConfig) -> Result<String> {
+    let port = local_env_config.pg_port;
+    let connection_error = match service_health::fetch_postgres_health(local_env_config).await {
+        EndpointHealth::Healthy => return Ok(format!("Connected to Postgres on port {port}")),
+        EndpointHealth::Unhealthy(reason) => anyhow!(reason),
+    };
+    if !local_env_config.docker {
+        Err(connection_error.context(format!(
+            "Failed connecting to Postgres at {}:{port}",
+            local_env_config.pg_host
+        )))
+    } else if is_port_available(port) {
+        Ok(format!("Port {port} is available"))
+    } else {
+        Err(connection_error.context(format!(
+            "Port {port} is in use, but connecting to Postgres on it failed"
+        )))
+    }
+}
+
+pub async fn run_doctor(
+    project_paths: &ParsedProjectPaths,
+    local_env_args: &LocalEnvArgs,
+) -> Result<()> {
+    let current_dir = &project_paths.project_root;
+    let mut checks = vec![
+        Check::new("Node.js", check_node(current_dir).await),
+        Check::new("npm", commands::doctor::get_npm_version(current_dir).await),
+        Check::new(
+            "pnpm",
+            commands::doctor::get_pnpm_version(current_dir)
+                .await
+                .context("Install it with npm install --global pnpm"),
+        ),
+    ];
+
+    match LocalEnvConfig::resolve(&project_paths.project_root, local_env_args) {
+        Ok(local_env_config) => {
+            if local_env_config.docker {
+                checks.push(Check::new(
+                    "Docker Compose",
+                    commands::doctor::get_docker_compose_version(current_dir).await,
+                ));
+            }
+            if local_env_config.hasura {
+                checks.push(Check::new("Hasura", check_hasura(&local_env_config).await));
+            }
+            checks.push(Check::new(
+                "Postgres",
+                check_postgres(&local_env_config).await,
+            ));
+            for url in &local_env_config.health_check_urls {
+                let result = match service_health::fetch_custom_endpoint_health(url).await {
+                    EndpointHealth::Healthy => Ok("Healthy".to_string()),
+                    EndpointHealth::Unhealthy(reason) => Err(anyhow!(reason)),
+                };
+                checks.push(Check::new(format!("Health check {url}"), result));
+            }
+        }
+        Err(err) => checks.push(Check::new("Local environment", Err(err))),
+    }
+
+    checks.push(Check::new(
+        "Config environment variables",
+        SystemConfig::get_config_env_variables(project_paths).map(|names| {
+            if names.is_empty() {
+                "No environment variables are referenced".to_string()
+            } else {
+                format!("Resolved {}", names.join(", "))
+            }
+        }),
+    ));
+
+    match SystemConfig::parse_from_project_files(project_paths) {
+        Ok(config) => {
+            checks.push(Check::new("Config", Ok(config.name.clone())));
+            for endpoint_check in config.check_endpoints().await {
+                checks.push(Check::new(
+                    format!(
+                        "Network {} {}",
+                        endpoint_check.network_id, endpoint_check.name
+                    ),
+                    endpoint_check.result.map(|status| status.to_string()),
+                ));
+            }
+        }
+        Err(err) => checks.push(Check::new(
+            "Config",
+            Err(err.context("Network endpoints weren't checked")),
+        )),
+    }
+
+    let failed_count = checks.iter().filter(|check| check.result.is_err()).count();
+    if failed_count > 0 {
+        return Err(anyhow!(
+            "{} of {} checks failed",
+            failed_count,
+            checks.len()
+        ));
+    }
+    println!("All {} checks passed", checks.len());
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_node_major_version;
+
+    #[test]
+    fn parses_node_major_version() {
+        assert_eq!(parse_node_major_version("v20.11.1"), Some(20));
+        assert_eq!(parse_node_major_version("18.0.0\n"), Some(18));
+        assert_eq!(parse_node_major_version("nightly"), None);
+    }
+}
@@ -113,7 +113,9 @@ pub async fn run_init_args(init_args: InitArgs, project_paths: &ProjectPaths) ->
         Ecosystem::Fuel {
             init_flow: init_config::fuel::InitFlow::ContractImport(contract_import_selection),
         } => {
-            let fuel_config = contract_import_selection.to_human_config(&init_config);
+            let fuel_config = contract_import_selection
+                .to_human_config(&init_config)
+                .context("Failed to converting contract import selection into config.yaml")?;
 
             // TODO: Allow parsed paths to not depend on a written config.yaml file in file system
             file_system::write_file_string_to_system(
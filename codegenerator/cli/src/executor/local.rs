@@ -1,7 +1,9 @@
 use crate::{
-    cli_args::clap_definitions::{DbMigrateSubcommands, LocalCommandTypes, LocalDockerSubcommands},
+    cli_args::clap_definitions::{
+        DbMigrateSubcommands, LocalCommandTypes, LocalDockerSubcommands, LocalEnvArgs,
+    },
     commands,
-    config_parsing::system_config::SystemConfig,
+    config_parsing::{local_env_config::LocalEnvConfig, system_config::SystemConfig},
     persisted_state::PersistedState,
     project_paths::ParsedProjectPaths,
 };
@@ -13,14 +15,17 @@ pub async fn run_local(
 ) -> Result<()> {
     let config =
         SystemConfig::parse_from_project_files(project_paths).context("Failed parsing config")?;
+    //The local commands don't have the local env flags, so only the env is used
+    let local_env_config =
+        LocalEnvConfig::resolve(&project_paths.project_root, &LocalEnvArgs::default())?;
 
     match local_commands {
         LocalCommandTypes::Docker(subcommand) => match subcommand {
             LocalDockerSubcommands::Up => {
-                commands::docker::docker_compose_up_d(&config).await?;
+                commands::docker::docker_compose_up_d(&config, &local_env_config).await?;
             }
             LocalDockerSubcommands::Down => {
-                commands::docker::docker_compose_down_v(&config).await?;
+                commands::docker::docker_compose_down_v(&config, &local_env_config).await?;
             }
         },
         LocalCommandTypes::DbMigrate(subcommand) => {
@@ -35,16 +40,26 @@ pub async fn run_local(
             match subcommand {
                 DbMigrateSubcommands::Up => {
                     let persisted_state = get_persisted_state()?;
-                    commands::db_migrate::run_up_migrations(&config, &persisted_state).await?;
+                    commands::db_migrate::run_up_migrations(
+                        &config,
+                        &local_env_config,
+                        &persisted_state,
+                    )
+                    .await?;
                 }
 
                 DbMigrateSubcommands::Down => {
-                    commands::db_migrate::run_drop_schema(&config).await?;
+                    commands::db_migrate::run_drop_schema(&config, &local_env_config).await?;
                 }
 
                 DbMigrateSubcommands::Setup => {
                     let persisted_state = get_persisted_state()?;
-                    commands::db_migrate::run_db_setup(&config, &persisted_state).await?;
+                    commands::db_migrate::run_db_setup(
+                        &config,
+                        &local_env_config,
+                        &persisted_state,
+                    )
+                    .await?;
                 }
             }
         }
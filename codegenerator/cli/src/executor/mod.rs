@@ -2,7 +2,7 @@ use crate::{
     clap_definitions::{JsonSchema, Script},
     cli_args::clap_definitions::{CommandLineArgs, CommandType},
     commands,
-    config_parsing::{human_config, system_config::SystemConfig},
+    config_parsing::{human_config, local_env_config::LocalEnvConfig, system_config::SystemConfig},
     persisted_state::{PersistedState, PersistedStateExists, CURRENT_CRATE_VERSION},
     project_paths::ParsedProjectPaths,
     scripts,
@@ -10,10 +10,12 @@ use crate::{
 
 mod codegen;
 mod dev;
+mod doctor;
 pub mod init;
 mod local;
+mod schema;
 
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
 use schemars::schema_for;
 
 pub async fn execute(command_line_args: CommandLineArgs) -> Result<()> {
@@ -26,18 +28,36 @@ pub async fn execute(command_line_args: CommandLineArgs) -> Result<()> {
             init::run_init_args(init_args, &global_project_paths).await?;
         }
 
-        CommandType::Codegen => {
-            codegen::run_codegen(&parsed_project_paths).await?;
+        CommandType::Codegen(codegen_args) => {
+            codegen::run_codegen(&parsed_project_paths, &codegen_args).await?;
         }
 
-        CommandType::Dev => {
-            dev::run_dev(parsed_project_paths).await?;
+        CommandType::Schema(schema_subcommands) => {
+            schema::run_schema(&schema_subcommands, &parsed_project_paths)?;
         }
 
-        CommandType::Stop => {
+        CommandType::Dev(local_env_args) => {
+            dev::run_dev(parsed_project_paths, &local_env_args).await?;
+        }
+
+        CommandType::Stop(local_env_args) => {
             let config = SystemConfig::parse_from_project_files(&parsed_project_paths)
                 .context("Failed parsing config")?;
-            commands::docker::docker_compose_down_v(&config).await?;
+            let local_env_config =
+                LocalEnvConfig::resolve(&parsed_project_paths.project_root, &local_env_args)?;
+            if local_env_config.docker {
+                commands::docker::docker_compose_down_v(&config, &local_env_config).await?;
+            } else {
+                let exit =
+                    commands::db_migrate::run_drop_schema(&config, &local_env_config).await?;
+                if !exit.success() {
+                    return Err(anyhow!("Failed dropping the database schema"));
+                }
+            }
+        }
+
+        CommandType::Doctor(local_env_args) => {
+            doctor::run_doctor(&parsed_project_paths, &local_env_args).await?;
         }
 
         CommandType::Start(start_args) => {
@@ -69,15 +89,23 @@ pub async fn execute(command_line_args: CommandLineArgs) -> Result<()> {
 
             let config = SystemConfig::parse_from_project_files(&parsed_project_paths)
                 .context("Failed parsing config")?;
+            let local_env_config =
+                LocalEnvConfig::resolve(&parsed_project_paths.project_root, &start_args.local_env)?;
 
             if start_args.restart {
                 let persisted_state = PersistedState::get_current_state(&config)
                     .context("Failed constructing persisted state")?;
 
-                commands::db_migrate::run_db_setup(&config, &persisted_state).await?;
+                commands::db_migrate::run_db_setup(&config, &local_env_config, &persisted_state)
+                    .await?;
             }
-            const SHOULD_OPEN_HASURA: bool = false;
-            commands::start::start_indexer(&config, SHOULD_OPEN_HASURA).await?;
+            const HASURA_CONSOLE_URL: Option<&str> = None;
+            commands::start::start_indexer(
+                &config,
+                local_env_config.get_start_env_vars(),
+                HASURA_CONSOLE_URL,
+            )
+            .await?;
         }
 
         CommandType::Local(local_commands) => {
@@ -0,0 +1,85 @@
+use crate::{
+    cli_args::clap_definitions::{SchemaGenerateArgs, SchemaSubcommands},
+    config_parsing::system_config::SystemConfig,
+    hbs_templating::contract_import_templates::EventEntity,
+    project_paths::ParsedProjectPaths,
+};
+use anyhow::{Context, Result};
+use std::io::Write;
+
+pub fn run_schema(
+    schema_subcommands: &SchemaSubcommands,
+    project_paths: &ParsedProjectPaths,
+) -> Result<()> {
+    let config =
+        SystemConfig::parse_from_project_files(project_paths).context("Failed parsing config")?;
+
+    match schema_subcommands {
+        SchemaSubcommands::Generate(args) => run_schema_generate(args, &config),
+    }
+}
+
+fn run_schema_generate(args: &SchemaGenerateArgs, config: &SystemConfig) -> Result<()> {
+    let schema_path = config.get_path_to_schema()?;
+    let event_entities = EventEntity::get_missing_from_schema(config)
+        .context("Failed getting event entities missing from the schema")?;
+
+    if event_entities.is_empty() {
+        println!(
+            "All events in the config already have an entity in {}",
+            schema_path.display()
+        );
+        return Ok(());
+    }
+
+    let additions = event_entities
+        .iter()
+        .map(|event_entity| event_entity.to_graphql_type_def())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    if args.dry_run {
+        println!(
+            "The following types would be added to {}:\n\n{additions}",
+            schema_path.display()
+        );
+        return Ok(());
+    }
+
+    let current_schema = std::fs::read_to_string(&schema_path).context(format!(
+        "Failed reading schema file at {}",
+        schema_path.display()
+    ))?;
+    //Keep a blank line between the existing types and the added ones
+    let separator = match current_schema.as_str() {
+        "" => "",
+        s if s.ends_with("\n\n") => "",
+        s if s.ends_with('\n') => "\n",
+        _ => "\n\n",
+    };
+
+    //Only append to the file, so existing types are never touched
+    let mut schema_file = std::fs::OpenOptions::new()
+        .append(true)
+        .open(&schema_path)
+        .context(format!(
+            "Failed opening schema file at {}",
+            schema_path.display()
+        ))?;
+    write!(schema_file, "{separator}{additions}").context(format!(
+        "Failed writing to schema file at {}",
+        schema_path.display()
+    ))?;
+
+    println!(
+        "Added {} to {}",
+        event_entities
+            .iter()
+            .map(|event_entity| event_entity.name.clone())
+            .collect::<Vec<_>>()
+            .join(", "),
+        schema_path.display()
+    );
+
+    Ok(())
+}
@@ -15,6 +15,11 @@ pub const TRANSFER_EVENT_NAME: &str = "Transfer";
 pub const MINT_EVENT_NAME: &str = "Mint";
 pub const BURN_EVENT_NAME: &str = "Burn";
 pub const CALL_EVENT_NAME: &str = "Call";
+pub const SPEND_EVENT_NAME: &str = "Spend";
+pub const RUN_EVENT_NAME: &str = "Run";
+
+const MAIN_FN_NAME: &str = "main";
+pub const MAIN_ARGS_TYPE_NAME: &str = "mainArgs";
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct FuelType {
@@ -33,8 +38,11 @@ impl FuelType {
             "u32" => "U32Log",
             "u64" => "U64Log",
             "u128" => "U128Log",
+            "u256" => "U256Log",
             "raw untyped ptr" => "RawUntypedPtrLog",
+            "raw untyped slice" => "RawUntypedSliceLog",
             "b256" => "B256Log",
+            "b512" => "B512Log",
             "address" => "AddressLog",
             "Vec" => "VecLog",
             "str" => "StrLog",
@@ -59,6 +67,7 @@ impl FuelType {
 #[derive(Debug, Clone, PartialEq)]
 pub struct FuelLog {
     pub id: String,
+    pub application: UnifiedTypeApplication,
     pub logged_type: FuelType,
     pub event_name: String,
     pub data_type: RescriptTypeIdent,
@@ -72,6 +81,7 @@ pub struct FuelAbi {
     program: UnifiedProgramABI,
     logs: HashMap<String, FuelLog>,
     types: HashMap<usize, FuelType>,
+    main_args: Option<RescriptTypeDecl>,
 }
 
 impl FuelAbi {
@@ -110,14 +120,6 @@ impl FuelAbi {
             })
             .collect::<HashMap<usize, String>>();
 
-        let get_unknown_res_type_ident = |type_field: &str| {
-            println!("Unhandled type_field \"{}\" in abi", type_field);
-            RescriptTypeIdent::Unknown
-        };
-
-        let get_unknown_res_type_expr =
-            |type_field| RescriptTypeExpr::Identifier(get_unknown_res_type_ident(type_field));
-
         program
             .types
             .iter()
@@ -227,6 +229,8 @@ impl FuelAbi {
                         "u8" | "u16" | "u32" => Int.get_ok_expr(),
                         "u64" | "u128" | "u256" | "raw untyped ptr" => BigInt.get_ok_expr(),
                         "b256" | "address" => String.get_ok_expr(),
+                        //Decoded as a hex string
+                        "b512" | "struct std::b512::B512" => String.get_ok_expr(),
                         "str" | "struct std::string::String" => String.get_ok_expr(),
                         type_field if type_field.starts_with("str[") => String.get_ok_expr(),
                         "struct std::vec::Vec" => Array(Box::new(GenericParam(
@@ -234,8 +238,8 @@ impl FuelAbi {
                                 .context("Failed getting param for struct Vec")?,
                         )))
                         .get_ok_expr(),
-                        // It's decoded as Uint8Array, but we don't have a schema for it yet
-                        "struct std::bytes::Bytes" => Unknown.get_ok_expr(),
+                        //Decoded as a hex string
+                        "struct std::bytes::Bytes" | "raw untyped slice" => String.get_ok_expr(),
                         //TODO: handle nested option since this would need to be flattened to
                         //single level rescript option.
                         "enum Option" => Option(Box::new(GenericParam(
@@ -292,10 +296,9 @@ impl FuelAbi {
                                 .ok_or(anyhow!("Missing array element type component"))?;
                             Array(Box::new(element_name_and_type_ident.1.clone())).get_ok_expr()
                         }
-                        type_field => {
-                            //Unknown
-                            Ok(get_unknown_res_type_expr(type_field))
-                        }
+                        //Unsupported types are reported with their path,
+                        //when they are used by an event
+                        _ => Unknown.get_ok_expr(),
                     }
                 };
 
@@ -387,6 +390,7 @@ impl FuelAbi {
                     id.clone(),
                     FuelLog {
                         id,
+                        application: abi_log.application.clone(),
                         event_name,
                         data_type: Self::get_type_application(&abi_log.application, types)?,
                         logged_type: logged_type.clone(),
@@ -400,13 +404,57 @@ impl FuelAbi {
         Ok(logs_map)
     }
 
+    //Predicates and scripts receive their input data as arguments of the main function
+    fn decode_main_args(
+        program: &UnifiedProgramABI,
+        types: &HashMap<usize, FuelType>,
+    ) -> Result<Option<RescriptTypeDecl>> {
+        let main_fn = match program.functions.iter().find(|f| f.name == MAIN_FN_NAME) {
+            Some(main_fn) => main_fn,
+            None => return Ok(None),
+        };
+
+        let type_expr = if main_fn.inputs.is_empty() {
+            RescriptTypeExpr::Identifier(RescriptTypeIdent::Unit)
+        } else {
+            let record_fields = main_fn
+                .inputs
+                .iter()
+                .map(|input| {
+                    Ok(RescriptRecordField::new(
+                        input.name.clone(),
+                        Self::get_type_application(input, types).context(format!(
+                            "Failed getting type of the main function argument {}",
+                            input.name
+                        ))?,
+                    ))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            RescriptTypeExpr::Record(record_fields)
+        };
+
+        Ok(Some(RescriptTypeDecl::new(
+            MAIN_ARGS_TYPE_NAME.to_string(),
+            type_expr,
+            vec![],
+        )))
+    }
+
     pub fn parse(path_buf: PathBuf) -> Result<Self> {
+        let raw = fs::read_to_string(&path_buf).context(format!(
+            "Failed to read Fuel ABI file at \"{}\"",
+            path_buf.display()
+        ))?;
+        Self::from_raw(path_buf, raw)
+    }
+
+    /// Parses ABI which is not read from the file system, like the one fetched from an ABI
+    /// registry. The path_buf should point to where the ABI is going to be stored.
+    pub fn from_raw(path_buf: PathBuf, raw: String) -> Result<Self> {
         let path = path_buf
             .to_str()
             .context("The ABI file path is invalid Unicode")?
             .to_string();
-        let raw = fs::read_to_string(&path_buf)
-            .context(format!("Failed to read Fuel ABI file at \"{}\"", path))?;
         let program = Self::decode_program(&raw).context(format!(
             "Failed to decode Fuel ABI file at \"{}\". Make sure you built your program with the \
              forc v0.33.0 or higher.",
@@ -420,6 +468,10 @@ impl FuelAbi {
             "Failed to decode logs of Fuel ABI file at \"{}\"",
             path
         ))?;
+        let main_args = Self::decode_main_args(&program, &types).context(format!(
+            "Failed to decode main function arguments of Fuel ABI file at \"{}\"",
+            path
+        ))?;
         Ok(Self {
             path,
             path_buf,
@@ -427,6 +479,7 @@ impl FuelAbi {
             program,
             logs,
             types,
+            main_args,
         })
     }
 
@@ -472,14 +525,235 @@ impl FuelAbi {
         self.logs.values().cloned().collect()
     }
 
+    /// Returns the type of the main function arguments. Available only for
+    /// predicates and scripts, since contracts don't have a main function.
+    pub fn get_main_args_type(&self) -> Result<RescriptTypeIdent> {
+        match &self.main_args {
+            Some(decl) => Ok(RescriptTypeIdent::TypeApplication {
+                name: decl.name.clone(),
+                type_params: vec![],
+            }),
+            None => Err(anyhow!(
+                "ABI doesn't contain the {MAIN_FN_NAME} function. Make sure the ABI belongs to a \
+                 predicate or a script"
+            )),
+        }
+    }
+
+    /// Fails with the path to the first nested type which can't be decoded,
+    /// like `MyEvent.inner.field`
+    pub fn check_log_types(&self, log: &FuelLog, event_name: &str) -> Result<()> {
+        self.check_type_application(&log.application, event_name)
+    }
+
+    /// Same as check_log_types, but for every argument of the main function
+    pub fn check_main_args_types(&self, path: &str) -> Result<()> {
+        for main_fn in self
+            .program
+            .functions
+            .iter()
+            .filter(|f| f.name == MAIN_FN_NAME)
+        {
+            for input in main_fn.inputs.iter() {
+                self.check_type_application(input, &format!("{path}.{}", input.name))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn check_type_application(
+        &self,
+        application: &UnifiedTypeApplication,
+        path: &str,
+    ) -> Result<()> {
+        match self.find_unsupported_type(application, &HashMap::new(), path.to_string()) {
+            Some((path, type_field)) => Err(anyhow!(
+                "EE115: The type \"{type_field}\" at {path} is not supported by the Fuel ABI \
+                 decoding. Set allow_unknown_types to true in the config file to index it as an \
+                 unknown value."
+            )),
+            None => Ok(()),
+        }
+    }
+
+    //Walks the type down to the nested fields and returns the path and the
+    //type field of the first type which can't be decoded
+    fn find_unsupported_type(
+        &self,
+        application: &UnifiedTypeApplication,
+        generic_args: &HashMap<usize, UnifiedTypeApplication>,
+        path: String,
+    ) -> Option<(String, String)> {
+        //Substitutes the generic params with the type arguments of the parent type
+        fn resolve_generics(
+            application: &UnifiedTypeApplication,
+            generic_args: &HashMap<usize, UnifiedTypeApplication>,
+        ) -> UnifiedTypeApplication {
+            match generic_args.get(&application.type_id) {
+                Some(type_argument) => type_argument.clone(),
+                None => UnifiedTypeApplication {
+                    type_arguments: application.type_arguments.as_ref().map(|type_arguments| {
+                        type_arguments
+                            .iter()
+                            .map(|ta| resolve_generics(ta, generic_args))
+                            .collect()
+                    }),
+                    ..application.clone()
+                },
+            }
+        }
+
+        let application = resolve_generics(application, generic_args);
+        //Generic params which couldn't be resolved are not declared as types
+        let fuel_type = self.types.get(&application.type_id)?;
+        let type_expr = &fuel_type.rescript_type_decl.type_expr;
+
+        if type_expr == &RescriptTypeExpr::Identifier(RescriptTypeIdent::Unknown) {
+            return Some((path, fuel_type.abi_type_field.clone()));
+        }
+
+        let type_decl = self
+            .program
+            .types
+            .iter()
+            .find(|t| t.type_id == application.type_id)?;
+        //Generic params of the parent types might be used by the nested
+        //types like arrays and tuples, so they are kept as well
+        let mut generic_args = generic_args.clone();
+        generic_args.extend(
+            type_decl
+                .type_parameters
+                .iter()
+                .flatten()
+                .cloned()
+                .zip(application.type_arguments.iter().flatten().cloned()),
+        );
+        let components = type_decl.components.iter().flatten();
+        let type_arguments = application.type_arguments.iter().flatten();
+
+        match type_expr {
+            RescriptTypeExpr::Record(_) | RescriptTypeExpr::Variant(_) => {
+                components.find_map(|component| {
+                    self.find_unsupported_type(
+                        component,
+                        &generic_args,
+                        format!("{path}.{}", component.name),
+                    )
+                })
+            }
+            RescriptTypeExpr::Identifier(RescriptTypeIdent::Tuple(_)) => {
+                components.enumerate().find_map(|(index, component)| {
+                    self.find_unsupported_type(component, &generic_args, format!("{path}.{index}"))
+                })
+            }
+            //Fixed size arrays have the element type as a component,
+            //while Vec has it as a type argument
+            RescriptTypeExpr::Identifier(RescriptTypeIdent::Array(_))
+                if fuel_type.abi_type_field.starts_with("[_;") =>
+            {
+                components.take(1).find_map(|element| {
+                    self.find_unsupported_type(element, &generic_args, format!("{path}[]"))
+                })
+            }
+            RescriptTypeExpr::Identifier(RescriptTypeIdent::Array(_)) => {
+                type_arguments.find_map(|element| {
+                    self.find_unsupported_type(element, &generic_args, format!("{path}[]"))
+                })
+            }
+            RescriptTypeExpr::Identifier(RescriptTypeIdent::Option(_)) => {
+                type_arguments.find_map(|type_argument| {
+                    self.find_unsupported_type(type_argument, &generic_args, path.clone())
+                })
+            }
+            _ => None,
+        }
+    }
+
     pub fn to_rescript_type_decl_multi(&self) -> Result<RescriptTypeDeclMulti> {
         let type_declerations = self
             .types
             .values()
             .sorted_by_key(|t| t.id)
             .map(|t| t.rescript_type_decl.clone())
+            .chain(self.main_args.clone())
             .collect();
 
         Ok(RescriptTypeDeclMulti::new(type_declerations))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const EVENT_TYPE_ID: &str = "1111111111111111111111111111111111111111111111111111111111111111";
+    const U512_TYPE_ID: &str = "2222222222222222222222222222222222222222222222222222222222222222";
+    const B512_TYPE_ID: &str = "3333333333333333333333333333333333333333333333333333333333333333";
+
+    fn parse_abi() -> FuelAbi {
+        let raw = serde_json::json!({
+            "programType": "contract",
+            "specVersion": "1",
+            "encodingVersion": "1",
+            "concreteTypes": [
+                {"type": "struct MyEvent", "concreteTypeId": EVENT_TYPE_ID, "metadataTypeId": 0},
+                {"type": "u512", "concreteTypeId": U512_TYPE_ID},
+                {"type": "struct std::b512::B512", "concreteTypeId": B512_TYPE_ID, "metadataTypeId": 3}
+            ],
+            "metadataTypes": [
+                {"type": "struct MyEvent", "metadataTypeId": 0, "components": [
+                    {"name": "inner", "typeId": 1}
+                ]},
+                {"type": "struct Inner", "metadataTypeId": 1, "components": [
+                    {"name": "signature", "typeId": B512_TYPE_ID},
+                    {"name": "field", "typeId": U512_TYPE_ID}
+                ]},
+                {"type": "b256", "metadataTypeId": 2},
+                {"type": "struct std::b512::B512", "metadataTypeId": 3, "components": [
+                    {"name": "bits", "typeId": 4}
+                ]},
+                {"type": "[_; 2]", "metadataTypeId": 4, "components": [
+                    {"name": "__array_element", "typeId": 2}
+                ]}
+            ],
+            "functions": [],
+            "loggedTypes": [{"logId": "1", "concreteTypeId": EVENT_TYPE_ID}],
+            "messagesTypes": [],
+            "configurables": []
+        })
+        .to_string();
+
+        FuelAbi::from_raw(PathBuf::from("abi.json"), raw).unwrap()
+    }
+
+    #[test]
+    fn fails_with_path_to_unsupported_type() {
+        let abi = parse_abi();
+        let log = abi.get_log(&"1".to_string()).unwrap();
+
+        assert_eq!(
+            abi.check_log_types(&log, "MyEvent")
+                .unwrap_err()
+                .to_string(),
+            "EE115: The type \"u512\" at MyEvent.inner.field is not supported by the Fuel ABI \
+             decoding. Set allow_unknown_types to true in the config file to index it as an \
+             unknown value."
+        );
+    }
+
+    #[test]
+    fn decodes_b512_as_string() {
+        let abi = parse_abi();
+        let b512_type = abi
+            .types
+            .values()
+            .find(|t| t.abi_type_field == "struct std::b512::B512")
+            .unwrap();
+
+        assert_eq!(
+            b512_type.rescript_type_decl.type_expr,
+            RescriptTypeExpr::Identifier(RescriptTypeIdent::String)
+        );
+    }
+}
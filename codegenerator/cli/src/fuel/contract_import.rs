@@ -0,0 +1,173 @@
+use std::{env, path::PathBuf};
+
+use crate::{fuel::abi::FuelAbi, fuel::address::Address, init_config::fuel::Network};
+use anyhow::{anyhow, Context};
+use async_recursion::async_recursion;
+use serde::Deserialize;
+use tokio::time::Duration;
+
+pub const ABI_REGISTRY_URL_ENV: &str = "ENVIO_FUEL_ABI_REGISTRY_URL";
+
+pub struct ContractData {
+    pub abi: FuelAbi,
+    pub name: Option<String>,
+}
+
+pub enum ContractImportResult {
+    Contract(ContractData),
+    NotFound,
+}
+
+#[derive(Deserialize, Debug)]
+struct ContractImportResponse {
+    name: Option<String>,
+    // The registry might return the ABI either as a JSON object or as a JSON encoded string
+    abi: serde_json::Value,
+}
+
+/// Returns the ABI registry URL from the env, if it's set
+pub fn get_abi_registry_url_from_env() -> Option<String> {
+    env::var(ABI_REGISTRY_URL_ENV)
+        .ok()
+        .filter(|url| !url.is_empty())
+}
+
+fn get_contract_import_url(registry_url: &str, network: &Network, address: &Address) -> String {
+    format!(
+        "{}/{}/{}",
+        registry_url.trim_end_matches('/'),
+        network.get_network_id(),
+        address
+    )
+}
+
+fn parse_contract_import_response(url: &str, body: &str) -> anyhow::Result<ContractData> {
+    let ContractImportResponse { name, abi } =
+        serde_json::from_str(body).context("Failed to parse ABI registry response")?;
+
+    let raw_abi = match abi {
+        serde_json::Value::String(raw_abi) => raw_abi,
+        abi => serde_json::to_string_pretty(&abi).context("Failed serializing contract ABI")?,
+    };
+
+    // The url is used as the ABI location in error messages.
+    // The ABI is vendored to the project once the contract name is known
+    let abi =
+        FuelAbi::from_raw(PathBuf::from(url), raw_abi).context("Failed parsing contract ABI")?;
+
+    Ok(ContractData { name, abi })
+}
+
+/// Fetches a contract ABI from a registry serving them at
+/// `GET {registry_url}/{network_id}/{address}`. The response body should be
+/// a JSON object with the `abi` and an optional `name` of the contract.
+#[async_recursion]
+pub async fn contract_import(
+    registry_url: &str,
+    network: &Network,
+    address: &Address,
+    retry: u64,
+) -> anyhow::Result<ContractImportResult> {
+    let url = get_contract_import_url(registry_url, network, address);
+    let response: reqwest::Response = match reqwest::get(&url).await {
+        Ok(response) => response,
+        Err(err) => {
+            // Just a few retries in case of a bad internet connection
+            if retry > 2 {
+                return Err(anyhow!("Failed to fetch contract ABI from {url}. {}", err));
+            }
+            tokio::time::sleep(Duration::from_secs(2)).await;
+            return contract_import(registry_url, network, address, retry + 1).await;
+        }
+    };
+
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Ok(ContractImportResult::NotFound);
+    }
+
+    if !response.status().is_success() {
+        return Err(anyhow!(
+            "Failed to fetch contract ABI from {url}. The registry responded with status {}",
+            response.status()
+        ));
+    }
+
+    let body = response
+        .text()
+        .await
+        .context("Failed to read ABI registry response")?;
+
+    Ok(ContractImportResult::Contract(
+        parse_contract_import_response(&url, &body)?,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{get_contract_import_url, parse_contract_import_response};
+    use crate::{fuel::address::Address, init_config::fuel::Network};
+
+    const ADDRESS: &str = "0x4a2ce054e3e94155f7092f7365b212f7f45105b74819c623744ebcc5d065c6ac";
+
+    fn read_greeter_abi() -> String {
+        std::fs::read_to_string(format!(
+            "{}/test/abis/greeter-abi.json",
+            env!("CARGO_MANIFEST_DIR")
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn gets_contract_import_url() {
+        let address = Address::new(ADDRESS).unwrap();
+        assert_eq!(
+            get_contract_import_url("https://registry.example.com/", &Network::Mainnet, &address),
+            format!("https://registry.example.com/9889/{ADDRESS}")
+        );
+        assert_eq!(
+            get_contract_import_url(
+                "https://registry.example.com/abis",
+                &Network::Testnet,
+                &address
+            ),
+            format!("https://registry.example.com/abis/0/{ADDRESS}")
+        );
+    }
+
+    #[test]
+    fn parses_contract_import_response() {
+        let url = format!("https://registry.example.com/0/{ADDRESS}");
+        let raw_abi = read_greeter_abi();
+        let abi_json: serde_json::Value = serde_json::from_str(&raw_abi).unwrap();
+
+        let contract_data = parse_contract_import_response(
+            &url,
+            &serde_json::json!({"name": "Greeter", "abi": abi_json}).to_string(),
+        )
+        .unwrap();
+        assert_eq!(contract_data.name, Some("Greeter".to_string()));
+        assert_eq!(contract_data.abi.path, url);
+
+        let contract_data = parse_contract_import_response(
+            &url,
+            &serde_json::json!({ "abi": raw_abi }).to_string(),
+        )
+        .unwrap();
+        assert_eq!(contract_data.name, None, "The name is optional");
+        assert_eq!(
+            contract_data.abi.get_logs().len(),
+            parse_contract_import_response(
+                &url,
+                &serde_json::json!({ "abi": abi_json }).to_string()
+            )
+            .unwrap()
+            .abi
+            .get_logs()
+            .len(),
+            "The ABI can be either a JSON object or a JSON encoded string"
+        );
+
+        assert!(parse_contract_import_response(&url, r#"{"name": "Greeter"}"#).is_err());
+        assert!(parse_contract_import_response(&url, r#"{"abi": "invalid"}"#).is_err());
+    }
+}
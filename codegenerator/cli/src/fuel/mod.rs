@@ -1,2 +1,3 @@
 pub mod abi;
 pub mod address;
+pub mod contract_import;
@@ -0,0 +1,123 @@
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeSet,
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+const CODEGEN_MANIFEST_FILE_NAME: &str = "codegen_manifest.envio.json";
+
+///The list of files written by a codegen run, relative to the generated directory.
+///Since codegen only rewrites changed files instead of purging the directory,
+///the manifest of the previous run is used to delete files that are no longer generated.
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct CodegenManifest {
+    files: BTreeSet<PathBuf>,
+}
+
+impl CodegenManifest {
+    pub fn from_generated_files(generated_dir: &Path, generated_files: &[PathBuf]) -> Result<Self> {
+        let files = generated_files
+            .iter()
+            .map(|path| {
+                path.strip_prefix(generated_dir)
+                    .map(|relative_path| relative_path.to_path_buf())
+                    .map_err(|_| {
+                        anyhow!(
+                            "Generated file {} is outside of the generated directory {}",
+                            path.display(),
+                            generated_dir.display()
+                        )
+                    })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { files })
+    }
+
+    ///Reads the manifest of the previous codegen run. A missing or invalid manifest
+    ///is treated as empty, so nothing is deleted.
+    pub fn read(generated_dir: &Path) -> Self {
+        fs::read_to_string(generated_dir.join(CODEGEN_MANIFEST_FILE_NAME))
+            .ok()
+            .and_then(|manifest_str| serde_json::from_str(&manifest_str).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn write(&self, generated_dir: &Path) -> Result<()> {
+        let manifest_str =
+            serde_json::to_string_pretty(self).context("Failed serializing codegen manifest")?;
+        fs::write(generated_dir.join(CODEGEN_MANIFEST_FILE_NAME), manifest_str)
+            .context("Failed writing codegen manifest")
+    }
+
+    ///Files from the previous manifest that weren't generated this time.
+    ///Only plain relative paths are returned, so an edited manifest can't point
+    ///outside of the generated directory.
+    fn get_stale_files<'a>(&self, previous_manifest: &'a Self) -> Vec<&'a PathBuf> {
+        previous_manifest
+            .files
+            .difference(&self.files)
+            .filter(|path| {
+                path.components()
+                    .all(|component| matches!(component, Component::Normal(_)))
+            })
+            .collect()
+    }
+
+    pub fn remove_stale_files(&self, previous_manifest: &Self, generated_dir: &Path) -> Result<()> {
+        for stale_file in self.get_stale_files(previous_manifest) {
+            let path = generated_dir.join(stale_file);
+            match fs::remove_file(&path) {
+                Err(err) if err.kind() != std::io::ErrorKind::NotFound => {
+                    return Err(err).context(format!(
+                        "Failed removing stale generated file {}",
+                        path.display()
+                    ));
+                }
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CodegenManifest;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn gets_stale_files_from_previous_manifest() {
+        let generated_dir = Path::new("generated");
+        let previous_manifest = CodegenManifest::from_generated_files(
+            generated_dir,
+            &[
+                PathBuf::from("generated/src/Types.res"),
+                PathBuf::from("generated/src/handlers/OldContract.res"),
+                PathBuf::from("generated/../config.yaml"),
+            ],
+        )
+        .unwrap();
+        let manifest = CodegenManifest::from_generated_files(
+            generated_dir,
+            &[PathBuf::from("generated/src/Types.res")],
+        )
+        .unwrap();
+
+        assert_eq!(
+            manifest.get_stale_files(&previous_manifest),
+            vec![&PathBuf::from("src/handlers/OldContract.res")]
+        );
+    }
+
+    #[test]
+    fn fails_on_files_outside_of_generated_dir() {
+        assert!(CodegenManifest::from_generated_files(
+            Path::new("generated"),
+            &[PathBuf::from("src/EventHandlers.ts")],
+        )
+        .is_err());
+    }
+}
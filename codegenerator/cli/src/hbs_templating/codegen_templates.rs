@@ -8,13 +8,15 @@ use std::{
 use super::hbs_dir_generator::HandleBarsDirGenerator;
 use crate::{
     config_parsing::{
-        entity_parsing::{Entity, Field, GraphQLEnum, MultiFieldIndex, Schema},
+        entity_parsing::{
+            ComputedField, Entity, Field, FullTextIndex, GraphQLEnum, MultiFieldIndex, Schema,
+        },
         event_parsing::{abi_to_rescript_type, EthereumEventParam},
-        human_config::evm::{For, Rpc, RpcSyncConfig},
+        human_config::evm::{FinalityTag, For, Rpc, RpcSyncConfig},
         postgres_types,
         system_config::{
             self, get_envio_version, Abi, Ecosystem, EventKind, FuelEventKind, MainEvmDataSource,
-            SelectedField, SystemConfig,
+            MainFuelDataSource, SelectedField, SystemConfig,
         },
     },
     persisted_state::{PersistedState, PersistedStateJsonString},
@@ -23,7 +25,8 @@ use crate::{
         ParsedProjectPaths,
     },
     rescript_types::{
-        RescriptRecordField, RescriptSchemaMode, RescriptTypeExpr, RescriptTypeIdent,
+        RescriptRecordField, RescriptSchemaMode, RescriptTypeDecl, RescriptTypeDeclMulti,
+        RescriptTypeExpr, RescriptTypeIdent,
     },
     template_dirs::TemplateDirs,
     utils::text::{Capitalize, CapitalizedOptions, CaseOptions},
@@ -221,12 +224,42 @@ pub struct DerivedFieldTemplate {
     pub derived_from_field: String,
 }
 
+#[derive(Serialize, Debug, PartialEq, Clone)]
+pub struct ComputedFieldTemplate {
+    pub field_name: String,
+    pub field_type: postgres_types::Primitive,
+    ///Sql expression of the computed field as a string literal
+    pub sql_code: String,
+}
+
+impl ComputedFieldTemplate {
+    ///The name of the row argument in the generated sql function
+    const ROW_ARG: &'static str = "entity";
+
+    fn from_computed_field(
+        computed_field: &ComputedField,
+        entity: &Entity,
+        schema: &Schema,
+    ) -> Result<Self> {
+        let sql = computed_field.get_sql_expression(entity, schema, Self::ROW_ARG)?;
+        Ok(ComputedFieldTemplate {
+            field_name: computed_field.name.clone(),
+            field_type: computed_field
+                .field_type
+                .to_underlying_postgres_primitive(schema)?,
+            sql_code: serde_json::to_string(&sql)?,
+        })
+    }
+}
+
 #[derive(Serialize, Debug, PartialEq, Clone)]
 pub struct EntityRecordTypeTemplate {
     pub name: CapitalizedOptions,
     pub postgres_fields: Vec<postgres_types::Field>,
     pub composite_indices: Vec<Vec<String>>,
+    pub full_text_indices: Vec<FullTextIndex>,
     pub derived_fields: Vec<DerivedFieldTemplate>,
+    pub computed_fields: Vec<ComputedFieldTemplate>,
     pub params: Vec<EntityParamTypeTemplate>,
     pub index_groups: Vec<EntityIndexParamGroup>,
     pub relational_params: FilteredTemplateLists<EntityRelationalTypesTemplate>,
@@ -319,13 +352,27 @@ impl EntityRecordTypeTemplate {
             .filter_map(|gql_field| gql_field.get_derived_from_field())
             .collect();
 
+        let computed_fields = entity
+            .computed_fields
+            .iter()
+            .map(|computed_field| {
+                ComputedFieldTemplate::from_computed_field(computed_field, entity, &config.schema)
+            })
+            .collect::<Result<Vec<_>>>()
+            .context(format!(
+                "Failed constructing computed fields of entity {}",
+                entity.name
+            ))?;
+
         let composite_indices = entity.get_composite_indices();
 
         Ok(EntityRecordTypeTemplate {
             name: entity.name.to_capitalized_options(),
             postgres_fields,
             derived_fields,
+            computed_fields,
             composite_indices,
+            full_text_indices: entity.full_text_indexes.clone(),
             params,
             index_groups,
             relational_params,
@@ -383,6 +430,25 @@ impl EventMod {
                 r#"LogData({
   logId: sighash,
   decode: Fuel.Receipt.getLogDataDecoder(~abi, ~logId=sighash),
+})"#
+                .to_string(),
+            ),
+            Some(FuelEventKind::PredicateSpend(_)) => Some(
+                r#"PredicateSpend({
+  decode: Fuel.getMainArgsDecoder(~abi),
+})"#
+                .to_string(),
+            ),
+            Some(FuelEventKind::ScriptRun(_)) => Some(
+                r#"ScriptRun({
+  decode: Fuel.getMainArgsDecoder(~abi),
+})"#
+                .to_string(),
+            ),
+            Some(FuelEventKind::ScriptLogData(_)) => Some(
+                r#"ScriptLogData({
+  logId: sighash,
+  decode: Fuel.Receipt.getLogDataDecoder(~abi, ~logId=sighash),
 })"#
                 .to_string(),
             ),
@@ -394,7 +460,10 @@ impl EventMod {
             Some(FuelEventKind::Burn) => "burn".to_string(),
             Some(FuelEventKind::Call) => "call".to_string(),
             Some(FuelEventKind::Transfer) => "transfer".to_string(),
-            Some(FuelEventKind::LogData(_)) => sighash.to_string(),
+            Some(FuelEventKind::LogData(_))
+            | Some(FuelEventKind::PredicateSpend(_))
+            | Some(FuelEventKind::ScriptRun(_))
+            | Some(FuelEventKind::ScriptLogData(_)) => sighash.to_string(),
         };
 
         let (block_type, block_schema, transaction_type, transaction_schema) =
@@ -453,6 +522,8 @@ let register = (): Internal.fuelEventConfig => {{
   kind: {fuel_event_kind_code},
   filterByAddresses: false,
   dependsOnAddresses: !(handlerRegister->EventRegister.isWildcard),
+  blockSchema: blockSchema->(Utils.magic: S.t<block> => S.t<Internal.eventBlock>),
+  transactionSchema: transactionSchema->(Utils.magic: S.t<transaction> => S.t<Internal.eventTransaction>),
   {base_event_config_code}
 }}"#
             ),
@@ -694,6 +765,56 @@ impl EventTemplate {
         }
     }
 
+    /// The type of the event params as it's represented in runtime
+    pub fn get_event_args_type_expr(config_event: &system_config::Event) -> RescriptTypeExpr {
+        let record = |fields: Vec<(&str, RescriptTypeIdent)>| {
+            RescriptTypeExpr::Record(
+                fields
+                    .into_iter()
+                    .map(|(name, type_ident)| {
+                        RescriptRecordField::new(name.to_string(), type_ident)
+                    })
+                    .collect(),
+            )
+        };
+        match &config_event.kind {
+            EventKind::Params(params) if params.is_empty() => {
+                RescriptTypeExpr::Identifier(RescriptTypeIdent::Unit)
+            }
+            EventKind::Params(params) => RescriptTypeExpr::Record(
+                params
+                    .iter()
+                    .map(|p| {
+                        RescriptRecordField::new(
+                            p.name.to_string(),
+                            abi_to_rescript_type(&p.into()),
+                        )
+                    })
+                    .collect(),
+            ),
+            EventKind::Fuel(
+                FuelEventKind::LogData(type_ident)
+                | FuelEventKind::ScriptRun(type_ident)
+                | FuelEventKind::ScriptLogData(type_ident),
+            ) => RescriptTypeExpr::Identifier(type_ident.clone()),
+            EventKind::Fuel(FuelEventKind::PredicateSpend(type_ident)) => record(vec![
+                ("owner", RescriptTypeIdent::Address),
+                ("assetId", RescriptTypeIdent::String),
+                ("amount", RescriptTypeIdent::BigInt),
+                ("data", type_ident.clone()),
+            ]),
+            EventKind::Fuel(FuelEventKind::Mint | FuelEventKind::Burn) => record(vec![
+                ("subId", RescriptTypeIdent::String),
+                ("amount", RescriptTypeIdent::BigInt),
+            ]),
+            EventKind::Fuel(FuelEventKind::Call | FuelEventKind::Transfer) => record(vec![
+                ("to", RescriptTypeIdent::Address),
+                ("assetId", RescriptTypeIdent::String),
+                ("amount", RescriptTypeIdent::BigInt),
+            ]),
+        }
+    }
+
     pub fn from_config_event(
         config_event: &system_config::Event,
         preload_handlers: bool,
@@ -717,21 +838,7 @@ impl EventTemplate {
                     })
                     .collect::<Vec<_>>();
 
-                let data_type_expr = if params.is_empty() {
-                    RescriptTypeExpr::Identifier(RescriptTypeIdent::Unit)
-                } else {
-                    RescriptTypeExpr::Record(
-                        params
-                            .iter()
-                            .map(|p| {
-                                RescriptRecordField::new(
-                                    p.name.to_string(),
-                                    abi_to_rescript_type(&p.into()),
-                                )
-                            })
-                            .collect(),
-                    )
-                };
+                let data_type_expr = Self::get_event_args_type_expr(config_event);
 
                 let event_mod = EventMod {
                     sighash: config_event.sighash.to_string(),
@@ -760,7 +867,9 @@ impl EventTemplate {
             EventKind::Fuel(fuel_event_kind) => {
                 let fuel_event_kind = fuel_event_kind.clone();
                 match &fuel_event_kind {
-                    FuelEventKind::LogData(type_indent) => {
+                    FuelEventKind::LogData(type_indent)
+                    | FuelEventKind::ScriptRun(type_indent)
+                    | FuelEventKind::ScriptLogData(type_indent) => {
                         let event_mod = EventMod {
                             sighash: config_event.sighash.to_string(),
                             topic_count: 0, //Default to 0 for fuel,
@@ -785,6 +894,32 @@ impl EventTemplate {
                             params: vec![],
                         })
                     }
+                    FuelEventKind::PredicateSpend(type_indent) => {
+                        let event_mod = EventMod {
+                            sighash: config_event.sighash.to_string(),
+                            topic_count: 0, //Default to 0 for fuel,
+                            event_name: event_name.clone(),
+                            parse_event_filters_code: "".to_string(),
+                            data_type: format!("Internal.fuelPredicateSpendParams<{type_indent}>"),
+                            params_raw_event_schema: format!(
+                                "Internal.makeFuelPredicateSpendParamsSchema({})->Utils.Schema.\
+                                 coerceToJsonPgType",
+                                type_indent.to_rescript_schema(&RescriptSchemaMode::ForDb)
+                            ),
+                            convert_hyper_sync_event_args_code:
+                                Self::CONVERT_HYPER_SYNC_EVENT_ARGS_NEVER.to_string(),
+                            event_filter_type: Self::EVENT_FILTER_TYPE_STUB.to_string(),
+                            custom_field_selection: config_event.field_selection.clone(),
+                            fuel_event_kind: Some(fuel_event_kind),
+                            preload_handlers: preload_handlers,
+                        };
+
+                        Ok(EventTemplate {
+                            name: event_name,
+                            module_code: event_mod.to_string(),
+                            params: vec![],
+                        })
+                    }
                     FuelEventKind::Mint | FuelEventKind::Burn => Ok(Self::from_fuel_supply_event(
                         config_event,
                         preload_handlers,
@@ -808,6 +943,7 @@ pub struct ContractTemplate {
     pub name: CapitalizedOptions,
     pub codegen_events: Vec<EventTemplate>,
     pub module_code: String,
+    pub ts_type_declarations: String,
     pub handler: HandlerPathsTemplate,
 }
 
@@ -878,11 +1014,39 @@ let eventSignatures = [{}]
             }
         };
 
+        let ts_type_declarations = {
+            let abi_type_declarations = match &contract.abi {
+                Abi::Evm(_) => None,
+                Abi::Fuel(abi) => Some(abi.to_rescript_type_decl_multi()?.to_typescript()),
+            };
+            let event_args_type_declarations = RescriptTypeDeclMulti::new(
+                contract
+                    .events
+                    .iter()
+                    .map(|event| {
+                        RescriptTypeDecl::new(
+                            format!("{}_eventArgs", event.name.capitalize()),
+                            EventTemplate::get_event_args_type_expr(event),
+                            vec![],
+                        )
+                    })
+                    .collect(),
+            )
+            .to_typescript();
+            abi_type_declarations
+                .into_iter()
+                .chain([event_args_type_declarations])
+                .filter(|code| !code.is_empty())
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
         Ok(ContractTemplate {
             name,
             handler,
             codegen_events,
             module_code,
+            ts_type_declarations,
         })
     }
 }
@@ -938,6 +1102,8 @@ type EthAddress = String;
 struct NetworkTemplate {
     pub id: u64,
     confirmed_block_threshold: i32,
+    finality: Option<String>,
+    min_confirmed_block_threshold: Option<i32>,
     start_block: u64,
     end_block: Option<u64>,
 }
@@ -947,6 +1113,16 @@ impl NetworkTemplate {
         NetworkTemplate {
             id: network.id,
             confirmed_block_threshold: network.confirmed_block_threshold,
+            finality: network.finality.map(|finality| {
+                match finality.tag {
+                    FinalityTag::Finalized => "Finalized",
+                    FinalityTag::Safe => "Safe",
+                }
+                .to_string()
+            }),
+            min_confirmed_block_threshold: network
+                .finality
+                .and_then(|finality| finality.min_confirmed_block_threshold),
             start_block: network.start_block,
             end_block: network.end_block,
         }
@@ -1010,14 +1186,36 @@ impl NetworkConfigTemplate {
 
         let (sources_code, deprecated_sync_source_code) = match &network.sync_source {
             system_config::DataSource::Fuel {
-                hypersync_endpoint_url,
-            } => (
-                format!(
-                    "[HyperFuelSource.make({{chain: chain, endpointUrl: \
-                         \"{hypersync_endpoint_url}\"}})]",
+                main,
+                fuel_core_fallback_url,
+            } => match main {
+                MainFuelDataSource::HyperFuel {
+                    hypersync_endpoint_url,
+                } => {
+                    let fallback_code = match fuel_core_fallback_url {
+                        Some(url) => format!(
+                            ", FuelCoreSource.make({{chain: chain, endpointUrl: \"{url}\", \
+                             sourceFor: Fallback}})"
+                        ),
+                        None => "".to_string(),
+                    };
+                    (
+                        format!(
+                            "[HyperFuelSource.make({{chain: chain, endpointUrl: \
+                             \"{hypersync_endpoint_url}\", shouldRollbackOnReorg: {}}}){fallback_code}]",
+                            config.rollback_on_reorg
+                        ),
+                        format!("HyperFuel({{endpointUrl: \"{hypersync_endpoint_url}\"}})"),
+                    )
+                }
+                MainFuelDataSource::FuelCore { url } => (
+                    format!(
+                        "[FuelCoreSource.make({{chain: chain, endpointUrl: \"{url}\", \
+                         sourceFor: Sync}})]",
+                    ),
+                    format!("FuelCore({{endpointUrl: \"{url}\"}})"),
                 ),
-                format!("HyperFuel({{endpointUrl: \"{hypersync_endpoint_url}\"}})"),
-            ),
+            },
             system_config::DataSource::Evm {
                 main,
                 is_client_decoder,
@@ -1032,10 +1230,19 @@ impl NetworkConfigTemplate {
                 let hyper_sync_code = match main {
                     MainEvmDataSource::HyperSync {
                         hypersync_endpoint_url,
+                        ..
                     } => format!("Some(\"{hypersync_endpoint_url}\")"),
                     MainEvmDataSource::Rpc(_) => "None".to_string(),
                 };
 
+                let hyper_sync_api_token_code = match main {
+                    MainEvmDataSource::HyperSync {
+                        api_token: Some(api_token),
+                        ..
+                    } => format!(", ~hyperSyncApiToken={}", to_rescript_string(api_token)),
+                    _ => String::new(),
+                };
+
                 let rpc_to_sync_config_options = |rpc: &Rpc| match rpc.sync_config {
                     None => "{}".to_string(),
                     Some(RpcSyncConfig {
@@ -1092,6 +1299,7 @@ impl NetworkConfigTemplate {
                 let deprecated_sync_source_code = match main {
                     MainEvmDataSource::HyperSync {
                         hypersync_endpoint_url,
+                        ..
                     } => {
                         format!("HyperSync({{endpointUrl: \"{hypersync_endpoint_url}\"}})")
                     }
@@ -1106,14 +1314,46 @@ impl NetworkConfigTemplate {
                 let rpcs = rpcs
                     .iter()
                     .map(|rpc| {
+                        let mut optional_fields = String::new();
+                        if let Some(weight) = rpc.weight {
+                            optional_fields.push_str(&format!(", weight: {}", weight));
+                        }
+                        if let Some(max_requests_per_second) = rpc.max_requests_per_second {
+                            optional_fields.push_str(&format!(
+                                ", maxRequestsPerSecond: {}",
+                                max_requests_per_second
+                            ));
+                        }
+                        if let Some(max_block_range) = rpc.max_block_range {
+                            optional_fields
+                                .push_str(&format!(", maxBlockRange: {}", max_block_range));
+                        }
+                        if let Some(headers) = &rpc.headers {
+                            let headers_code = headers
+                                .iter()
+                                .map(|(name, value)| {
+                                    format!(
+                                        "({}, {})",
+                                        to_rescript_string(name),
+                                        to_rescript_string(value)
+                                    )
+                                })
+                                .collect::<Vec<_>>()
+                                .join(", ");
+                            optional_fields.push_str(&format!(
+                                ", headers: Js.Dict.fromArray([{headers_code}])"
+                            ));
+                        }
                         format!(
-                            "{{url: \"{}\", sourceFor: {}, syncConfig: {}}}",
+                            "{{url: \"{}\", sourceFor: {}, syncConfig: {}{}}}",
                             rpc.url,
                             match rpc.source_for {
                                 For::Sync => "Sync",
                                 For::Fallback => "Fallback",
+                                For::Realtime => "Realtime",
                             },
-                            rpc_to_sync_config_options(rpc)
+                            rpc_to_sync_config_options(rpc),
+                            optional_fields
                         )
                     })
                     .collect::<Vec<String>>()
@@ -1121,7 +1361,7 @@ impl NetworkConfigTemplate {
 
                 (
                     format!(
-                        "NetworkSources.evm(~chain, ~contracts=[{contracts_code}], ~hyperSync={hyper_sync_code}, \
+                        "NetworkSources.evm(~chain, ~contracts=[{contracts_code}], ~hyperSync={hyper_sync_code}{hyper_sync_api_token_code}, \
                          ~allEventSignatures=[{all_event_signatures}]->Belt.Array.concatMany, \
                          ~shouldUseHypersyncClientDecoder={is_client_decoder}, ~rpcs=[{rpcs}])"
                     ),
@@ -1262,6 +1502,8 @@ pub struct ProjectTemplate {
     codegen_contracts: Vec<ContractTemplate>,
     has_typescript: bool,
     entities: Vec<EntityRecordTypeTemplate>,
+    //Record types and schemas of the @embedded types, used by entity fields
+    embedded_types_code: String,
     gql_enums: Vec<GraphQlEnumTypeTemplate>,
     chain_configs: Vec<NetworkConfigTemplate>,
     persisted_state: PersistedStateJsonString,
@@ -1280,18 +1522,54 @@ pub struct ProjectTemplate {
     relative_path_to_root_from_generated: String,
 }
 
+///Quotes a string for ReScript code. JSON string escapes are valid in ReScript strings.
+fn to_rescript_string(value: &str) -> String {
+    serde_json::to_string(value).expect("Strings should always serialize to JSON")
+}
+
+fn get_embedded_types_code(schema: &Schema) -> Result<String> {
+    let embedded_types = schema.get_embedded_types();
+    if embedded_types.is_empty() {
+        return Ok("".to_string());
+    }
+
+    let type_decls = RescriptTypeDeclMulti::new(
+        embedded_types
+            .into_iter()
+            .map(|embedded_type| embedded_type.to_rescript_type_decl(schema))
+            .collect::<Result<_>>()?,
+    );
+
+    // Declared one by one instead of a single recursive type,
+    // so every record gets exported with genType
+    let types_code = type_decls
+        .get_sorted_by_dependencies()
+        .iter()
+        .map(|type_decl| format!("@genType\n{type_decl}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    Ok(format!(
+        "{types_code}\n{}",
+        type_decls.to_rescript_schema(&RescriptSchemaMode::ForDb)
+    ))
+}
+
 impl ProjectTemplate {
-    pub fn generate_templates(&self, project_paths: &ParsedProjectPaths) -> Result<()> {
+    pub fn generate_templates(
+        &self,
+        project_paths: &ParsedProjectPaths,
+        skipped_files: &BTreeSet<PathBuf>,
+    ) -> Result<Vec<PathBuf>> {
         let template_dirs = TemplateDirs::new();
         let dynamic_codegen_dir = template_dirs
             .get_codegen_dynamic_dir()
             .context("Failed getting dynamic codegen dir")?;
 
         let hbs =
-            HandleBarsDirGenerator::new(&dynamic_codegen_dir, &self, &project_paths.generated);
-        hbs.generate_hbs_templates()?;
-
-        Ok(())
+            HandleBarsDirGenerator::new(&dynamic_codegen_dir, &self, &project_paths.generated)
+                .with_skipped_files(skipped_files);
+        hbs.generate_hbs_templates()
     }
 
     pub fn from_config(cfg: &SystemConfig) -> Result<Self> {
@@ -1313,6 +1591,9 @@ impl ProjectTemplate {
             .collect::<Result<_>>()
             .context("Failed generating entity template types")?;
 
+        let embedded_types_code = get_embedded_types_code(&cfg.schema)
+            .context("Failed generating embedded template types")?;
+
         let gql_enums: Vec<GraphQlEnumTypeTemplate> = cfg
             .get_gql_enums()
             .iter()
@@ -1368,6 +1649,7 @@ impl ProjectTemplate {
             has_typescript,
             codegen_contracts,
             entities,
+            embedded_types_code,
             gql_enums,
             chain_configs,
             persisted_state,
@@ -1431,6 +1713,8 @@ mod test {
             Self {
                 id: 0,
                 confirmed_block_threshold: 200,
+                finality: None,
+                min_confirmed_block_threshold: None,
                 start_block: 0,
                 end_block: None,
             }
@@ -1461,7 +1745,7 @@ mod test {
             codegen_contracts: vec![contract1],
             is_fuel: true,
             sources_code: format!(
-                "[HyperFuelSource.make({{chain: chain, endpointUrl: \"https://fuel-testnet.hypersync.xyz\"}})]"
+                "[HyperFuelSource.make({{chain: chain, endpointUrl: \"https://fuel-testnet.hypersync.xyz\", shouldRollbackOnReorg: false}})]"
             ),
             deprecated_sync_source_code: format!(
                 "HyperFuel({{endpointUrl: \"https://fuel-testnet.hypersync.xyz\"}})"
@@ -1490,6 +1774,7 @@ mod test {
 
         let network1 = NetworkTemplate {
             id: 1,
+            confirmed_block_threshold: 96,
             ..NetworkTemplate::default()
         };
 
@@ -1532,6 +1817,7 @@ mod test {
 
         let network1 = NetworkTemplate {
             id: 1,
+            confirmed_block_threshold: 96,
             ..NetworkTemplate::default()
         };
 
@@ -1584,6 +1870,7 @@ mod test {
 
         let network1 = NetworkTemplate {
             id: 1,
+            confirmed_block_threshold: 96,
             ..NetworkTemplate::default()
         };
 
@@ -1615,6 +1902,7 @@ mod test {
     fn convert_to_chain_configs_case_4() {
         let network1 = NetworkTemplate {
             id: 1,
+            confirmed_block_threshold: 96,
             ..NetworkTemplate::default()
         };
 
@@ -2,6 +2,7 @@
 ///tuples in event params
 mod nested_params {
     use super::*;
+    use ethers::abi::ParamType;
     pub type ParamIndex = usize;
 
     ///Recursive Representation of param token. With reference to it's own index
@@ -199,7 +200,7 @@ use crate::{
     utils::text::{Capitalize, CapitalizedOptions},
 };
 use anyhow::{Context, Result};
-use ethers::abi::ParamType;
+use itertools::Itertools;
 use nested_params::{flatten_event_inputs, FlattenedEventParam, ParamIndex};
 use serde::Serialize;
 use std::{path::Path, vec};
@@ -337,7 +338,6 @@ pub struct Param {
     ///the event key ie. event.params.myTupleParam[0][1]
     tuple_param_accessor_indexes: Option<Vec<ParamIndex>>,
     graphql_type: FieldType,
-    is_eth_address: bool,
 }
 
 impl Param {
@@ -355,7 +355,6 @@ impl Param {
                     "Converting eth event param '{}' to gql scalar",
                     flattened_event_param.event_param.name
                 ))?,
-            is_eth_address: flattened_event_param.event_param.kind == ParamType::Address,
         })
     }
 }
@@ -369,6 +368,94 @@ impl From<Param> for Field {
     }
 }
 
+///An entity mirroring the params of a config event. Used by `envio schema generate`
+///to append the entities of events which aren't in the schema yet
+#[derive(Debug, PartialEq)]
+pub struct EventEntity {
+    pub name: String,
+    fields: Vec<EventEntityField>,
+}
+
+#[derive(Debug, PartialEq)]
+struct EventEntityField {
+    name: String,
+    graphql_type: FieldType,
+    ///Indexed event params are likely to be queried by, so they get an @index
+    is_indexed: bool,
+}
+
+impl EventEntity {
+    ///Fuel events don't have params to map to entity fields, so there is no entity for them
+    fn from_config_event(
+        event: &system_config::Event,
+        contract: &system_config::Contract,
+    ) -> Result<Option<Self>> {
+        let params = match &event.kind {
+            EventKind::Params(params) => params,
+            EventKind::Fuel(_) => return Ok(None),
+        };
+        let fields = flatten_event_inputs(params.clone())
+            .into_iter()
+            .map(|flattened_event_param| {
+                let is_indexed = flattened_event_param.event_param.indexed;
+                let param = Param::from_event_param(flattened_event_param)?;
+                Ok(EventEntityField {
+                    name: param.entity_key.original,
+                    graphql_type: param.graphql_type,
+                    is_indexed,
+                })
+            })
+            .collect::<Result<_>>()
+            .context(format!("Failed getting params for event {}", event.name))?;
+
+        Ok(Some(EventEntity {
+            name: format!("{}_{}", contract.name.capitalize(), event.name),
+            fields,
+        }))
+    }
+
+    ///Gets an entity for every event in the config without a type
+    ///of the same name in the schema. Fuel events are skipped
+    pub fn get_missing_from_schema(config: &SystemConfig) -> Result<Vec<Self>> {
+        let mut event_entities = vec![];
+        for contract in config.get_contracts() {
+            for event in &contract.events {
+                let event_entity = Self::from_config_event(event, contract).context(format!(
+                    "Failed getting entity for event {} of contract {}",
+                    event.name, contract.name
+                ))?;
+                if let Some(event_entity) = event_entity {
+                    if !config.schema.has_type_def(&event_entity.name) {
+                        event_entities.push(event_entity);
+                    }
+                }
+            }
+        }
+
+        //Events with overloaded signatures would otherwise produce duplicate types
+        Ok(event_entities
+            .into_iter()
+            .unique_by(|event_entity| event_entity.name.clone())
+            .collect())
+    }
+
+    pub fn to_graphql_type_def(&self) -> String {
+        let fields_code = self
+            .fields
+            .iter()
+            .map(|field| {
+                let index_directive = if field.is_indexed { " @index" } else { "" };
+                format!(
+                    "  {}: {}{index_directive}\n",
+                    field.name, field.graphql_type
+                )
+            })
+            .collect::<String>();
+
+        format!("type {} {{\n  id: ID!\n{fields_code}}}\n", self.name)
+    }
+}
+
 impl AutoSchemaHandlerTemplate {
     pub fn try_from(
         config: SystemConfig,
@@ -454,7 +541,7 @@ impl AutoSchemaHandlerTemplate {
 #[cfg(test)]
 mod test {
     use super::*;
-    use ethers::abi::EventParam;
+    use ethers::abi::{EventParam, ParamType};
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -578,4 +665,75 @@ mod test {
             "`${event.chainId}_${event.block.height}_${event.logIndex}`".to_string()
         );
     }
+
+    #[test]
+    fn test_event_entities_missing_from_schema() {
+        let project_paths = crate::project_paths::ParsedProjectPaths::new(
+            &format!("{}/test", env!("CARGO_MANIFEST_DIR")),
+            "generated/",
+            "configs/config1.yaml",
+        )
+        .expect("Parsed paths");
+        let config = SystemConfig::parse_from_project_files(&project_paths)
+            .expect("Deserialized yml config should be parseable");
+
+        let event_entities = EventEntity::get_missing_from_schema(&config).unwrap();
+
+        assert_eq!(
+            event_entities
+                .iter()
+                .map(|event_entity| event_entity.name.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                "Contract1_NewGravatar".to_string(),
+                "Contract1_UpdatedGravatar".to_string()
+            ]
+        );
+        assert_eq!(
+            event_entities[0].to_graphql_type_def(),
+            "type Contract1_NewGravatar {\n  id: ID!\n  event_id: BigInt!\n  owner: \
+             Address!\n  displayName: String!\n  imageUrl: String!\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_event_entities_skip_fuel_events() {
+        let project_paths = crate::project_paths::ParsedProjectPaths::new(
+            &format!("{}/test", env!("CARGO_MANIFEST_DIR")),
+            "generated/",
+            "configs/fuel-config.yaml",
+        )
+        .expect("Parsed paths");
+        let config = SystemConfig::parse_from_project_files(&project_paths)
+            .expect("Deserialized yml config should be parseable");
+
+        assert_eq!(
+            EventEntity::get_missing_from_schema(&config).unwrap(),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_event_entity_indexes_indexed_params() {
+        let event_entity = EventEntity {
+            name: "ERC20_Transfer".to_string(),
+            fields: vec![
+                EventEntityField {
+                    name: "from".to_string(),
+                    graphql_type: FieldType::from_ethabi_type(&ParamType::Address).unwrap(),
+                    is_indexed: true,
+                },
+                EventEntityField {
+                    name: "value".to_string(),
+                    graphql_type: FieldType::from_ethabi_type(&ParamType::Uint(256)).unwrap(),
+                    is_indexed: false,
+                },
+            ],
+        };
+
+        assert_eq!(
+            event_entity.to_graphql_type_def(),
+            "type ERC20_Transfer {\n  id: ID!\n  from: Address! @index\n  value: BigInt!\n}\n"
+        );
+    }
 }
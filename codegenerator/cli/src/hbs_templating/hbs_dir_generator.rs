@@ -1,46 +1,65 @@
-use crate::{project_paths::path_utils::normalize_path, template_dirs::RelativeDir};
+use crate::{
+    project_paths::path_utils::normalize_path, template_dirs::RelativeDir,
+    utils::file_system::write_file_if_changed,
+};
 use anyhow::{anyhow, Context};
 use handlebars::{handlebars_helper, Handlebars};
 use include_dir::DirEntry;
 use serde::Serialize;
-use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::{collections::BTreeSet, fs};
+
+///Creates the handlebars registry with the settings and helpers shared by
+///all the templates
+pub fn new_handlebars<'a>() -> Handlebars<'a> {
+    let mut handlebars = Handlebars::new();
+    handlebars.set_strict_mode(true);
+    handlebars.register_escape_fn(handlebars::no_escape);
+
+    handlebars_helper!(vec_to_array_helper: |vec: Vec<String>| {
+      let items = vec
+        .iter()
+        .map(|v| format!("\"{}\"", v))
+        .collect::<Vec<_>>()
+        .join(", ");
+      format!("[{}]", items)
+    });
+    handlebars.register_helper("vec_to_array", Box::new(vec_to_array_helper));
+
+    handlebars
+}
 
 pub struct HandleBarsDirGenerator<'a, T: Serialize> {
     handlebars: handlebars::Handlebars<'a>,
     templates_dir: &'a RelativeDir<'a>,
     rs_template: &'a T,
     output_dir: &'a Path,
+    //Output files that shouldn't be written, since they are shadowed by other templates
+    skipped_files: BTreeSet<PathBuf>,
 }
 
 impl<'a, T: Serialize> HandleBarsDirGenerator<'a, T> {
     pub fn new(templates_dir: &'a RelativeDir, rs_template: &'a T, output_dir: &'a Path) -> Self {
-        let mut handlebars = Handlebars::new();
-        handlebars.set_strict_mode(true);
-        handlebars.register_escape_fn(handlebars::no_escape);
-
-        handlebars_helper!(vec_to_array_helper: |vec: Vec<String>| {
-          let items = vec
-            .iter()
-            .map(|v| format!("\"{}\"", v))
-            .collect::<Vec<_>>()
-            .join(", ");
-          format!("[{}]", items)
-        });
-        handlebars.register_helper("vec_to_array", Box::new(vec_to_array_helper));
-
         HandleBarsDirGenerator {
-            handlebars,
+            handlebars: new_handlebars(),
             templates_dir,
             rs_template,
             output_dir,
+            skipped_files: BTreeSet::new(),
         }
     }
 
+    ///Skips rendering templates with any of the given output paths
+    pub fn with_skipped_files(mut self, skipped_files: &BTreeSet<PathBuf>) -> Self {
+        self.skipped_files = skipped_files.clone();
+        self
+    }
+
     fn generate_hbs_templates_internal_recursive(
         &self,
         //The relative dir in "TemplateDirs" that can be extracted
         hbs_templates_root_dir: &RelativeDir,
+        generated_files: &mut Vec<PathBuf>,
     ) -> anyhow::Result<()> {
         for entry in hbs_templates_root_dir.entries() {
             match entry {
@@ -70,6 +89,20 @@ impl<'a, T: Serialize> HandleBarsDirGenerator<'a, T> {
                             .file_stem()
                             .ok_or_else(|| anyhow!("Could not produce filestem of {}", path_str))?;
 
+                        //Setup output directory
+                        let output_dir_path = normalize_path(self.output_dir.join(parent));
+                        let output_dir_path_str = output_dir_path.to_str().ok_or({
+                            anyhow!("Could not cast output path to str in generate_hbs_templates")
+                        })?;
+
+                        //append the filename
+                        let output_file_path = output_dir_path.join(file_stem);
+
+                        //Shadowed templates are left for whatever shadows them
+                        if self.skipped_files.contains(&output_file_path) {
+                            continue;
+                        }
+
                         //Read the template file contents
                         let file_str = file.contents_utf8().ok_or_else(|| {
                             anyhow!("Could not produce file contents of {}", path_str)
@@ -81,24 +114,16 @@ impl<'a, T: Serialize> HandleBarsDirGenerator<'a, T> {
                             .render_template(file_str, &self.rs_template)
                             .context(format!("Could not render file at {}", path_str))?;
 
-                        //Setup output directory
-                        let output_dir_path = normalize_path(self.output_dir.join(parent));
-                        let output_dir_path_str = output_dir_path.to_str().ok_or({
-                            anyhow!("Could not cast output path to str in generate_hbs_templates")
-                        })?;
-
                         //ensure the dir exists or is created
                         fs::create_dir_all(&output_dir_path).context(format!(
                             "create_dir_all failed at {}",
                             &output_dir_path_str,
                         ))?;
 
-                        //append the filename
-                        let output_file_path = output_dir_path.join(file_stem);
-
-                        //Write the file
-                        fs::write(&output_file_path, rendered_file)
+                        //Write the file, unchanged files are skipped to preserve their mtime
+                        write_file_if_changed(&output_file_path, rendered_file)
                             .context(format!("file write failed at {}", &output_dir_path_str))?;
+                        generated_files.push(output_file_path);
                     }
                 }
                 DirEntry::Dir(dir) => Self::generate_hbs_templates_internal_recursive(
@@ -106,12 +131,21 @@ impl<'a, T: Serialize> HandleBarsDirGenerator<'a, T> {
                     //Create a child when recursing so that there is always a reference
                     //to the relative path this template dir is at
                     &hbs_templates_root_dir.new_child(dir),
+                    generated_files,
                 )?,
             }
         }
         Ok(())
     }
-    pub fn generate_hbs_templates(&self) -> anyhow::Result<()> {
-        Self::generate_hbs_templates_internal_recursive(self, self.templates_dir)
+    ///Renders all the templates to the output dir and returns the paths of the
+    ///rendered files
+    pub fn generate_hbs_templates(&self) -> anyhow::Result<Vec<PathBuf>> {
+        let mut generated_files = vec![];
+        Self::generate_hbs_templates_internal_recursive(
+            self,
+            self.templates_dir,
+            &mut generated_files,
+        )?;
+        Ok(generated_files)
     }
 }
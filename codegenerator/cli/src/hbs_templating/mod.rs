@@ -1,4 +1,6 @@
+pub mod codegen_manifest;
 pub mod codegen_templates;
 pub mod contract_import_templates;
 pub mod hbs_dir_generator;
 pub mod init_templates;
+pub mod templates_override;
@@ -0,0 +1,196 @@
+use super::hbs_dir_generator::new_handlebars;
+use crate::{project_paths::ParsedProjectPaths, utils::file_system::write_file_if_changed};
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::{
+    collections::BTreeSet,
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub const TEMPLATES_OVERRIDE_DIR_NAME: &str = "templates_override";
+
+///Templates from the templates_override directory in the project root.
+///Each file shadows the codegen template with the same output path in the generated
+///directory. Files with the .hbs extension are rendered with the same context as the
+///embedded dynamic templates and written without the extension, the rest are copied as is.
+///Files that don't shadow anything are simply added to the generated directory.
+pub struct TemplatesOverride {
+    dir: PathBuf,
+    //Paths relative to the templates_override dir
+    files: Vec<PathBuf>,
+}
+
+impl TemplatesOverride {
+    ///Returns None when the project doesn't have a templates_override directory
+    pub fn read(project_paths: &ParsedProjectPaths) -> Result<Option<Self>> {
+        let dir = project_paths.project_root.join(TEMPLATES_OVERRIDE_DIR_NAME);
+        if !dir.is_dir() {
+            return Ok(None);
+        }
+
+        let mut files = vec![];
+        collect_files(&dir, &dir, &mut files)
+            .context(format!("Failed reading {}", dir.display()))?;
+        files.sort();
+
+        Ok(Some(Self { dir, files }))
+    }
+
+    ///The override files relative to the templates_override dir
+    pub fn get_files(&self) -> &[PathBuf] {
+        &self.files
+    }
+
+    ///The absolute paths of the override files
+    pub fn get_file_paths(&self) -> Vec<PathBuf> {
+        self.files.iter().map(|file| self.dir.join(file)).collect()
+    }
+
+    fn is_hbs_file(file: &Path) -> bool {
+        file.extension().is_some_and(|ext| ext == "hbs")
+    }
+
+    ///Gets the path of the file in the generated directory.
+    ///src/MyTemplate.res.hbs -> src/MyTemplate.res
+    fn get_output_path(file: &Path) -> PathBuf {
+        if Self::is_hbs_file(file) {
+            file.with_extension("")
+        } else {
+            file.to_path_buf()
+        }
+    }
+
+    ///The paths in the generated directory that the embedded templates shouldn't write to
+    pub fn get_shadowed_files(&self, generated_dir: &Path) -> BTreeSet<PathBuf> {
+        self.files
+            .iter()
+            .map(|file| generated_dir.join(Self::get_output_path(file)))
+            .collect()
+    }
+
+    ///Renders and copies the override files into the generated directory.
+    ///Returns the paths of the written files
+    pub fn generate<T: Serialize>(
+        &self,
+        template: &T,
+        generated_dir: &Path,
+    ) -> Result<Vec<PathBuf>> {
+        let handlebars = new_handlebars();
+
+        self.files
+            .iter()
+            .map(|file| {
+                let source_path = self.dir.join(file);
+                let contents = if Self::is_hbs_file(file) {
+                    let template_str = fs::read_to_string(&source_path)
+                        .context(format!("Failed reading {}", source_path.display()))?;
+                    handlebars
+                        .render_template(&template_str, template)
+                        .context(format!(
+                            "Could not render file at {}",
+                            source_path.display()
+                        ))?
+                        .into_bytes()
+                } else {
+                    fs::read(&source_path)
+                        .context(format!("Failed reading {}", source_path.display()))?
+                };
+
+                let output_path = generated_dir.join(Self::get_output_path(file));
+                if let Some(parent) = output_path.parent() {
+                    fs::create_dir_all(parent)
+                        .context(format!("create_dir_all failed at {}", parent.display()))?;
+                }
+                write_file_if_changed(&output_path, contents)?;
+
+                Ok(output_path)
+            })
+            .collect()
+    }
+}
+
+fn collect_files(dir: &Path, root_dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_files(&path, root_dir, files)?;
+        } else {
+            files.push(path.strip_prefix(root_dir)?.to_path_buf());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::TemplatesOverride;
+    use crate::project_paths::ParsedProjectPaths;
+    use serde::Serialize;
+    use std::{collections::BTreeSet, fs};
+    use tempdir::TempDir;
+
+    #[derive(Serialize)]
+    struct TestTemplate {
+        project_name: String,
+    }
+
+    #[test]
+    fn renders_and_copies_override_files() {
+        let project_dir = TempDir::new("templates_override").unwrap();
+        let project_root = project_dir.path().to_str().unwrap();
+        let override_dir = project_dir.path().join("templates_override");
+        fs::create_dir_all(override_dir.join("src")).unwrap();
+        fs::write(override_dir.join("docker-compose.yaml"), "services: {}").unwrap();
+        fs::write(
+            override_dir.join("src/Custom.res.hbs"),
+            "let name = \"{{project_name}}\"",
+        )
+        .unwrap();
+
+        let project_paths =
+            ParsedProjectPaths::new(project_root, "generated", "config.yaml").unwrap();
+        let templates_override = TemplatesOverride::read(&project_paths)
+            .unwrap()
+            .expect("Should find the templates_override dir");
+
+        let generated = &project_paths.generated;
+        assert_eq!(
+            templates_override.get_shadowed_files(generated),
+            BTreeSet::from([
+                generated.join("docker-compose.yaml"),
+                generated.join("src/Custom.res"),
+            ])
+        );
+
+        let template = TestTemplate {
+            project_name: "my-indexer".to_string(),
+        };
+        let written_files = templates_override.generate(&template, generated).unwrap();
+
+        assert_eq!(
+            written_files,
+            vec![
+                generated.join("docker-compose.yaml"),
+                generated.join("src/Custom.res"),
+            ]
+        );
+        assert_eq!(
+            fs::read_to_string(generated.join("src/Custom.res")).unwrap(),
+            "let name = \"my-indexer\""
+        );
+    }
+
+    #[test]
+    fn returns_none_without_override_dir() {
+        let project_dir = TempDir::new("no_templates_override").unwrap();
+        let project_paths = ParsedProjectPaths::new(
+            project_dir.path().to_str().unwrap(),
+            "generated",
+            "config.yaml",
+        )
+        .unwrap();
+
+        assert!(TemplatesOverride::read(&project_paths).unwrap().is_none());
+    }
+}
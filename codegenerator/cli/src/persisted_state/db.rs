@@ -1,36 +1,35 @@
 use super::{PersistedState, PersistedStateExists};
-use crate::config_parsing::system_config::EnvState;
+use crate::config_parsing::local_env_config::LocalEnvConfig;
 use sqlx::postgres::{PgPool, PgPoolOptions, PgQueryResult};
-use std::env;
 
-fn get_env_with_default(var: &str, default: &str) -> String {
-    env::var(var).unwrap_or_else(|_| default.to_string())
+async fn get_pg_pool(local_env_config: &LocalEnvConfig) -> Result<PgPool, sqlx::Error> {
+    PgPoolOptions::new()
+        .connect(&local_env_config.get_pg_connection_url())
+        .await
 }
 
-async fn get_pg_pool() -> Result<PgPool, sqlx::Error> {
-    let host = get_env_with_default("ENVIO_PG_HOST", "localhost");
-    let port = get_env_with_default("ENVIO_PG_PORT", "5433");
-    let user = get_env_with_default("ENVIO_PG_USER", "postgres");
-    let password = get_env_with_default("ENVIO_POSTGRES_PASSWORD", "testing");
-    let database = get_env_with_default("ENVIO_PG_DATABASE", "envio-dev");
-
-    let connection_url = format!("postgres://{user}:{password}@{host}:{port}/{database}");
-
-    PgPoolOptions::new().connect(&connection_url).await
+///Connects to the database the indexer uses and runs a trivial query
+pub async fn check_pg_connection(local_env_config: &LocalEnvConfig) -> Result<(), sqlx::Error> {
+    let pool = get_pg_pool(local_env_config).await?;
+    sqlx::query("SELECT 1").execute(&pool).await?;
+    Ok(())
 }
 
 impl PersistedState {
-    pub async fn upsert_to_db(&self) -> Result<PgQueryResult, sqlx::Error> {
-        let pool = get_pg_pool().await?;
-        self.upsert_to_db_with_pool(&pool).await
+    pub async fn upsert_to_db(
+        &self,
+        local_env_config: &LocalEnvConfig,
+    ) -> Result<PgQueryResult, sqlx::Error> {
+        let pool = get_pg_pool(local_env_config).await?;
+        self.upsert_to_db_with_pool(&pool, &local_env_config.pg_public_schema)
+            .await
     }
 
-    async fn upsert_to_db_with_pool(&self, pool: &PgPool) -> Result<PgQueryResult, sqlx::Error> {
-        let mut env_state = EnvState::new(&std::env::current_dir().unwrap_or_default());
-        let public_schema = env_state
-            .var("ENVIO_PG_PUBLIC_SCHEMA")
-            .unwrap_or_else(|| "public".to_string());
-
+    async fn upsert_to_db_with_pool(
+        &self,
+        pool: &PgPool,
+        public_schema: &str,
+    ) -> Result<PgQueryResult, sqlx::Error> {
         sqlx::query(&format!(
             r#"
             INSERT INTO "{}".persisted_state (
@@ -39,14 +38,16 @@ impl PersistedState {
                 config_hash,
                 schema_hash,
                 handler_files_hash,
-                abi_files_hash
+                abi_files_hash,
+                templates_override_hash
             ) VALUES (
                 $1, 
                 $2, 
                 $3, 
                 $4, 
                 $5, 
-                $6
+                $6,
+                $7
             )
             ON CONFLICT (id) DO UPDATE
             SET 
@@ -54,7 +55,8 @@ impl PersistedState {
                 config_hash = EXCLUDED.config_hash,
                 schema_hash = EXCLUDED.schema_hash,
                 handler_files_hash = EXCLUDED.handler_files_hash,
-                abi_files_hash = EXCLUDED.abi_files_hash
+                abi_files_hash = EXCLUDED.abi_files_hash,
+                templates_override_hash = EXCLUDED.templates_override_hash
             "#,
             public_schema
         ))
@@ -64,31 +66,31 @@ impl PersistedState {
         .bind(&self.schema_hash)
         .bind(&self.handler_files_hash)
         .bind(&self.abi_files_hash)
+        .bind(&self.templates_override_hash)
         .execute(pool)
         .await
     }
 }
 
 impl PersistedStateExists {
-    pub async fn read_from_db() -> Result<PersistedStateExists, sqlx::Error> {
-        let pool = get_pg_pool().await?;
-        Self::read_from_db_with_pool(&pool).await
+    pub async fn read_from_db(
+        local_env_config: &LocalEnvConfig,
+    ) -> Result<PersistedStateExists, sqlx::Error> {
+        let pool = get_pg_pool(local_env_config).await?;
+        Self::read_from_db_with_pool(&pool, &local_env_config.pg_public_schema).await
     }
     pub async fn read_from_db_with_pool(
         pool: &PgPool,
+        public_schema: &str,
     ) -> Result<PersistedStateExists, sqlx::Error> {
-        let mut env_state = EnvState::new(&std::env::current_dir().unwrap_or_default());
-        let public_schema = env_state
-            .var("ENVIO_PG_PUBLIC_SCHEMA")
-            .unwrap_or_else(|| "public".to_string());
-
         let val = sqlx::query_as::<_, PersistedState>(&format!(
             "SELECT 
             envio_version,
             config_hash,
             schema_hash,
             handler_files_hash,
-            abi_files_hash
+            abi_files_hash,
+            templates_override_hash
             FROM \"{}\".persisted_state WHERE id = 1",
             public_schema
         ))
@@ -115,7 +117,10 @@ impl PersistedStateExists {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{config_parsing::system_config::SystemConfig, project_paths::ParsedProjectPaths};
+    use crate::{
+        cli_args::clap_definitions::LocalEnvArgs, config_parsing::system_config::SystemConfig,
+        project_paths::ParsedProjectPaths,
+    };
     use anyhow::{Context, Result};
 
     #[tokio::test]
@@ -135,8 +140,12 @@ mod test {
         let persisted_state =
             PersistedState::get_current_state(&system_cfg).context("persisted_state")?;
 
+        let local_env_config = LocalEnvConfig::resolve(
+            &system_cfg.parsed_project_paths.project_root,
+            &LocalEnvArgs::default(),
+        )?;
         persisted_state
-            .upsert_to_db()
+            .upsert_to_db(&local_env_config)
             .await
             .context("write_to_db")?;
 
@@ -149,7 +158,9 @@ mod test {
         println!(
             "This test only works if the db migrations have been run and the db is up and running"
         );
-        let val = PersistedStateExists::read_from_db()
+        let local_env_config =
+            LocalEnvConfig::resolve(&std::env::current_dir()?, &LocalEnvArgs::default())?;
+        let val = PersistedStateExists::read_from_db(&local_env_config)
             .await
             .context("read from db")?;
 
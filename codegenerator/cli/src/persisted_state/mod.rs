@@ -1,7 +1,12 @@
 mod db;
 mod hash_string;
 
-use crate::{config_parsing::system_config::SystemConfig, project_paths::ParsedProjectPaths};
+pub use db::check_pg_connection;
+
+use crate::{
+    config_parsing::system_config::SystemConfig,
+    hbs_templating::templates_override::TemplatesOverride, project_paths::ParsedProjectPaths,
+};
 use anyhow::Context;
 use hash_string::HashString;
 use serde::{Deserialize, Serialize};
@@ -20,6 +25,7 @@ pub struct PersistedState {
     pub schema_hash: HashString,
     pub handler_files_hash: HashString,
     pub abi_files_hash: HashString,
+    pub templates_override_hash: HashString,
 }
 const PERSISTED_STATE_FILE_NAME: &str = "persisted_state.envio.json";
 pub static CURRENT_CRATE_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -32,6 +38,7 @@ pub enum StateField {
     Schema,
     AbiFiles,
     HandlerFiles,
+    TemplatesOverride,
 }
 
 ///Gets the path to the persisted file in generated folder
@@ -39,6 +46,20 @@ fn get_generated_file_path(project_paths: &ParsedProjectPaths) -> PathBuf {
     project_paths.generated.join(PERSISTED_STATE_FILE_NAME)
 }
 
+///Hashes both the paths and the contents of the templates_override files,
+///since the paths determine which generated files they shadow
+fn hash_templates_override(project_paths: &ParsedProjectPaths) -> anyhow::Result<HashString> {
+    let Some(templates_override) = TemplatesOverride::read(project_paths)? else {
+        return Ok(HashString::from_string(String::new()));
+    };
+    let contents_hash = HashString::from_file_paths(templates_override.get_file_paths(), true)?;
+    Ok(HashString::from_string(format!(
+        "{:?}{}",
+        templates_override.get_files(),
+        contents_hash
+    )))
+}
+
 impl PersistedState {
     ///Compares a given field between two PersistedState structs.
     ///Useful for checking diffs between new state and persisted state file or db
@@ -49,6 +70,9 @@ impl PersistedState {
             StateField::Schema => self.schema_hash == other_state.schema_hash,
             StateField::AbiFiles => self.abi_files_hash == other_state.abi_files_hash,
             StateField::HandlerFiles => self.handler_files_hash == other_state.handler_files_hash,
+            StateField::TemplatesOverride => {
+                self.templates_override_hash == other_state.templates_override_hash
+            }
         }
     }
 
@@ -86,7 +110,7 @@ impl PersistedState {
 
         Ok(PersistedState {
             envio_version: CURRENT_CRATE_VERSION.to_string(),
-            config_hash: HashString::from_string(config.human_config.to_string()),
+            config_hash: HashString::from_string(config.human_config.to_hash_string()),
             schema_hash: HashString::from_file_path(schema_path.clone())
                 .context("Failed hashing schema file")?,
             handler_files_hash: HashString::from_file_paths(
@@ -96,6 +120,8 @@ impl PersistedState {
             .context("Failed hashing handler files")?,
             abi_files_hash: HashString::from_file_paths(all_abi_file_paths, ABI_FILES_MUST_EXIST)
                 .context("Failed hashing abi files")?,
+            templates_override_hash: hash_templates_override(&config.parsed_project_paths)
+                .context("Failed hashing templates_override files")?,
         })
     }
 
@@ -109,6 +135,8 @@ impl PersistedState {
             StateField::AbiFiles,
             //If schema has changed this will affect generated entity types
             StateField::Schema,
+            //Override files replace the embedded templates in the generated code
+            StateField::TemplatesOverride,
             //If the version envio changes, this could infer differences that need to be re-code
             //generated
             StateField::EnvioVersion,
@@ -179,7 +207,7 @@ impl From<PersistedState> for PersistedStateJsonString {
 
 #[cfg(test)]
 mod test {
-    use super::PersistedState;
+    use super::{PersistedState, StateField};
     use serde_json::json;
 
     #[test]
@@ -190,6 +218,7 @@ mod test {
             "schema_hash": "<HASH_STRING>",
             "handler_files_hash": "<HASH_STRING>",
             "abi_files_hash": "<HASH_STRING>",
+            "templates_override_hash": "<HASH_STRING>",
         }))
         .unwrap();
 
@@ -199,6 +228,7 @@ mod test {
             "schema_hash": "<HASH_STRING>",
             "handler_files_hash": "<HASH_STRING>",
             "abi_files_hash": "<HASH_STRING>",
+            "templates_override_hash": "<HASH_STRING>",
         }))
         .unwrap();
 
@@ -211,6 +241,38 @@ mod test {
         );
     }
 
+    #[test]
+    fn should_run_codegen_on_templates_override_change() {
+        let persisted_file: PersistedState = serde_json::from_value(json!({
+            "envio_version": "0.0.1",
+            "config_hash": "<HASH_STRING>",
+            "schema_hash": "<HASH_STRING>",
+            "handler_files_hash": "<HASH_STRING>",
+            "abi_files_hash": "<HASH_STRING>",
+            "templates_override_hash": "<HASH_STRING>",
+        }))
+        .unwrap();
+
+        let current_state: PersistedState = serde_json::from_value(json!({
+            "envio_version": "0.0.1",
+            "config_hash": "<HASH_STRING>",
+            "schema_hash": "<HASH_STRING>",
+            "handler_files_hash": "<HASH_STRING>",
+            "abi_files_hash": "<HASH_STRING>",
+            "templates_override_hash": "<CHANGED_HASH_STRING>",
+        }))
+        .unwrap();
+
+        let (should_run_codegen, changed_fields) =
+            current_state.should_run_codegen(&persisted_file);
+
+        assert!(
+            should_run_codegen,
+            "should run codegen should be true since templates_override changed"
+        );
+        assert_eq!(changed_fields, vec![StateField::TemplatesOverride]);
+    }
+
     #[test]
     fn should_not_run_codegen() {
         let persisted_file: PersistedState = serde_json::from_value(json!({
@@ -219,6 +281,7 @@ mod test {
             "schema_hash": "<HASH_STRING>",
             "handler_files_hash": "<HASH_STRING>",
             "abi_files_hash": "<HASH_STRING>",
+            "templates_override_hash": "<HASH_STRING>",
         }))
         .unwrap();
 
@@ -228,6 +291,7 @@ mod test {
             "schema_hash": "<HASH_STRING>",
             "handler_files_hash": "<CHANGED_HASH_STRING>",
             "abi_files_hash": "<HASH_STRING>",
+            "templates_override_hash": "<HASH_STRING>",
         }))
         .unwrap();
 
@@ -248,6 +312,7 @@ mod test {
             "schema_hash": "<HASH_STRING>",
             "handler_files_hash": "<HASH_STRING>",
             "abi_files_hash": "<HASH_STRING>",
+            "templates_override_hash": "<HASH_STRING>",
         }))
         .unwrap();
 
@@ -257,6 +322,7 @@ mod test {
             "schema_hash": "<CHANGED_HASH_STRING>",
             "handler_files_hash": "<CHANGED_HASH_STRING>",
             "abi_files_hash": "<HASH_STRING>",
+            "templates_override_hash": "<HASH_STRING>",
         }))
         .unwrap();
 
@@ -277,6 +343,7 @@ mod test {
             "schema_hash": "<HASH_STRING>",
             "handler_files_hash": "<HASH_STRING>",
             "abi_files_hash": "<HASH_STRING>",
+            "templates_override_hash": "<HASH_STRING>",
         }))
         .unwrap();
 
@@ -286,6 +353,7 @@ mod test {
             "schema_hash": "<HASH_STRING>",
             "handler_files_hash": "<HASH_STRING>",
             "abi_files_hash": "<HASH_STRING>",
+            "templates_override_hash": "<HASH_STRING>",
         }))
         .unwrap();
 
@@ -25,7 +25,9 @@ impl RescriptTypeDeclMulti {
         Self(type_declarations)
     }
 
-    pub fn to_rescript_schema(&self, mode: &RescriptSchemaMode) -> String {
+    /// Returns the type declarations ordered so that every declaration
+    /// comes after the declarations it depends on
+    pub fn get_sorted_by_dependencies(&self) -> Vec<RescriptTypeDecl> {
         let mut sorted: Vec<RescriptTypeDecl> = vec![];
         let mut registered: HashSet<String> = HashSet::new();
 
@@ -53,6 +55,10 @@ impl RescriptTypeDeclMulti {
         }
 
         sorted
+    }
+
+    pub fn to_rescript_schema(&self, mode: &RescriptSchemaMode) -> String {
+        self.get_sorted_by_dependencies()
             .iter()
             .map(|decl| {
                 format!(
@@ -65,6 +71,16 @@ impl RescriptTypeDeclMulti {
             .join("\n")
     }
 
+    /// Renders the type declarations as the equivalent TypeScript declarations,
+    /// matching the runtime representation of the ReScript values
+    pub fn to_typescript(&self) -> String {
+        self.0
+            .iter()
+            .map(|decl| decl.to_typescript())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
     fn to_string_internal(&self) -> String {
         match self.0.as_slice() {
             [single_decl] => single_decl.to_string(),
@@ -180,6 +196,25 @@ impl RescriptTypeDecl {
         }
     }
 
+    pub fn to_typescript(&self) -> String {
+        let parameters = if self.parameters.is_empty() {
+            "".to_string()
+        } else {
+            let param_names_joined = self
+                .parameters
+                .iter()
+                // Generic params are capitalized by the TypeScript convention
+                .map(|p| p.capitalize())
+                .join(", ");
+            format!("<{param_names_joined}>")
+        };
+        format!(
+            "export type {}{parameters} = {};",
+            self.name,
+            self.type_expr.to_typescript()
+        )
+    }
+
     pub fn to_usage(&self, arguments: Vec<String>) -> Result<String> {
         if self.parameters.len() != arguments.len() {
             Err(anyhow!(
@@ -237,6 +272,44 @@ impl RescriptTypeExpr {
         }
     }
 
+    pub fn to_typescript(&self) -> String {
+        match self {
+            Self::Identifier(type_ident) => type_ident.to_typescript(),
+            Self::Record(fields) if fields.is_empty() => "{}".to_string(),
+            Self::Record(fields) => {
+                let fields_str = fields
+                    .iter()
+                    .map(|field| {
+                        //The original name is used as the field name in runtime
+                        let name = field.as_name.as_ref().unwrap_or(&field.name);
+                        let is_identifier = !name.is_empty()
+                            && !name.starts_with(|c: char| c.is_ascii_digit())
+                            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+                        let key = if is_identifier {
+                            name.clone()
+                        } else {
+                            format!("\"{name}\"")
+                        };
+                        format!("readonly {key}: {}", field.type_ident.to_typescript())
+                    })
+                    .join("; ");
+                format!("{{ {fields_str} }}")
+            }
+            Self::Variant(constructors) if constructors.is_empty() => "never".to_string(),
+            //Variants are tagged with the "case" field in runtime
+            Self::Variant(constructors) => constructors
+                .iter()
+                .map(|constr| {
+                    format!(
+                        "{{ readonly case: \"{}\"; readonly payload: {} }}",
+                        constr.name,
+                        constr.payload.to_typescript()
+                    )
+                })
+                .join(" | "),
+        }
+    }
+
     pub fn to_rescript_schema(&self, type_name: &String, mode: &RescriptSchemaMode) -> String {
         match self {
             Self::Identifier(type_ident) => type_ident.to_rescript_schema(mode),
@@ -372,6 +445,8 @@ pub enum RescriptTypeIdent {
     BigInt,
     BigDecimal,
     Address,
+    //Address stored on an entity, normalized to its checksummed form
+    ChecksumAddress,
     String,
     Json,
     Bool,
@@ -412,7 +487,7 @@ impl RescriptTypeIdent {
             Self::BigInt => "bigint".to_string(),
             Self::Unknown => "unknown".to_string(),
             Self::BigDecimal => "BigDecimal.t".to_string(),
-            Self::Address => "Address.t".to_string(),
+            Self::Address | Self::ChecksumAddress => "Address.t".to_string(),
             Self::String => "string".to_string(),
             Self::Json => "Js.Json.t".to_string(),
             Self::ID => "id".to_string(),
@@ -454,6 +529,42 @@ impl RescriptTypeIdent {
         }
     }
 
+    /// Renders the type as it's represented in runtime. The rendered code
+    /// expects Address, BigDecimal and Enums to be imported
+    pub fn to_typescript(&self) -> String {
+        match self {
+            Self::Unit => "undefined".to_string(),
+            Self::Int | Self::Float => "number".to_string(),
+            Self::BigInt => "bigint".to_string(),
+            Self::BigDecimal => "BigDecimal".to_string(),
+            Self::Address | Self::ChecksumAddress => "Address".to_string(),
+            Self::String | Self::ID => "string".to_string(),
+            Self::Json | Self::Unknown => "unknown".to_string(),
+            Self::Bool => "boolean".to_string(),
+            Self::Timestamp => "Date".to_string(),
+            Self::SchemaEnum(enum_name) => format!("Enums.{}_t", &enum_name.capitalized),
+            Self::Array(inner_type) => match inner_type.as_ref() {
+                //Unions need to be wrapped in parentheses to be used as array items
+                Self::Option(_) => format!("({})[]", inner_type.to_typescript()),
+                _ => format!("{}[]", inner_type.to_typescript()),
+            },
+            Self::Option(inner_type) => format!("{} | undefined", inner_type.to_typescript()),
+            Self::Tuple(inner_types) => {
+                let inner_types_str = inner_types
+                    .iter()
+                    .map(|inner_type| inner_type.to_typescript())
+                    .join(", ");
+                format!("[{inner_types_str}]")
+            }
+            Self::GenericParam(name) => name.capitalize(),
+            Self::TypeApplication { name, type_params } if type_params.is_empty() => name.clone(),
+            Self::TypeApplication { name, type_params } => {
+                let params_joined = type_params.iter().map(|p| p.to_typescript()).join(", ");
+                format!("{name}<{params_joined}>")
+            }
+        }
+    }
+
     pub fn to_rescript_schema(&self, mode: &RescriptSchemaMode) -> String {
         match self {
             Self::Unit => "S.literal(%raw(`null`))->S.shape(_ => ())".to_string(),
@@ -466,6 +577,7 @@ impl RescriptTypeIdent {
             },
             Self::BigDecimal => "BigDecimal.schema".to_string(),
             Self::Address => "Address.schema".to_string(),
+            Self::ChecksumAddress => "Address.Evm.checksumSchema".to_string(),
             Self::String => "S.string".to_string(),
             Self::Json => "S.json(~validate=false)".to_string(),
             Self::ID => "S.string".to_string(),
@@ -521,6 +633,7 @@ impl RescriptTypeIdent {
             | Self::BigInt
             | Self::BigDecimal
             | Self::Address
+            | Self::ChecksumAddress
             | Self::String
             | Self::Unknown
             | Self::ID
@@ -555,7 +668,9 @@ impl RescriptTypeIdent {
             Self::BigInt => "0n".to_string(),
             Self::Json => "%raw(`{}`)".to_string(),
             Self::BigDecimal => "BigDecimal.zero".to_string(),
-            Self::Address => "TestHelpers_MockAddresses.defaultAddress".to_string(),
+            Self::Address | Self::ChecksumAddress => {
+                "TestHelpers_MockAddresses.defaultAddress".to_string()
+            }
             Self::String => "\"foo\"".to_string(),
             Self::ID => "\"my_id\"".to_string(),
             Self::Bool => "false".to_string(),
@@ -622,7 +737,7 @@ impl RescriptTypeIdent {
             Self::BigDecimal => "// default value not required since BigDecimal doesn't exist on \
                                  contracts for contract import"
                 .to_string(),
-            Self::Address => "Addresses.defaultAddress".to_string(),
+            Self::Address | Self::ChecksumAddress => "Addresses.defaultAddress".to_string(),
             Self::String => "\"foo\"".to_string(),
             Self::ID => "\"my_id\"".to_string(),
             Self::Bool => "false".to_string(),
@@ -1074,4 +1189,55 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn renders_type_decl_multi_to_typescript() {
+        let type_decl_multi = RescriptTypeDeclMulti::new(vec![
+            RescriptTypeDecl::new(
+                "myVariant".to_string(),
+                RescriptTypeExpr::Variant(vec![
+                    RescriptVariantConstr::new("None".to_string(), RescriptTypeIdent::Unit),
+                    RescriptVariantConstr::new(
+                        "Some".to_string(),
+                        RescriptTypeIdent::GenericParam("t".to_string()),
+                    ),
+                ]),
+                vec!["t".to_string()],
+            ),
+            RescriptTypeDecl::new(
+                "myRecord".to_string(),
+                RescriptTypeExpr::Record(vec![
+                    RescriptRecordField::new(
+                        "Amount".to_string(),
+                        RescriptTypeIdent::option(RescriptTypeIdent::BigInt),
+                    ),
+                    RescriptRecordField::new(
+                        "pair".to_string(),
+                        RescriptTypeIdent::Tuple(vec![
+                            RescriptTypeIdent::Int,
+                            RescriptTypeIdent::array(RescriptTypeIdent::option(
+                                RescriptTypeIdent::String,
+                            )),
+                        ]),
+                    ),
+                    RescriptRecordField::new(
+                        "maybe".to_string(),
+                        RescriptTypeIdent::TypeApplication {
+                            name: "myVariant".to_string(),
+                            type_params: vec![RescriptTypeIdent::Address],
+                        },
+                    ),
+                ]),
+                vec![],
+            ),
+        ]);
+
+        assert_eq!(
+            type_decl_multi.to_typescript(),
+            "export type myVariant<T> = { readonly case: \"None\"; readonly payload: undefined } \
+             | { readonly case: \"Some\"; readonly payload: T };\nexport type myRecord = { \
+             readonly Amount: bigint | undefined; readonly pair: [number, (string | \
+             undefined)[]]; readonly maybe: myVariant<Address> };"
+        );
+    }
 }
@@ -1,12 +1,8 @@
-use crate::config_parsing::chain_helpers::{
-    ChainTier, GraphNetwork, HypersyncNetwork, NetworkWithExplorer,
-};
+use crate::config_parsing::{chain_helpers::ChainTier, chain_registry::ChainRegistry};
 use anyhow::Result;
-use convert_case::{Case, Casing};
 use reqwest;
 use serde::Deserialize;
 use std::collections::HashSet;
-use strum::IntoEnumIterator;
 
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "lowercase")]
@@ -35,6 +31,7 @@ impl Diff {
         let url = "https://chains.hyperquery.xyz/active_chains";
         let response = reqwest::get(url).await?;
         let chains: Vec<Chain> = response.json().await?;
+        let chain_registry = ChainRegistry::bundled()?;
 
         let mut api_chain_ids = HashSet::new();
 
@@ -72,22 +69,14 @@ impl Diff {
 
             api_chain_ids.insert(chain_id);
 
-            let Some(hypersync_network) = HypersyncNetwork::from_repr(chain_id) else {
-                let subenums = vec![
-                    Some("HypersyncNetwork"),
-                    NetworkWithExplorer::from_repr(chain_id).map(|_| "NetworkWithExplorer"),
-                    GraphNetwork::from_repr(chain_id).map(|_| "GraphNetwork"),
-                ]
-                .into_iter()
-                .flatten()
-                .collect::<Vec<_>>()
-                .join(", ");
-
+            let Some(registry_chain) = chain_registry
+                .get_by_id(chain_id)
+                .filter(|c| c.hypersync_url.is_some())
+            else {
                 missing_chains.push(format!(
-                    "    #[subenum({})]\n    {} = {},",
-                    subenums,
-                    chain.name.to_case(Case::Pascal),
-                    chain_id
+                    "  {{\n    \"id\": {chain_id},\n    \"name\": \"{}\",\n    \"hypersync_url\": \
+                     \"https://{chain_id}.hypersync.xyz\"\n  }}",
+                    chain.name
                 ));
 
                 continue;
@@ -98,21 +87,21 @@ impl Diff {
                 continue;
             };
 
-            if tier != hypersync_network.get_tier() {
-                let network_name = hypersync_network.get_plain_name();
-                let current_tier = hypersync_network.get_tier();
+            if Some(tier) != registry_chain.tier {
+                let network_name = &registry_chain.name;
+                let current_tier = registry_chain
+                    .tier
+                    .map_or("NONE".to_string(), |t| t.to_string());
                 incorrect_tiers.push(format!("{network_name}: {current_tier} -> {tier}",));
             }
         }
 
         let mut extra_chains = Vec::new();
-        for network in HypersyncNetwork::iter() {
-            let network_id = network as u64;
-            if !api_chain_ids.contains(&network_id) {
+        for registry_chain in chain_registry.iter_hypersync_chains() {
+            if !api_chain_ids.contains(&registry_chain.id) {
                 extra_chains.push(format!(
                     "{:?} (ID: {})",
-                    network.get_plain_name(),
-                    network_id
+                    registry_chain.name, registry_chain.id
                 ));
             }
         }
@@ -147,12 +136,15 @@ impl Diff {
         } = self;
         if self.is_empty() {
             println!(
-                "All chains from the API are present in the HypersyncNetwork enum, and vice \
-                 versa. Nothing to update."
+                "All chains from the API are present in chains.json with a hypersync_url, and \
+                 vice versa. Nothing to update."
             );
         } else {
             if !missing_chains.is_empty() {
-                println!("\nThe following chains are missing from the Network enum:");
+                println!(
+                    "\nThe following chains are missing from chains.json (or don't have a \
+                     hypersync_url):"
+                );
                 for chain in missing_chains {
                     println!("{}", chain);
                 }
@@ -160,8 +152,8 @@ impl Diff {
 
             if !extra_chains.is_empty() {
                 println!(
-                    "\nThe following chains are in the HypersyncNetwork enum but not in the API \
-                     (remove the HypersyncNetwork subEnum from the chain_helpers.rs file):"
+                    "\nThe following chains have a hypersync_url in chains.json but are not in the \
+                     API (remove their hypersync_url and tier):"
                 );
                 for chain in extra_chains {
                     println!("- {}", chain);
@@ -1,14 +1,15 @@
+use crate::{config_parsing::local_env_config::LocalEnvConfig, persisted_state};
+use std::{fmt, future::Future};
 use tokio::time::{timeout, Duration};
 
-// NOTE: This assumes the hasura graphql availability means the postgres database is also available
 const MAXIMUM_BACKOFF: Duration = Duration::from_secs(120); // Likely the user will kill this before it gets here, but wanted to make it quite large to allow for users with slow computers
 const BACKOFF_INCREMENT: Duration = Duration::from_secs(1);
-const HASURA_ENDPOINT: &str = "http://localhost:8080/"; // todo: is this available somewhere
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
 
 // Function to fetch the health of the Hasura service
-pub async fn fetch_hasura_healthz() -> Result<bool, reqwest::Error> {
+pub async fn fetch_hasura_healthz(hasura_url: &str) -> Result<bool, reqwest::Error> {
     let client = reqwest::Client::new();
-    let url = format!("{}/hasura/healthz?strict=true", HASURA_ENDPOINT);
+    let url = format!("{}/hasura/healthz?strict=true", hasura_url);
     let response = client.get(&url).send().await?;
     let is_success = response.status().is_success();
     Ok(is_success)
@@ -19,33 +20,113 @@ fn delete_last_println() {
     print!("{ERASE_ANSI_ESCAPE_CODE}");
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum EndpointHealth {
     Healthy,
     Unhealthy(String),
 }
 
-pub async fn fetch_hasura_healthz_with_retry() -> EndpointHealth {
+impl fmt::Display for EndpointHealth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EndpointHealth::Healthy => write!(f, "healthy"),
+            EndpointHealth::Unhealthy(reason) => write!(f, "unhealthy ({reason})"),
+        }
+    }
+}
+
+async fn with_timeout(health: impl Future<Output = EndpointHealth>) -> EndpointHealth {
+    timeout(REQUEST_TIMEOUT, health)
+        .await
+        .unwrap_or_else(|_| EndpointHealth::Unhealthy("Request timed out".to_string()))
+}
+
+///Runs a SELECT 1 query, since Postgres accepts connections before it's ready for queries
+pub async fn fetch_postgres_health(local_env_config: &LocalEnvConfig) -> EndpointHealth {
+    with_timeout(async {
+        match persisted_state::check_pg_connection(local_env_config).await {
+            Ok(()) => EndpointHealth::Healthy,
+            Err(err) => EndpointHealth::Unhealthy(err.to_string()),
+        }
+    })
+    .await
+}
+
+pub async fn fetch_hasura_health(hasura_url: &str) -> EndpointHealth {
+    with_timeout(async {
+        match fetch_hasura_healthz(hasura_url).await {
+            Ok(true) => EndpointHealth::Healthy,
+            Ok(false) => EndpointHealth::Unhealthy("Hasura isn't ready yet".to_string()),
+            Err(err) => EndpointHealth::Unhealthy(err.to_string()),
+        }
+    })
+    .await
+}
+
+///Any successful response of the url means the endpoint is healthy
+pub async fn fetch_custom_endpoint_health(url: &str) -> EndpointHealth {
+    with_timeout(async {
+        match reqwest::get(url).await {
+            Ok(response) if response.status().is_success() => EndpointHealth::Healthy,
+            Ok(response) => {
+                EndpointHealth::Unhealthy(format!("Responded with status {}", response.status()))
+            }
+            Err(err) => EndpointHealth::Unhealthy(err.to_string()),
+        }
+    })
+    .await
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceHealth {
+    pub name: String,
+    pub health: EndpointHealth,
+}
+
+impl fmt::Display for ServiceHealth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.name, self.health)
+    }
+}
+
+///Checks every service of the local environment independently: Postgres,
+///Hasura when it's used and the custom endpoints from ENVIO_HEALTH_CHECK_URLS
+pub async fn fetch_services_health(local_env_config: &LocalEnvConfig) -> Vec<ServiceHealth> {
+    let mut services = vec![ServiceHealth {
+        name: "Postgres".to_string(),
+        health: fetch_postgres_health(local_env_config).await,
+    }];
+    if local_env_config.hasura {
+        services.push(ServiceHealth {
+            name: "Hasura".to_string(),
+            health: fetch_hasura_health(&local_env_config.hasura_url).await,
+        });
+    }
+    for url in &local_env_config.health_check_urls {
+        services.push(ServiceHealth {
+            name: url.clone(),
+            health: fetch_custom_endpoint_health(url).await,
+        });
+    }
+    services
+}
+
+async fn wait_until_healthy<Fut: Future<Output = EndpointHealth>>(
+    service_name: &str,
+    fetch_health: impl Fn() -> Fut,
+) -> EndpointHealth {
     let mut refetch_delay = BACKOFF_INCREMENT;
 
     let mut first_run = true;
 
     loop {
-        match timeout(refetch_delay, fetch_hasura_healthz()).await {
-            Ok(Ok(success)) => {
-                if success {
-                    break EndpointHealth::Healthy;
-                } else if refetch_delay >= MAXIMUM_BACKOFF {
-                    return EndpointHealth::Unhealthy(
-                        "Maximum backoff timeout exceeded".to_string(),
-                    );
-                }
-            }
-            Ok(Err(err)) => {
+        match fetch_health().await {
+            EndpointHealth::Healthy => break EndpointHealth::Healthy,
+            EndpointHealth::Unhealthy(reason) => {
                 if refetch_delay >= MAXIMUM_BACKOFF {
                     return EndpointHealth::Unhealthy(format!(
                         "Maximum backoff timeout exceeded: Error: {}",
-                        err
+                        reason
                     ));
                 }
                 if !first_run {
@@ -54,19 +135,8 @@ pub async fn fetch_hasura_healthz_with_retry() -> EndpointHealth {
                     first_run = false;
                 }
                 println!(
-                    "Waiting for the docker services to become available. {} seconds.",
-                    refetch_delay.as_secs()
-                );
-            }
-            Err(err) => {
-                if refetch_delay >= MAXIMUM_BACKOFF {
-                    return EndpointHealth::Unhealthy(format!(
-                        "Maximum backoff timeout exceeded: Error: {}",
-                        err
-                    ));
-                }
-                println!(
-                    "Fetching the services health timed out. Retrying in {} seconds...",
+                    "Waiting for {} to become available. {} seconds.",
+                    service_name,
                     refetch_delay.as_secs()
                 );
             }
@@ -75,3 +145,50 @@ pub async fn fetch_hasura_healthz_with_retry() -> EndpointHealth {
         refetch_delay += BACKOFF_INCREMENT;
     }
 }
+
+pub async fn wait_for_postgres(local_env_config: &LocalEnvConfig) -> EndpointHealth {
+    wait_until_healthy("Postgres", || fetch_postgres_health(local_env_config)).await
+}
+
+pub async fn wait_for_hasura(hasura_url: &str) -> EndpointHealth {
+    wait_until_healthy("Hasura", || fetch_hasura_health(hasura_url)).await
+}
+
+#[cfg(test)]
+mod test {
+    use super::{wait_until_healthy, EndpointHealth, ServiceHealth};
+
+    #[tokio::test(start_paused = true)]
+    async fn waits_until_the_service_is_healthy() {
+        let attempts = std::cell::Cell::new(0);
+        let health = wait_until_healthy("Test service", || {
+            attempts.set(attempts.get() + 1);
+            let attempt = attempts.get();
+            async move {
+                match attempt {
+                    1 => EndpointHealth::Unhealthy("Starting".to_string()),
+                    _ => EndpointHealth::Healthy,
+                }
+            }
+        })
+        .await;
+        assert_eq!(health, EndpointHealth::Healthy);
+        assert_eq!(attempts.get(), 2);
+    }
+
+    #[test]
+    fn displays_service_health() {
+        let service = |health| ServiceHealth {
+            name: "Postgres".to_string(),
+            health,
+        };
+        assert_eq!(
+            service(EndpointHealth::Healthy).to_string(),
+            "Postgres: healthy"
+        );
+        assert_eq!(
+            service(EndpointHealth::Unhealthy("Connection refused".to_string())).to_string(),
+            "Postgres: unhealthy (Connection refused)"
+        );
+    }
+}
@@ -1,8 +1,12 @@
-use crate::cli_args::init_config::{evm, fuel, Language};
+use crate::{
+    cli_args::init_config::{evm, fuel, Language},
+    utils::file_system::write_file_if_changed,
+};
 use anyhow::{anyhow, Context, Result};
 use include_dir::{include_dir, Dir, DirEntry};
 use pathdiff::diff_paths;
 use std::{
+    collections::BTreeSet,
     fmt::Display,
     fs,
     path::{Path, PathBuf},
@@ -106,13 +110,24 @@ impl<'a> RelativeDir<'a> {
 
     /// Create directories and extract all files to real filesystem.
     /// Creates parent directories of `path` if they do not already exist.
-    /// Fails if some files already exist.
+    /// Files that already exist with the same contents are left untouched.
     /// In case of error, partially extracted directory may remain on the filesystem.
     /// If RelativedDir is at path static/codegen/src with parsent static/codegen it will
     /// extract to {base_path}/src not {base_path}/static/codegen/src like the regular
     /// Dir.extract() method
-    pub fn extract<S: AsRef<Path>>(&self, base_path: S) -> Result<()> {
+    /// Returns the paths of all the extracted files
+    pub fn extract<S: AsRef<Path>>(&self, base_path: S) -> Result<Vec<PathBuf>> {
+        self.extract_except(base_path, &BTreeSet::new())
+    }
+
+    /// Same as extract but leaves out the files with any of the given output paths
+    pub fn extract_except<S: AsRef<Path>>(
+        &self,
+        base_path: S,
+        skipped_files: &BTreeSet<PathBuf>,
+    ) -> Result<Vec<PathBuf>> {
         let base_path = base_path.as_ref();
+        let mut extracted_files = vec![];
 
         for entry in self.dir.entries() {
             let rel_entry_path = self.diff_path_from_parent(entry.path())?;
@@ -121,19 +136,25 @@ impl<'a> RelativeDir<'a> {
             match entry {
                 DirEntry::Dir(dir) => {
                     fs::create_dir_all(&path)?;
-                    self.new_child(dir).extract(base_path)?;
+                    extracted_files.extend(
+                        self.new_child(dir)
+                            .extract_except(base_path, skipped_files)?,
+                    );
                 }
                 DirEntry::File(f) => {
                     // Skip .gitkeep files
-                    if path.file_name().map_or(false, |n| n == ".gitkeep") {
+                    if path.file_name().map_or(false, |n| n == ".gitkeep")
+                        || skipped_files.contains(&path)
+                    {
                         continue;
                     }
-                    fs::write(path, f.contents())?;
+                    write_file_if_changed(&path, f.contents())?;
+                    extracted_files.push(path);
                 }
             }
         }
 
-        Ok(())
+        Ok(extracted_files)
     }
 }
 
@@ -1,5 +1,8 @@
 use anyhow::{Context, Result};
-use std::{fs, path::PathBuf};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
 
 pub async fn write_file_string_to_system(file_string: String, fs_file_path: PathBuf) -> Result<()> {
     let file_path_str = fs_file_path.to_str().unwrap_or("unknown file path");
@@ -13,3 +16,35 @@ pub async fn write_file_string_to_system(file_string: String, fs_file_path: Path
 
     Ok(())
 }
+
+///Writes the contents to the file only if they differ from what's already on disk.
+///Leaving unchanged files untouched keeps their mtimes, so incremental builds and
+///file watchers don't pick them up. Returns whether the file was written.
+pub fn write_file_if_changed<C: AsRef<[u8]>>(path: &Path, contents: C) -> Result<bool> {
+    let contents = contents.as_ref();
+    match fs::read(path) {
+        Ok(existing_contents) if existing_contents == contents => Ok(false),
+        _ => {
+            fs::write(path, contents)
+                .with_context(|| format!("Failed to write file at {}", path.display()))?;
+            Ok(true)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::write_file_if_changed;
+    use tempdir::TempDir;
+
+    #[test]
+    fn write_file_if_changed_skips_unchanged_contents() {
+        let temp_dir = TempDir::new("write_file_if_changed").unwrap();
+        let path = temp_dir.path().join("Types.res");
+
+        assert!(write_file_if_changed(&path, "type t = int").unwrap());
+        assert!(!write_file_if_changed(&path, "type t = int").unwrap());
+        assert!(write_file_if_changed(&path, "type t = string").unwrap());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "type t = string");
+    }
+}
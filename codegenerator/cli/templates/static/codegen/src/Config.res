@@ -34,6 +34,12 @@ type chainConfig = {
   startBlock: int,
   endBlock: option<int>,
   confirmedBlockThreshold: int,
+  // When set, confirmedBlockThreshold is replaced on start
+  // by the reorg depth detected from the RPC block tag
+  finality?: Source.finalityTag,
+  // The confirmed_block_threshold set together with the finality tag.
+  // The detected reorg depth doesn't go below it
+  minConfirmedBlockThreshold?: int,
   chain: ChainMap.Chain.t,
   contracts: array<contract>,
   sources: array<Source.t>,
@@ -354,6 +354,7 @@ let main = async () => {
 
     await config.persistence->Persistence.init
 
+    let config = await config->ChainFinality.resolveConfig
     let chainManager = await ChainManager.makeFromDbState(~config)
     let globalState = GlobalState.make(~config, ~chainManager, ~shouldUseTui)
     let stateUpdatedHook = if shouldUseTui {
@@ -4,6 +4,7 @@ type t = {
   @as("schema_hash") schemaHash: string,
   @as("handler_files_hash") handlerFilesHash: string,
   @as("abi_files_hash") abiFilesHash: string,
+  @as("templates_override_hash") templatesOverrideHash: string,
 }
 
 let schema = S.schema(s => {
@@ -12,6 +13,7 @@ let schema = S.schema(s => {
   schemaHash: s.matches(S.string),
   handlerFilesHash: s.matches(S.string),
   abiFilesHash: s.matches(S.string),
+  templatesOverrideHash: s.matches(S.string),
 })
 
 external requireJson: string => Js.Json.t = "require"
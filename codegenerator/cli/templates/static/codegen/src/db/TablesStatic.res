@@ -95,6 +95,7 @@ module PersistedState = {
     schema_hash: string,
     handler_files_hash: string,
     abi_files_hash: string,
+    templates_override_hash: string,
   }
 
   let table = mkTable(
@@ -106,6 +107,7 @@ module PersistedState = {
       mkField("schema_hash", Text, ~fieldSchema=S.string),
       mkField("handler_files_hash", Text, ~fieldSchema=S.string),
       mkField("abi_files_hash", Text, ~fieldSchema=S.string),
+      mkField("templates_override_hash", Text, ~fieldSchema=S.string),
     ],
   )
 }
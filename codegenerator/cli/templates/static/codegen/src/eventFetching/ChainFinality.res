@@ -0,0 +1,110 @@
+open Belt
+
+exception SourceTimeout
+
+// Don't let a hanging source block the indexer start
+let defaultTimeoutMillis = 10_000
+
+let withTimeout = (promise, ~timeoutMillis) => {
+  let timeoutIdRef = ref(None)
+  let timeout = Promise.make((_, reject) => {
+    timeoutIdRef := Some(Js.Global.setTimeout(() => reject(SourceTimeout), timeoutMillis))
+  })
+  Promise.race([promise, timeout])->Promise.finally(() =>
+    timeoutIdRef.contents->Option.forEach(Js.Global.clearTimeout)
+  )
+}
+
+/**
+The number of blocks between the head and the tagged block of the source.
+None if the source doesn't support block tags, failed to return them or didn't respond in time.
+*/
+let getSourceReorgDepth = async (
+  source: Source.t,
+  ~tag,
+  ~logger,
+  ~timeoutMillis=defaultTimeoutMillis,
+) =>
+  switch source.getBlockNumberByTagOrThrow {
+  | None => None
+  | Some(getBlockNumberByTagOrThrow) =>
+    try {
+      let (height, taggedBlockNumber) = await Promise.all2((
+        source.getHeightOrThrow(),
+        getBlockNumberByTagOrThrow(~tag),
+      ))->withTimeout(~timeoutMillis)
+      taggedBlockNumber->Option.map(blockNumber => Pervasives.max(height - blockNumber, 0))
+    } catch {
+    | exn =>
+      logger->Logging.childWarn({
+        "msg": `Failed to get the "${(tag :> string)}" block from the source`,
+        "source": source.name,
+        "err": exn->Internal.prettifyExn,
+      })
+      None
+    }
+  }
+
+/**
+Detects the reorg depth from the deepest tagged block of the chain sources.
+It doesn't go below the minConfirmedBlockThreshold when the user set one.
+Falls back to the confirmedBlockThreshold from the config when no source supports the tag.
+*/
+let resolveChainConfig = async (
+  chainConfig: Config.chainConfig,
+  ~timeoutMillis=defaultTimeoutMillis,
+) =>
+  switch chainConfig.finality {
+  | None => chainConfig
+  | Some(tag) => {
+      let logger = Logging.createChild(
+        ~params={"chainId": chainConfig.chain->ChainMap.Chain.toChainId},
+      )
+      let depths =
+        await chainConfig.sources
+        ->Array.map(source => source->getSourceReorgDepth(~tag, ~logger, ~timeoutMillis))
+        ->Promise.all
+
+      switch depths->Array.keepMap(depth => depth) {
+      | [] => {
+          logger->Logging.childWarn({
+            "msg": `None of the chain sources support the "${(tag :> string)}" block tag. Using the default confirmed block threshold instead.`,
+            "confirmedBlockThreshold": chainConfig.confirmedBlockThreshold,
+          })
+          chainConfig
+        }
+      | depths => {
+          let detectedDepth = depths->Js.Math.maxMany_int
+          let confirmedBlockThreshold = switch chainConfig.minConfirmedBlockThreshold {
+          | Some(minConfirmedBlockThreshold) => Pervasives.max(detectedDepth, minConfirmedBlockThreshold)
+          | None => detectedDepth
+          }
+          logger->Logging.childInfo({
+            "msg": `Detected the reorg depth from the "${(tag :> string)}" block tag`,
+            "detectedDepth": detectedDepth,
+            "confirmedBlockThreshold": confirmedBlockThreshold,
+          })
+          {...chainConfig, confirmedBlockThreshold}
+        }
+      }
+    }
+  }
+
+let resolveConfig = async (config: Config.t) => {
+  let chainConfigs =
+    await config.chainMap
+    ->ChainMap.values
+    ->Array.map(chainConfig => chainConfig->resolveChainConfig)
+    ->Promise.all
+  let chainMap =
+    chainConfigs
+    ->Array.map(chainConfig => (chainConfig.chain, chainConfig))
+    ->ChainMap.fromArrayUnsafe
+  {
+    ...config,
+    chainMap,
+    defaultChain: config.defaultChain->Option.flatMap(defaultChain =>
+      chainConfigs->Array.getBy(chainConfig => chainConfig.chain === defaultChain.chain)
+    ),
+  }
+}
@@ -4,12 +4,17 @@ type rpc = {
   url: string,
   sourceFor: Source.sourceFor,
   syncConfig?: Config.syncConfigOptions,
+  weight?: int,
+  maxRequestsPerSecond?: int,
+  maxBlockRange?: int,
+  headers?: dict<string>,
 }
 
 let evm = (
   ~chain,
   ~contracts: array<Internal.evmContractConfig>,
   ~hyperSync,
+  ~hyperSyncApiToken=?,
   ~allEventSignatures,
   ~shouldUseHypersyncClientDecoder,
   ~rpcs: array<rpc>,
@@ -25,6 +30,7 @@ let evm = (
         chain,
         contracts,
         endpointUrl,
+        apiToken: ?hyperSyncApiToken,
         allEventSignatures,
         eventRouter,
         shouldUseHypersyncClientDecoder: Env.Configurable.shouldUseHypersyncClientDecoder->Option.getWithDefault(
@@ -34,16 +40,34 @@ let evm = (
     ]
   | _ => []
   }
-  rpcs->Js.Array2.forEach(({?syncConfig, url, sourceFor}) => {
+  rpcs->Js.Array2.forEach(({
+    ?syncConfig,
+    url,
+    sourceFor,
+    ?weight,
+    ?maxRequestsPerSecond,
+    ?maxBlockRange,
+    ?headers,
+  }) => {
     let _ = sources->Js.Array2.push(
-      RpcSource.make({
-        chain,
-        sourceFor,
-        contracts,
-        syncConfig: Config.getSyncConfig(syncConfig->Option.getWithDefault({})),
-        url,
-        eventRouter,
-      }),
+      switch sourceFor {
+      // Realtime RPCs are WebSocket endpoints used only for new block detection
+      | Realtime => RpcRealtimeSource.make({chain, url})
+      | Sync
+      | Fallback =>
+        RpcSource.make({
+          chain,
+          sourceFor,
+          contracts,
+          syncConfig: Config.getSyncConfig(syncConfig->Option.getWithDefault({})),
+          url,
+          eventRouter,
+          ?weight,
+          ?maxRequestsPerSecond,
+          ?maxBlockRange,
+          ?headers,
+        })
+      },
     )
   })
 
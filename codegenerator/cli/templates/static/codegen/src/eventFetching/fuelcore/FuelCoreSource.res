@@ -0,0 +1,186 @@
+open Source
+open Belt
+
+type options = {
+  chain: ChainMap.Chain.t,
+  endpointUrl: string,
+  sourceFor: Source.sourceFor,
+}
+
+// fuel-core limits the number of blocks which can be requested at once
+let maxBlocksPerQuery = 50
+
+let make = ({chain, endpointUrl, sourceFor}: options): t => {
+  let name = "FuelCore"
+
+  let client = Rest.client(endpointUrl)
+
+  // Reuse the HyperFuel routing, but the receipts are filtered on the client side,
+  // since fuel-core returns all receipts of the requested blocks
+  let getSelectionConfig = HyperFuelSource.memoGetSelectionConfig(~chain)
+
+  let getItemsOrThrow = async (
+    ~fromBlock,
+    ~toBlock,
+    ~addressesByContractName as _,
+    ~indexingContracts,
+    ~currentBlockHeight,
+    ~partitionId as _,
+    ~selection: FetchState.selection,
+    ~retry,
+    ~logger,
+  ) => {
+    let totalTimeRef = Hrtime.makeTimer()
+
+    let {eventRouter} = getSelectionConfig(selection)
+
+    let toBlock = switch toBlock {
+    | Some(toBlock) => Pervasives.min(toBlock, currentBlockHeight)
+    | None => currentBlockHeight
+    }
+    // Starts from the parent of the first block, which is used for the reorg guard
+    let parentBlockNumber = fromBlock - 1
+    let first =
+      Pervasives.max(Pervasives.min(toBlock - fromBlock + 1, maxBlocksPerQuery - 1), 1) +
+        (parentBlockNumber >= 0 ? 1 : 0)
+
+    let startFetchingBatchTimeRef = Hrtime.makeTimer()
+
+    let blocksWithParent = try await FuelCore.GetBlocks.route->Rest.fetch(
+      {
+        first,
+        after: parentBlockNumber > 0 ? Some((parentBlockNumber - 1)->Int.toString) : None,
+      },
+      ~client,
+    ) catch {
+    | exn =>
+      raise(
+        Source.GetItemsError(
+          Source.FailedGettingItems({
+            exn,
+            attemptedToBlock: toBlock,
+            retry: WithBackoff({
+              message: `Unexpected issue while fetching blocks from fuel-core. Attempt a retry.`,
+              backoffMillis: switch retry {
+              | 0 => 500
+              | _ => 1000 * retry
+              },
+            }),
+          }),
+        ),
+      )
+    }
+
+    let pageFetchTime =
+      startFetchingBatchTimeRef->Hrtime.timeSince->Hrtime.toMillis->Hrtime.intFromMillis
+
+    let (parentBlock, blocks) = blocksWithParent->FuelCore.splitParentBlock(~fromBlock)
+
+    let lastBlock = switch blocks->Utils.Array.last {
+    | Some(block) => block->FuelCore.toBlock
+    | None =>
+      raise(
+        Source.GetItemsError(
+          Source.FailedGettingItems({
+            exn: %raw(`null`),
+            attemptedToBlock: toBlock,
+            retry: WithBackoff({
+              message: `Block #${fromBlock->Int.toString} not found in fuel-core. Attempt a retry.`,
+              backoffMillis: 1000,
+            }),
+          }),
+        ),
+      )
+    }
+
+    let parsingTimeRef = Hrtime.makeTimer()
+
+    let parsedQueueItems = []
+    blocks->Array.forEach(blockWithTransactions => {
+      let block = blockWithTransactions->FuelCore.toBlock
+      // Counted across the transactions of the block,
+      // the same way as the logIndex of the HyperFuel source
+      let logIndexRef = ref(0)
+      blockWithTransactions.transactions->Array.forEach(transaction => {
+        transaction.receipts
+        ->Option.getWithDefault([])
+        ->Array.forEach(rawReceipt => {
+          let logIndex = logIndexRef.contents
+          logIndexRef := logIndex + 1
+          switch rawReceipt->FuelCore.parseReceipt {
+          | None => ()
+          | Some({contractId: contractAddress, receipt}) =>
+            switch eventRouter->EventRouter.get(
+              ~tag=receipt->HyperFuelSource.getEventTag,
+              ~indexingContracts,
+              ~contractAddress,
+              ~blockNumber=block.height,
+            ) {
+            | None => ()
+            | Some(eventConfig) =>
+              parsedQueueItems
+              ->Js.Array2.push(
+                HyperFuelSource.makeEventItem(
+                  ~eventConfig,
+                  ~receipt,
+                  ~block,
+                  ~contractAddress,
+                  ~logIndex,
+                  ~transaction={"id": transaction.id}->(
+                    Utils.magic: {"id": string} => Internal.eventTransaction
+                  ),
+                  ~chain,
+                  ~logger,
+                ),
+              )
+              ->ignore
+            }
+          }
+        })
+      })
+    })
+
+    let parsingTimeElapsed = parsingTimeRef->Hrtime.timeSince->Hrtime.toMillis->Hrtime.intFromMillis
+
+    let reorgGuard = FuelCore.makeReorgGuard(~parentBlock, ~lastBlock)
+
+    let totalTimeElapsed = totalTimeRef->Hrtime.timeSince->Hrtime.toMillis->Hrtime.intFromMillis
+
+    {
+      latestFetchedBlockTimestamp: lastBlock.time,
+      parsedQueueItems,
+      latestFetchedBlockNumber: lastBlock.height,
+      stats: {
+        totalTimeElapsed,
+        parsingTimeElapsed,
+        pageFetchTime,
+      },
+      currentBlockHeight: Pervasives.max(currentBlockHeight, lastBlock.height),
+      reorgGuard,
+      fromBlockQueried: fromBlock,
+    }
+  }
+
+  let getBlockHashes = (~blockNumbers, ~logger as _) => {
+    blockNumbers
+    ->Array.map(blockNumber => FuelCore.GetBlock.route->Rest.fetch(blockNumber, ~client))
+    ->Promise.all
+    ->Promise.thenResolve(blocks => {
+      blocks
+      ->Array.keepMap(block => block->Option.map(FuelCore.toBlockData))
+      ->Ok
+    })
+    ->Promise.catch(exn => exn->Error->Promise.resolve)
+  }
+
+  {
+    name,
+    sourceFor,
+    chain,
+    poweredByHyperSync: false,
+    pollingInterval: 1000,
+    getBlockHashes,
+    getHeightOrThrow: () => FuelCore.GetHeight.route->Rest.fetch((), ~client),
+    getItemsOrThrow,
+  }
+}
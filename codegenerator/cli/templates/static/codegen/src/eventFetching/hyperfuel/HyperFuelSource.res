@@ -7,14 +7,45 @@ let mintEventTag = "mint"
 let burnEventTag = "burn"
 let transferEventTag = "transfer"
 let callEventTag = "call"
+let predicateSpendEventTag = "predicate_spend"
+let scriptRunEventTag = "script_run"
 
 type selectionConfig = {
   getRecieptsSelection: (
     ~addressesByContractName: dict<array<Address.t>>,
   ) => array<HyperFuelClient.QueryTypes.receiptSelection>,
+  getInputsSelection: (
+    ~addressesByContractName: dict<array<Address.t>>,
+  ) => array<HyperFuelClient.QueryTypes.inputSelection>,
+  // Script receipts can't be filtered by the script on the HyperFuel side,
+  // so the response might contain receipts of other scripts and contracts
+  hasScriptEvents: bool,
   eventRouter: EventRouter.t<Internal.fuelEventConfig>,
+  blockFieldSelection: HyperFuelClient.QueryTypes.blockFieldSelection,
+  transactionFieldSelection: HyperFuelClient.QueryTypes.transactionFieldSelection,
 }
 
+// Fields which are always queried and don't need to be selected explicitly
+let defaultBlockFieldNames = ["id", "height", "time"]
+let defaultTransactionFieldNames = ["id"]
+
+let camelToSnakeCase = str =>
+  str->Js.String2.unsafeReplaceBy0(%re("/[A-Z]/g"), (letter, _, _) =>
+    "_" ++ letter->Js.String2.toLowerCase
+  )
+
+// HyperFuel field selection uses snake_case names of the fields
+let addSelectedFieldNames = (set, schema, ~defaultFieldNames) =>
+  switch schema->S.classify {
+  | Object({items}) =>
+    items->Array.forEach(item => {
+      if !(defaultFieldNames->Js.Array2.includes(item.location)) {
+        let _ = set->Utils.Set.add(item.location->camelToSnakeCase)
+      }
+    })
+  | _ => ()
+  }
+
 let logDataReceiptTypeSelection: array<Fuel.receiptType> = [LogData]
 
 // only transactions with status 1 (success)
@@ -68,6 +99,34 @@ let makeGetNormalRecieptsSelection = (
   }
 }
 
+// Selects all coin inputs instead of filtering by the predicate owners,
+// since the logIndex of a predicate spend is its index among the coin inputs of the block.
+// The inputs of not indexed owners are skipped by the event router
+let makeGetInputsSelection = (~predicateContractNames) => {
+  (~addressesByContractName) => {
+    let hasOwners =
+      predicateContractNames
+      ->Utils.Set.toArray
+      ->Array.some(contractName =>
+        addressesByContractName
+        ->Utils.Dict.dangerouslyGetNonOption(contractName)
+        ->Option.getWithDefault([])
+        ->Utils.Array.notEmpty
+      )
+
+    hasOwners
+      ? [
+          (
+            {
+              inputType: [Coin],
+              txStatus: txStatusSelection,
+            }: HyperFuelClient.QueryTypes.inputSelection
+          ),
+        ]
+      : []
+  }
+}
+
 let makeWildcardRecieptsSelection = (~wildcardLogDataRbs, ~nonLogDataWildcardReceiptTypes) => {
   let selection: array<HyperFuelClient.QueryTypes.receiptSelection> = []
 
@@ -114,6 +173,11 @@ let getSelectionConfig = (selection: FetchState.selection, ~chain) => {
   let nonLogDataReceiptTypesByContract = Js.Dict.empty()
   let nonLogDataWildcardReceiptTypes = []
 
+  // Scripts don't have contract ids, so their receipts are always queried as wildcard
+  let scriptLogDataRbs = []
+  let scriptReceiptTypes = []
+  let predicateContractNames = Utils.Set.make()
+
   let addNonLogDataWildcardReceiptTypes = (receiptType: Fuel.receiptType) => {
     nonLogDataWildcardReceiptTypes->Array.push(receiptType)->ignore
   }
@@ -125,11 +189,21 @@ let getSelectionConfig = (selection: FetchState.selection, ~chain) => {
   }
 
   let contractNames = Utils.Set.make()
+  let blockFieldNames = Utils.Set.make()
+  let transactionFieldNames = Utils.Set.make()
 
   selection.eventConfigs
   ->(Utils.magic: array<Internal.eventConfig> => array<Internal.fuelEventConfig>)
   ->Array.forEach(eventConfig => {
     let contractName = eventConfig.contractName
+    blockFieldNames->addSelectedFieldNames(
+      eventConfig.blockSchema,
+      ~defaultFieldNames=defaultBlockFieldNames,
+    )
+    transactionFieldNames->addSelectedFieldNames(
+      eventConfig.transactionSchema,
+      ~defaultFieldNames=defaultTransactionFieldNames,
+    )
     if !eventConfig.isWildcard {
       let _ = contractNames->Utils.Set.add(contractName)
     }
@@ -158,6 +232,20 @@ let getSelectionConfig = (selection: FetchState.selection, ~chain) => {
     | {kind: Call, isWildcard: true} => addNonLogDataWildcardReceiptTypes(Call)
     | {kind: Call} =>
       Js.Exn.raiseError("Call receipt indexing currently supported only in wildcard mode")
+    | {kind: PredicateSpend(_), isWildcard: true} =>
+      Js.Exn.raiseError("Predicate spend indexing currently isn't supported in wildcard mode")
+    | {kind: PredicateSpend(_)} => {
+        let _ = predicateContractNames->Utils.Set.add(contractName)
+      }
+    | {kind: ScriptRun(_)} => {
+        scriptReceiptTypes->Array.push(ScriptResult)->ignore
+        let _ = transactionFieldNames->Utils.Set.add("script")
+        let _ = transactionFieldNames->Utils.Set.add("script_data")
+      }
+    | {kind: ScriptLogData({logId})} => {
+        scriptLogDataRbs->Array.push(logId->BigInt.fromStringUnsafe)->ignore
+        let _ = transactionFieldNames->Utils.Set.add("script")
+      }
     | {kind: LogData({logId}), isWildcard} => {
         let rb = logId->BigInt.fromStringUnsafe
         if isWildcard {
@@ -172,23 +260,42 @@ let getSelectionConfig = (selection: FetchState.selection, ~chain) => {
     }
   })
 
+  let scriptRecieptsSelection = makeWildcardRecieptsSelection(
+    ~wildcardLogDataRbs=scriptLogDataRbs,
+    ~nonLogDataWildcardReceiptTypes=scriptReceiptTypes,
+  )
+
   {
     getRecieptsSelection: switch selection.dependsOnAddresses {
     | false => {
-        let recieptsSelection = makeWildcardRecieptsSelection(
-          ~wildcardLogDataRbs,
-          ~nonLogDataWildcardReceiptTypes,
-        )
+        let recieptsSelection =
+          makeWildcardRecieptsSelection(
+            ~wildcardLogDataRbs,
+            ~nonLogDataWildcardReceiptTypes,
+          )->Array.concat(scriptRecieptsSelection)
         (~addressesByContractName as _) => recieptsSelection
       }
-    | true =>
-      makeGetNormalRecieptsSelection(
-        ~nonWildcardLogDataRbsByContract,
-        ~nonLogDataReceiptTypesByContract,
-        ~contractNames,
-      )
+    | true => {
+        let getNormalRecieptsSelection = makeGetNormalRecieptsSelection(
+          ~nonWildcardLogDataRbsByContract,
+          ~nonLogDataReceiptTypesByContract,
+          ~contractNames,
+        )
+        (~addressesByContractName) =>
+          getNormalRecieptsSelection(~addressesByContractName)->Array.concat(
+            scriptRecieptsSelection,
+          )
+      }
     },
+    getInputsSelection: makeGetInputsSelection(~predicateContractNames),
+    hasScriptEvents: scriptRecieptsSelection->Utils.Array.notEmpty,
     eventRouter,
+    blockFieldSelection: blockFieldNames
+    ->Utils.Set.toArray
+    ->(Utils.magic: array<string> => HyperFuelClient.QueryTypes.blockFieldSelection),
+    transactionFieldSelection: transactionFieldNames
+    ->Utils.Set.toArray
+    ->(Utils.magic: array<string> => HyperFuelClient.QueryTypes.transactionFieldSelection),
   }
 }
 
@@ -205,12 +312,177 @@ let memoGetSelectionConfig = (~chain) => {
     }
 }
 
+let getEventTag = (receipt: Fuel.Receipt.t) =>
+  switch receipt {
+  | LogData({rb}) => BigInt.toString(rb)
+  | Mint(_) => mintEventTag
+  | Burn(_) => burnEventTag
+  | Transfer(_)
+  | TransferOut(_) => transferEventTag
+  | Call(_) => callEventTag
+  | ScriptResult(_) => scriptRunEventTag
+  }
+
+let makeEventItem = (
+  ~eventConfig: Internal.fuelEventConfig,
+  ~receipt: Fuel.Receipt.t,
+  ~block: HyperFuel.block,
+  ~contractAddress,
+  ~logIndex,
+  ~transaction: Internal.eventTransaction,
+  ~chain,
+  ~logger,
+): Internal.eventItem => {
+  let chainId = chain->ChainMap.Chain.toChainId
+
+  let decodeOrRaise = (decode, data, ~msg) =>
+    try decode(data) catch {
+    | exn => {
+        let params = {
+          "chainId": chainId,
+          "blockNumber": block.height,
+          "logIndex": logIndex,
+        }
+        let logger = Logging.createChildFrom(~logger, ~params)
+        exn->ErrorHandling.mkLogAndRaise(~msg, ~logger)
+      }
+    }
+
+  let params = switch (eventConfig, receipt) {
+  | ({kind: LogData({decode}) | ScriptLogData({decode})}, LogData({data})) =>
+    decodeOrRaise(
+      decode,
+      data,
+      ~msg="Failed to decode Fuel LogData receipt, please double check your ABI.",
+    )
+  | ({kind: ScriptRun({decode})}, ScriptResult(_)) =>
+    decodeOrRaise(
+      decode,
+      (transaction->(Utils.magic: Internal.eventTransaction => {"scriptData": string}))["scriptData"],
+      ~msg="Failed to decode Fuel script data, please double check your ABI.",
+    )
+  | (_, Mint({val, subId}))
+  | (_, Burn({val, subId})) =>
+    (
+      {
+        subId,
+        amount: val,
+      }: Internal.fuelSupplyParams
+    )->Obj.magic
+  | (_, Transfer({amount, assetId, to})) =>
+    (
+      {
+        to: to->Address.unsafeFromString,
+        assetId,
+        amount,
+      }: Internal.fuelTransferParams
+    )->Obj.magic
+  | (_, TransferOut({amount, assetId, toAddress})) =>
+    (
+      {
+        to: toAddress->Address.unsafeFromString,
+        assetId,
+        amount,
+      }: Internal.fuelTransferParams
+    )->Obj.magic
+  | (_, Call({amount, assetId, to})) =>
+    (
+      {
+        to: to->Address.unsafeFromString,
+        assetId,
+        amount,
+      }: Internal.fuelTransferParams
+    )->Obj.magic
+  // This should never happen unless there's a bug in the routing logic
+  | _ => Js.Exn.raiseError("Unexpected bug in the event routing logic")
+  }
+
+  {
+    eventConfig: (eventConfig :> Internal.eventConfig),
+    timestamp: block.time,
+    chain,
+    blockNumber: block.height,
+    logIndex,
+    event: {
+      chainId,
+      params,
+      transaction,
+      block: block->Obj.magic,
+      srcAddress: contractAddress,
+      logIndex,
+    },
+  }
+}
+
+let makePredicateSpendEventItem = (
+  ~eventConfig: Internal.fuelEventConfig,
+  ~inputItem: HyperFuel.inputItem,
+  ~transaction: Internal.eventTransaction,
+  ~chain,
+  ~logger,
+): Internal.eventItem => {
+  let {owner, input, logIndex, block} = inputItem
+  let chainId = chain->ChainMap.Chain.toChainId
+
+  let data = switch eventConfig {
+  | {kind: PredicateSpend({decode})} =>
+    try decode(input.predicateData) catch {
+    | exn => {
+        let params = {
+          "chainId": chainId,
+          "blockNumber": block.height,
+          "logIndex": logIndex,
+        }
+        let logger = Logging.createChildFrom(~logger, ~params)
+        exn->ErrorHandling.mkLogAndRaise(
+          ~msg="Failed to decode Fuel predicate data, please double check your ABI.",
+          ~logger,
+        )
+      }
+    }
+  // This should never happen unless there's a bug in the routing logic
+  | _ => Js.Exn.raiseError("Unexpected bug in the event routing logic")
+  }
+
+  {
+    eventConfig: (eventConfig :> Internal.eventConfig),
+    timestamp: block.time,
+    chain,
+    blockNumber: block.height,
+    logIndex,
+    event: {
+      chainId,
+      params: (
+        {
+          owner,
+          assetId: input.assetId,
+          amount: input.amount,
+          data,
+        }: Internal.fuelPredicateSpendParams<unknown>
+      )->Obj.magic,
+      transaction,
+      block: block->Obj.magic,
+      srcAddress: owner,
+      logIndex,
+    },
+  }
+}
+
+let toEventTransaction = (~transaction, ~transactionId) =>
+  switch transaction {
+  | Some(transaction) =>
+    transaction->(Utils.magic: HyperFuelClient.FuelTypes.transaction => Internal.eventTransaction)
+  | None => {"id": transactionId}->(Utils.magic: {"id": string} => Internal.eventTransaction)
+  }
+
 type options = {
   chain: ChainMap.Chain.t,
   endpointUrl: string,
+  // The parent of the first queried block is needed only to roll back on reorgs
+  shouldRollbackOnReorg: bool,
 }
 
-let make = ({chain, endpointUrl}: options): t => {
+let make = ({chain, endpointUrl, shouldRollbackOnReorg}: options): t => {
   let name = "HyperFuel"
 
   let getSelectionConfig = memoGetSelectionConfig(~chain)
@@ -231,15 +503,28 @@ let make = ({chain, endpointUrl}: options): t => {
 
     let selectionConfig = getSelectionConfig(selection)
     let recieptsSelection = selectionConfig.getRecieptsSelection(~addressesByContractName)
+    let inputsSelection = selectionConfig.getInputsSelection(~addressesByContractName)
 
     let startFetchingBatchTimeRef = Hrtime.makeTimer()
 
+    //The parent of the first queried block is used for the reorg guard.
+    //The error is handled after the page query, so it's kept as a result
+    let firstBlockParentPromise =
+      fromBlock > 0 && shouldRollbackOnReorg
+        ? HyperFuel.queryBlockData(~serverUrl=endpointUrl, ~blockNumber=fromBlock - 1, ~logger)
+          ->Promise.thenResolve(block => Ok(block))
+          ->Promise.catch(exn => Error(exn)->Promise.resolve)
+        : Promise.resolve(Ok(None))
+
     //fetch batch
     let pageUnsafe = try await HyperFuel.GetLogs.query(
       ~serverUrl=endpointUrl,
       ~fromBlock,
       ~toBlock,
       ~recieptsSelection,
+      ~inputsSelection,
+      ~blockFieldSelection=selectionConfig.blockFieldSelection,
+      ~transactionFieldSelection=selectionConfig.transactionFieldSelection,
     ) catch {
     | HyperSync.GetLogs.Error(error) =>
       raise(
@@ -351,32 +636,31 @@ let make = ({chain, endpointUrl}: options): t => {
 
     let parsingTimeRef = Hrtime.makeTimer()
 
-    let parsedQueueItems = pageUnsafe.items->Array.map(item => {
-      let {contractId: contractAddress, receipt, block, receiptIndex} = item
-
-      let chainId = chain->ChainMap.Chain.toChainId
-      let eventId = switch receipt {
-      | LogData({rb}) => BigInt.toString(rb)
-      | Mint(_) => mintEventTag
-      | Burn(_) => burnEventTag
-      | Transfer(_)
-      | TransferOut(_) => transferEventTag
-      | Call(_) => callEventTag
-      }
+    let receiptItems = pageUnsafe.items->Array.keepMap(item => {
+      let {contractId: contractAddress, receipt, block, logIndex} = item
 
-      let eventConfig = switch selectionConfig.eventRouter->EventRouter.get(
+      let eventId = receipt->getEventTag
+      let isIndexedContract =
+        indexingContracts
+        ->Utils.Dict.dangerouslyGetNonOption(contractAddress->Address.toString)
+        ->Option.isSome
+
+      switch selectionConfig.eventRouter->EventRouter.get(
         ~tag=eventId,
         ~indexingContracts,
         ~contractAddress,
         ~blockNumber=block.height,
       ) {
+      // Receipts of not indexed scripts and contracts matched by the script receipts selection
+      | None if selectionConfig.hasScriptEvents && (item.isScriptReceipt || !isIndexedContract) =>
+        None
       | None => {
           let logger = Logging.createChildFrom(
             ~logger,
             ~params={
-              "chainId": chainId,
+              "chainId": chain->ChainMap.Chain.toChainId,
               "blockNumber": block.height,
-              "logIndex": receiptIndex,
+              "logIndex": logIndex,
               "contractAddress": contractAddress,
               "eventId": eventId,
             },
@@ -386,89 +670,86 @@ let make = ({chain, endpointUrl}: options): t => {
             ~logger,
           )
         }
-      | Some(eventConfig) => eventConfig
-      }
-
-      let params = switch (eventConfig, receipt) {
-      | ({kind: LogData({decode})}, LogData({data})) =>
-        try decode(data) catch {
-        | exn => {
-            let params = {
-              "chainId": chainId,
-              "blockNumber": block.height,
-              "logIndex": receiptIndex,
-            }
-            let logger = Logging.createChildFrom(~logger, ~params)
-            exn->ErrorHandling.mkLogAndRaise(
-              ~msg="Failed to decode Fuel LogData receipt, please double check your ABI.",
-              ~logger,
-            )
-          }
-        }
-      | (_, Mint({val, subId}))
-      | (_, Burn({val, subId})) =>
-        (
-          {
-            subId,
-            amount: val,
-          }: Internal.fuelSupplyParams
-        )->Obj.magic
-      | (_, Transfer({amount, assetId, to})) =>
-        (
-          {
-            to: to->Address.unsafeFromString,
-            assetId,
-            amount,
-          }: Internal.fuelTransferParams
-        )->Obj.magic
-      | (_, TransferOut({amount, assetId, toAddress})) =>
-        (
-          {
-            to: toAddress->Address.unsafeFromString,
-            assetId,
-            amount,
-          }: Internal.fuelTransferParams
-        )->Obj.magic
-      | (_, Call({amount, assetId, to})) =>
-        (
-          {
-            to: to->Address.unsafeFromString,
-            assetId,
-            amount,
-          }: Internal.fuelTransferParams
-        )->Obj.magic
-      // This should never happen unless there's a bug in the routing logic
-      | _ => Js.Exn.raiseError("Unexpected bug in the event routing logic")
+      | Some(eventConfig) =>
+        makeEventItem(
+          ~eventConfig,
+          ~receipt,
+          ~block,
+          ~contractAddress,
+          ~logIndex,
+          ~transaction=toEventTransaction(
+            ~transaction=item.transaction,
+            ~transactionId=item.transactionId,
+          ),
+          ~chain,
+          ~logger,
+        )->Some
       }
+    })
 
-      (
-        {
-          eventConfig: (eventConfig :> Internal.eventConfig),
-          timestamp: block.time,
-          chain,
-          blockNumber: block.height,
-          logIndex: receiptIndex,
-          event: {
-            chainId,
-            params,
-            transaction: {
-              "id": item.transactionId,
-            }->Obj.magic, // TODO: Obj.magic needed until the field selection types are not configurable for Fuel and Evm separately
-            block: block->Obj.magic,
-            srcAddress: contractAddress,
-            logIndex: receiptIndex,
-          },
-        }: Internal.eventItem
+    let parsedQueueItems = switch pageUnsafe.inputItems {
+    | [] => receiptItems
+    | inputItems =>
+      inputItems
+      ->Array.keepMap(inputItem => {
+        selectionConfig.eventRouter
+        ->EventRouter.get(
+          ~tag=predicateSpendEventTag,
+          ~indexingContracts,
+          ~contractAddress=inputItem.owner,
+          ~blockNumber=inputItem.block.height,
+        )
+        ->Option.map(eventConfig =>
+          makePredicateSpendEventItem(
+            ~eventConfig,
+            ~inputItem,
+            ~transaction=toEventTransaction(
+              ~transaction=inputItem.transaction,
+              ~transactionId=inputItem.transactionId,
+            ),
+            ~chain,
+            ~logger,
+          )
+        )
+      })
+      ->Array.concat(receiptItems, _)
+      // The sort is stable, so items in the same block keep the receipts first order,
+      // which matches the predicate spends logIndex going after all receipts of the block
+      ->Js.Array2.sortInPlaceWith((a: Internal.eventItem, b: Internal.eventItem) =>
+        a.blockNumber - b.blockNumber
       )
-    })
+    }
 
     let parsingTimeElapsed = parsingTimeRef->Hrtime.timeSince->Hrtime.toMillis->Hrtime.intFromMillis
 
     let rangeLastBlock = await lastBlockQueriedPromise
 
+    let optFirstBlockParent = switch await firstBlockParentPromise {
+    | Ok(block) => block
+    | Error(exn) =>
+      raise(
+        Source.GetItemsError(
+          Source.FailedGettingItems({
+            exn,
+            attemptedToBlock: toBlock->Option.getWithDefault(currentBlockHeight),
+            retry: WithBackoff({
+              message: `Failed to query blockData for block ${(fromBlock - 1)
+                  ->Int.toString}. Attempt a retry.`,
+              backoffMillis: switch retry {
+              | 0 => 500
+              | _ => 1000 * retry
+              },
+            }),
+          }),
+        ),
+      )
+    }
+
     let reorgGuard: ReorgDetection.reorgGuard = {
       rangeLastBlock: rangeLastBlock->ReorgDetection.generalizeBlockDataWithTimestamp,
-      prevRangeLastBlock: None,
+      prevRangeLastBlock: optFirstBlockParent->Option.map(
+        ReorgDetection.generalizeBlockDataWithTimestamp,
+      ),
     }
 
     let totalTimeElapsed = totalTimeRef->Hrtime.timeSince->Hrtime.toMillis->Hrtime.intFromMillis
@@ -490,8 +771,27 @@ let make = ({chain, endpointUrl}: options): t => {
     }
   }
 
-  let getBlockHashes = (~blockNumbers as _, ~logger as _) =>
-    Js.Exn.raiseError("HyperFuel does not support getting block hashes")
+  // Queries the whole range of the requested blocks at once,
+  // since they are close to each other within the reorg threshold
+  let getBlockHashes = (~blockNumbers, ~logger) =>
+    switch blockNumbers {
+    | [] => Promise.resolve(Ok([]))
+    | _ => {
+        let requestedBlockNumbers = Utils.Set.fromArray(blockNumbers)
+        HyperFuel.queryBlocksData(
+          ~serverUrl=endpointUrl,
+          ~fromBlock=Js.Math.minMany_int(blockNumbers),
+          ~toBlock=Js.Math.maxMany_int(blockNumbers),
+          ~logger,
+        )
+        ->Promise.thenResolve(blocks =>
+          blocks
+          ->Array.keep(block => requestedBlockNumbers->Utils.Set.has(block.blockNumber))
+          ->Ok
+        )
+        ->Promise.catch(exn => exn->Error->Promise.resolve)
+      }
+    }
 
   let jsonApiClient = Rest.client(endpointUrl)
 
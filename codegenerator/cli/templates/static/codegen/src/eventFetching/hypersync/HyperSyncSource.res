@@ -148,6 +148,8 @@ type options = {
   contracts: array<Internal.evmContractConfig>,
   chain: ChainMap.Chain.t,
   endpointUrl: string,
+  // From the Authorization header in hypersync_config
+  apiToken?: string,
   allEventSignatures: array<string>,
   shouldUseHypersyncClientDecoder: bool,
   eventRouter: EventRouter.t<Internal.evmEventConfig>,
@@ -158,6 +160,7 @@ let make = (
     contracts,
     chain,
     endpointUrl,
+    ?apiToken,
     allEventSignatures,
     shouldUseHypersyncClientDecoder,
     eventRouter,
@@ -167,8 +170,11 @@ let make = (
 
   let getSelectionConfig = memoGetSelectionConfig(~chain)
 
-  let apiToken =
+  let apiToken = switch apiToken {
+  | Some(apiToken) => apiToken
+  | None =>
     Env.envioApiToken->Belt.Option.getWithDefault("3dc856dd-b0ea-494f-b27e-017b8b6b7e07")
+  }
 
   let client = HyperSyncClient.make(
     ~url=endpointUrl,
@@ -0,0 +1,150 @@
+open Belt
+open Source
+
+exception WebSocketClosed({url: string})
+exception UnsupportedByRealtimeSource({method: string})
+
+type heightWaiter = {
+  resolve: int => unit,
+  reject: exn => unit,
+}
+
+let sanitizeUrl = (url: string) => {
+  // Same as RpcSource.sanitizeUrl but for ws:// and wss://
+  let regex = %re("/wss?:\/\/([^\/?]+).*/")
+
+  switch Js.Re.exec_(regex, url) {
+  | Some(result) =>
+    switch Js.Re.captures(result)->Belt.Array.get(1) {
+    | Some(host) => host->Js.Nullable.toOption
+    | None => None
+    }
+  | None => None
+  }
+}
+
+type options = {
+  url: string,
+  chain: ChainMap.Chain.t,
+}
+
+/**
+Detects new blocks with an eth_subscribe("newHeads") subscription over WebSocket.
+It doesn't fetch any items, the block ranges are still queried by the Sync sources over HTTP.
+*/
+let make = ({url, chain}: options): t => {
+  let urlHost = switch sanitizeUrl(url) {
+  | None =>
+    Js.Exn.raiseError(
+      `EE116: The realtime RPC url "${url}" is incorrect format. The realtime RPC url needs to start with either ws:// or wss://`,
+    )
+  | Some(host) => host
+  }
+  let name = `RPC Realtime (${urlHost})`
+  let chainId = chain->ChainMap.Chain.toChainId
+
+  let providerRef = ref(None)
+  let latestHeightRef = ref(None)
+  // Every getHeightOrThrow call waits for a head newer than the previously returned one,
+  // so polling the source doesn't turn into a busy loop
+  let returnedHeightRef = ref(0)
+  let heightWaiters: array<heightWaiter> = []
+
+  let takeHeightWaiters = () =>
+    heightWaiters->Js.Array2.removeCountInPlace(~pos=0, ~count=heightWaiters->Array.length)
+
+  let disconnect = (~provider, ~exn) => {
+    switch providerRef.contents {
+    | Some(activeProvider) if activeProvider === provider => {
+        providerRef := None
+        provider->Ethers.WebSocketProvider.destroy->Promise.silentCatch->Promise.done
+        takeHeightWaiters()->Array.forEach(({reject}) => reject(exn))
+      }
+    // The provider has already been replaced
+    | _ => ()
+    }
+  }
+
+  let connect = () => {
+    let provider = Ethers.WebSocketProvider.make(~rpcUrl=url, ~chainId)
+    providerRef := Some(provider)
+
+    let websocket = provider->Ethers.WebSocketProvider.websocket
+    websocket->Ethers.WebSocketProvider.WebSocket.setOnError(err => {
+      Logging.warn({
+        "msg": "The realtime RPC WebSocket connection failed. It'll reconnect on the next height check.",
+        "source": name,
+        "err": err,
+      })
+      disconnect(~provider, ~exn=WebSocketClosed({url: url}))
+    })
+    websocket->Ethers.WebSocketProvider.WebSocket.setOnClose(() => {
+      disconnect(~provider, ~exn=WebSocketClosed({url: url}))
+    })
+
+    provider
+    ->Ethers.WebSocketProvider.onBlock(blockNumber => {
+      latestHeightRef := Some(blockNumber)
+      switch takeHeightWaiters() {
+      | [] => ()
+      | waiters => {
+          returnedHeightRef := blockNumber
+          waiters->Array.forEach(({resolve}) => resolve(blockNumber))
+        }
+      }
+    })
+    ->Promise.ignoreValue
+    ->Promise.catch(exn => {
+      disconnect(~provider, ~exn)
+      Promise.resolve()
+    })
+    ->Promise.done
+  }
+
+  let getHeightOrThrow = () => {
+    if providerRef.contents->Option.isNone {
+      connect()
+    }
+    switch latestHeightRef.contents {
+    | Some(height) if height > returnedHeightRef.contents => {
+        returnedHeightRef := height
+        Promise.resolve(height)
+      }
+    | _ =>
+      Promise.make((resolve, reject) => {
+        heightWaiters->Js.Array2.push({resolve, reject})->ignore
+      })
+    }
+  }
+
+  {
+    name,
+    sourceFor: Realtime,
+    chain,
+    poweredByHyperSync: false,
+    // The height check resolves only on a new head,
+    // so there's no need to wait between the checks
+    pollingInterval: 0,
+    getBlockHashes: (~blockNumbers as _, ~logger as _) =>
+      Error(UnsupportedByRealtimeSource({method: "getBlockHashes"}))->Promise.resolve,
+    getHeightOrThrow,
+    getItemsOrThrow: (
+      ~fromBlock as _,
+      ~toBlock as _,
+      ~addressesByContractName as _,
+      ~indexingContracts as _,
+      ~currentBlockHeight as _,
+      ~partitionId as _,
+      ~selection as _,
+      ~retry as _,
+      ~logger as _,
+    ) =>
+      raise(
+        GetItemsError(
+          UnsupportedSelection({
+            message: "The realtime RPC source is only used for new block detection and can't fetch items.",
+          }),
+        ),
+      ),
+  }
+}
@@ -16,8 +16,13 @@ let getKnownBlock = (provider, blockNumber) =>
     }
   )
 
-let rec getKnownBlockWithBackoff = async (~provider, ~blockNumber, ~backoffMsOnFailure) =>
-  switch await getKnownBlock(provider, blockNumber) {
+let rec getKnownBlockWithBackoff = async (
+  ~provider,
+  ~blockNumber,
+  ~backoffMsOnFailure,
+  ~rateLimit=fn => fn(),
+) =>
+  switch await rateLimit(() => getKnownBlock(provider, blockNumber)) {
   | exception err =>
     Logging.warn({
       "err": err,
@@ -29,6 +34,7 @@ let rec getKnownBlockWithBackoff = async (~provider, ~blockNumber, ~backoffMsOnF
       ~provider,
       ~blockNumber,
       ~backoffMsOnFailure=backoffMsOnFailure * 2,
+      ~rateLimit,
     )
   | result => result
   }
@@ -428,6 +434,21 @@ let sanitizeUrl = (url: string) => {
   }
 }
 
+// Adds the configured headers to the requests made by Rest.fetch
+let makeFetcherWithHeaders = (headers: dict<string>): Rest.ApiFetcher.t =>
+  args => {
+    let mergedHeaders = switch args.headers {
+    | Some(argsHeaders) => argsHeaders->Js.Dict.entries->Js.Dict.fromArray
+    | None => Js.Dict.empty()
+    }
+    headers
+    ->Js.Dict.entries
+    ->Js.Array2.forEach(((name, value)) =>
+      mergedHeaders->Js.Dict.set(name, value->(Utils.magic: string => unknown))
+    )
+    Rest.ApiFetcher.default({...args, headers: Some(mergedHeaders)})
+  }
+
 type options = {
   sourceFor: Source.sourceFor,
   syncConfig: Config.syncConfig,
@@ -435,9 +456,26 @@ type options = {
   chain: ChainMap.Chain.t,
   contracts: array<Internal.evmContractConfig>,
   eventRouter: EventRouter.t<Internal.evmEventConfig>,
+  weight?: int,
+  maxRequestsPerSecond?: int,
+  maxBlockRange?: int,
+  headers?: dict<string>,
 }
 
-let make = ({sourceFor, syncConfig, url, chain, contracts, eventRouter}: options): t => {
+let make = (
+  {
+    sourceFor,
+    syncConfig,
+    url,
+    chain,
+    contracts,
+    eventRouter,
+    ?weight,
+    ?maxRequestsPerSecond,
+    ?maxBlockRange,
+    ?headers,
+  }: options,
+): t => {
   let urlHost = switch sanitizeUrl(url) {
   | None =>
     Js.Exn.raiseError(
@@ -447,14 +485,40 @@ let make = ({sourceFor, syncConfig, url, chain, contracts, eventRouter}: options
   }
   let name = `RPC (${urlHost})`
 
-  let provider = Ethers.JsonRpcProvider.make(~rpcUrl=url, ~chainId=chain->ChainMap.Chain.toChainId)
+  // Never request logs for more blocks than the provider allows
+  let syncConfig = switch maxBlockRange {
+  | Some(maxBlockRange) => {
+      ...syncConfig,
+      initialBlockInterval: Pervasives.min(syncConfig.initialBlockInterval, maxBlockRange),
+      intervalCeiling: Pervasives.min(syncConfig.intervalCeiling, maxBlockRange),
+    }
+  | None => syncConfig
+  }
+
+  // All requests to the RPC go through the rate limiter, so it doesn't respond with 429s
+  let rateLimiter =
+    maxRequestsPerSecond->Option.map(maxRequestsPerSecond =>
+      RateLimiter.make(~maxRequestsPerSecond)
+    )
+  let rateLimit = fn =>
+    switch rateLimiter {
+    | Some(rateLimiter) => rateLimiter->RateLimiter.schedule(fn)
+    | None => fn()
+    }
+
+  let provider = Ethers.JsonRpcProvider.make(
+    ~rpcUrl=url,
+    ~chainId=chain->ChainMap.Chain.toChainId,
+    ~headers?,
+  )
 
   let getSelectionConfig = memoGetSelectionConfig(~chain)
 
   let suggestedBlockIntervals = Js.Dict.empty()
 
   let transactionLoader = LazyLoader.make(
-    ~loaderFn=transactionHash => provider->Ethers.JsonRpcProvider.getTransaction(~transactionHash),
+    ~loaderFn=transactionHash =>
+      rateLimit(() => provider->Ethers.JsonRpcProvider.getTransaction(~transactionHash)),
     ~onError=(am, ~exn) => {
       Logging.error({
         "err": exn,
@@ -473,7 +537,7 @@ let make = ({sourceFor, syncConfig, url, chain, contracts, eventRouter}: options
 
   let blockLoader = LazyLoader.make(
     ~loaderFn=blockNumber =>
-      getKnownBlockWithBackoff(~provider, ~backoffMsOnFailure=1000, ~blockNumber),
+      getKnownBlockWithBackoff(~provider, ~backoffMsOnFailure=1000, ~blockNumber, ~rateLimit),
     ~onError=(am, ~exn) => {
       Logging.error({
         "err": exn,
@@ -540,16 +604,20 @@ let make = ({sourceFor, syncConfig, url, chain, contracts, eventRouter}: options
     let {getLogSelectionOrThrow} = getSelectionConfig(selection)
     let {addresses, topicQuery} = getLogSelectionOrThrow(~addressesByContractName)
 
-    let {logs, latestFetchedBlock} = await getNextPage(
-      ~fromBlock,
-      ~toBlock=suggestedToBlock,
-      ~addresses,
-      ~topicQuery,
-      ~loadBlock=blockNumber => blockLoader->LazyLoader.get(blockNumber),
-      ~syncConfig,
-      ~provider,
-      ~suggestedBlockIntervals,
-      ~partitionId,
+    // Wait for the rate limit before starting the page fetch,
+    // so the waiting time doesn't count towards the query timeout
+    let {logs, latestFetchedBlock} = await rateLimit(() =>
+      getNextPage(
+        ~fromBlock,
+        ~toBlock=suggestedToBlock,
+        ~addresses,
+        ~topicQuery,
+        ~loadBlock=blockNumber => blockLoader->LazyLoader.get(blockNumber),
+        ~syncConfig,
+        ~provider,
+        ~suggestedBlockIntervals,
+        ~partitionId,
+      )
     )
 
     let executedBlockInterval = suggestedToBlock - fromBlock + 1
@@ -694,7 +762,7 @@ let make = ({sourceFor, syncConfig, url, chain, contracts, eventRouter}: options
     ->Promise.catch(exn => exn->Error->Promise.resolve)
   }
 
-  let client = Rest.client(url)
+  let client = Rest.client(url, ~fetcher=?headers->Option.map(makeFetcherWithHeaders))
 
   {
     name,
@@ -702,8 +770,16 @@ let make = ({sourceFor, syncConfig, url, chain, contracts, eventRouter}: options
     chain,
     poweredByHyperSync: false,
     pollingInterval: 1000,
+    ?weight,
     getBlockHashes,
-    getHeightOrThrow: () => Rpc.GetBlockHeight.route->Rest.fetch((), ~client),
+    getHeightOrThrow: () => rateLimit(() => Rpc.GetBlockHeight.route->Rest.fetch((), ~client)),
+    getBlockNumberByTagOrThrow: (~tag) =>
+      rateLimit(() =>
+        Rpc.GetBlockNumberByTag.route->Rest.fetch(
+          {"tag": (tag :> string), "includeTransactions": false},
+          ~client,
+        )
+      ),
     getItemsOrThrow,
   }
 }
@@ -3,8 +3,6 @@
 mod tests {
     use reqwest;
 
-    use strum::IntoEnumIterator;
-
     pub enum EndpointHealth {
         Healthy,
         Unhealthy(String),
@@ -30,8 +28,10 @@ mod tests {
     #[tokio::test]
     async fn all_supported_endpoints_are_healthy() {
         // TODO: implement a 'chain_id' method in hypersync, and test that the chain_id is correct too.
-        for network in envio::config_parsing::chain_helpers::HypersyncNetwork::iter() {
-            let rpc_url = envio::config_parsing::hypersync_endpoints::network_to_skar_url(&network);
+        let chain_registry =
+            envio::config_parsing::chain_registry::ChainRegistry::global().unwrap();
+        for chain in chain_registry.iter_hypersync_chains() {
+            let rpc_url = chain.hypersync_url.clone().unwrap();
             match fetch_hypersync_health(&rpc_url).await {
                 Ok(is_healthy) => {
                     // Assert that the endpoint health is Healthy
@@ -0,0 +1,73 @@
+open RescriptMocha
+open Belt
+
+describe("FuelCore - reorg guard", () => {
+  let mockBlock = (~height, ~id): FuelCore.blockWithTransactions => {
+    id,
+    height,
+    time: 1000 + height,
+    transactions: [],
+  }
+
+  let getReorgGuard = (blocksWithParent, ~fromBlock) => {
+    let (parentBlock, blocks) = blocksWithParent->FuelCore.splitParentBlock(~fromBlock)
+    FuelCore.makeReorgGuard(
+      ~parentBlock,
+      ~lastBlock=blocks->Utils.Array.last->Option.getExn->FuelCore.toBlock,
+    )
+  }
+
+  it("Splits the parent of the first requested block", () => {
+    let parentBlock = mockBlock(~height=9, ~id="0x09")
+    let block = mockBlock(~height=10, ~id="0x10")
+
+    Assert.deepEqual(
+      [parentBlock, block]->FuelCore.splitParentBlock(~fromBlock=10),
+      (Some(parentBlock->FuelCore.toBlock), [block]),
+    )
+    Assert.deepEqual(
+      [block]->FuelCore.splitParentBlock(~fromBlock=10),
+      (None, [block]),
+      ~message="Should keep the blocks when the parent is missing",
+    )
+
+    let genesisBlock = mockBlock(~height=0, ~id="0x00")
+    Assert.deepEqual(
+      [genesisBlock]->FuelCore.splitParentBlock(~fromBlock=0),
+      (None, [genesisBlock]),
+      ~message="The genesis block doesn't have a parent",
+    )
+  })
+
+  it("Detects a reorg of the previous range last block", () => {
+    let (scannedHashes, reorgResult) =
+      ReorgDetection.LastBlockScannedHashes.empty(~confirmedBlockThreshold=200)->ReorgDetection.LastBlockScannedHashes.registerReorgGuard(
+        ~reorgGuard=[
+          mockBlock(~height=0, ~id="0xa0"),
+          mockBlock(~height=1, ~id="0xa1"),
+          mockBlock(~height=2, ~id="0xa2"),
+        ]->getReorgGuard(~fromBlock=1),
+        ~currentBlockHeight=3,
+        ~shouldRollbackOnReorg=true,
+      )
+    Assert.equal(reorgResult, ReorgDetection.NoReorg)
+
+    let (_, reorgResult) =
+      scannedHashes->ReorgDetection.LastBlockScannedHashes.registerReorgGuard(
+        ~reorgGuard=[
+          mockBlock(~height=2, ~id="0xb2"),
+          mockBlock(~height=3, ~id="0xb3"),
+        ]->getReorgGuard(~fromBlock=3),
+        ~currentBlockHeight=3,
+        ~shouldRollbackOnReorg=true,
+      )
+    switch reorgResult {
+    | ReorgDetected({scannedBlock, receivedBlock}) =>
+      Assert.deepEqual(
+        (scannedBlock.blockNumber, scannedBlock.blockHash, receivedBlock.blockHash),
+        (2, "0xa2", "0xb2"),
+      )
+    | NoReorg => Assert.fail("Should detect the reorg of block 2")
+    }
+  })
+})
@@ -0,0 +1,19 @@
+open RescriptMocha
+
+describe("Fuel - getBytecodeRoot", () => {
+  // The expected roots follow Input::predicate_owner from fuel-tx:
+  // sha256("FUEL" ++ merkle root of the 16KiB bytecode chunks)
+  it("Gets the root of a script bytecode", () => {
+    Assert.equal(
+      Fuel.getBytecodeRoot("0x1a403000504100301a445000ba49000032400481"),
+      "0x0f938a0ae4a4aad5dc9115669bfaa0d6bd802d897a09029b04fe85548ccf928a",
+    )
+  })
+
+  it("Gets the root of a bytecode with multiple chunks", () => {
+    Assert.equal(
+      Fuel.getBytecodeRoot("0x" ++ "ab"->Js.String2.repeat(20000)),
+      "0xd03287a67df418a44a26edbbb889dd8320bf955424b5e52ada6bb3d91cba4414",
+    )
+  })
+})
@@ -0,0 +1,99 @@
+open RescriptMocha
+open Belt
+
+describe("HyperFuel - decodeLogQueryPageItems", () => {
+  let contractId = "0x0000000000000000000000000000000000000000000000000000000000000001"
+
+  let mockBlock = (~height) =>
+    {
+      "id": `0x${height->Int.toString}`,
+      "height": height,
+      "time": 1000 + height,
+    }->(Utils.magic: {"id": string, "height": int, "time": int} => HyperFuelClient.FuelTypes.block)
+
+  let mockReceipt = (~txId, ~blockHeight, ~receiptIndex, ~receiptType: Fuel.receiptType) =>
+    {
+      "txId": txId,
+      "txStatus": 1,
+      "blockHeight": blockHeight,
+      "receiptIndex": receiptIndex,
+      "receiptType": receiptType,
+      "rootContractId": switch receiptType {
+      | ScriptResult => None
+      | _ => Some(contractId)
+      },
+      "rb": BigInt.fromInt(1),
+      "data": "0x",
+    }->(Utils.magic: 'a => HyperFuelClient.FuelTypes.receipt)
+
+  let mockInput = (~txId, ~blockHeight, ~owner) =>
+    {
+      "txId": txId,
+      "blockHeight": blockHeight,
+      "inputType": 0,
+      "owner": owner,
+      "amount": BigInt.fromInt(100),
+      "assetId": contractId,
+    }->(Utils.magic: 'a => HyperFuelClient.FuelTypes.input)
+
+  let mockData = (~receipts, ~inputs=[]): HyperFuelClient.queryResponseDataTyped => {
+    transactions: [],
+    receipts,
+    blocks: Some([mockBlock(~height=10), mockBlock(~height=11)]),
+    inputs,
+    outputs: [],
+  }
+
+  it("Offsets the logIndex by the receipts of the previous transactions in the block", () => {
+    let (items, _) =
+      mockData(
+        ~receipts=[
+          mockReceipt(~txId="0xa", ~blockHeight=10, ~receiptIndex=1, ~receiptType=LogData),
+          mockReceipt(~txId="0xa", ~blockHeight=10, ~receiptIndex=3, ~receiptType=ScriptResult),
+          mockReceipt(~txId="0xb", ~blockHeight=10, ~receiptIndex=0, ~receiptType=LogData),
+          mockReceipt(~txId="0xb", ~blockHeight=10, ~receiptIndex=2, ~receiptType=ScriptResult),
+          mockReceipt(~txId="0xc", ~blockHeight=11, ~receiptIndex=2, ~receiptType=LogData),
+          mockReceipt(~txId="0xc", ~blockHeight=11, ~receiptIndex=4, ~receiptType=ScriptResult),
+        ],
+      )->HyperFuel.GetLogs.decodeLogQueryPageItems
+
+    Assert.deepEqual(
+      items->Array.map(item => (item.transactionId, item.logIndex)),
+      [("0xa", 1), ("0xb", 4), ("0xc", 2)],
+      ~message="Should count the receipts across the transactions of the block, the same as fuel-core",
+    )
+  })
+
+  it("Puts the predicate spends after all receipts of the block", () => {
+    let (_, inputItems) =
+      mockData(
+        ~receipts=[
+          mockReceipt(~txId="0xa", ~blockHeight=10, ~receiptIndex=1, ~receiptType=ScriptResult),
+          mockReceipt(~txId="0xb", ~blockHeight=10, ~receiptIndex=2, ~receiptType=ScriptResult),
+        ],
+        ~inputs=[
+          mockInput(~txId="0xa", ~blockHeight=10, ~owner="0x01"),
+          mockInput(~txId="0xb", ~blockHeight=10, ~owner="0x02"),
+          mockInput(~txId="0xb", ~blockHeight=10, ~owner="0x01"),
+          mockInput(~txId="0xc", ~blockHeight=11, ~owner="0x01"),
+        ],
+      )->HyperFuel.GetLogs.decodeLogQueryPageItems
+
+    Assert.deepEqual(
+      inputItems->Array.map(item => (item.transactionId, item.logIndex)),
+      [("0xa", 5), ("0xb", 6), ("0xb", 7), ("0xc", 0)],
+      ~message="Should be unique across the transactions of the block and not collide with receipts",
+    )
+  })
+
+  it("Fails when the ScriptResult receipt of the transaction is missing", () => {
+    Assert.throws(
+      () =>
+        mockData(
+          ~receipts=[
+            mockReceipt(~txId="0xa", ~blockHeight=10, ~receiptIndex=1, ~receiptType=LogData),
+          ],
+        )->HyperFuel.GetLogs.decodeLogQueryPageItems,
+    )
+  })
+})
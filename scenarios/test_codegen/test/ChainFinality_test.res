@@ -0,0 +1,139 @@
+open RescriptMocha
+
+let mockSource = (~height, ~getBlockNumberByTagOrThrow=?): Source.t => {
+  name: "MockSource",
+  sourceFor: Sync,
+  poweredByHyperSync: false,
+  chain: MockConfig.chain1337,
+  pollingInterval: 1000,
+  getBlockHashes: (~blockNumbers as _, ~logger as _) =>
+    Js.Exn.raiseError("The getBlockHashes not implemented"),
+  getHeightOrThrow: () => Promise.resolve(height),
+  ?getBlockNumberByTagOrThrow,
+  getItemsOrThrow: (
+    ~fromBlock as _,
+    ~toBlock as _,
+    ~addressesByContractName as _,
+    ~indexingContracts as _,
+    ~currentBlockHeight as _,
+    ~partitionId as _,
+    ~selection as _,
+    ~retry as _,
+    ~logger as _,
+  ) => Js.Exn.raiseError("The getItemsOrThrow not implemented"),
+}
+
+describe("ChainFinality", () => {
+  let chainConfig = (~finality=?, ~minConfirmedBlockThreshold=?, ~sources) => {
+    ...MockConfig.mockChainConfig,
+    confirmedBlockThreshold: 200,
+    ?finality,
+    ?minConfirmedBlockThreshold,
+    sources,
+  }
+
+  Async.it("Keeps the confirmed block threshold when finality isn't configured", async () => {
+    let resolved = await ChainFinality.resolveChainConfig(
+      chainConfig(
+        ~sources=[
+          mockSource(
+            ~height=100,
+            ~getBlockNumberByTagOrThrow=(~tag as _) => Promise.resolve(Some(90)),
+          ),
+        ],
+      ),
+    )
+    Assert.equal(resolved.confirmedBlockThreshold, 200)
+  })
+
+  Async.it("Uses the deepest reorg depth of the sources supporting the block tag", async () => {
+    let requestedTags = []
+    let getBlockNumberByTagOrThrow = (~blockNumber) => (~tag) => {
+      requestedTags->Js.Array2.push(tag)->ignore
+      Promise.resolve(Some(blockNumber))
+    }
+    let resolved = await ChainFinality.resolveChainConfig(
+      chainConfig(
+        ~finality=Source.Safe,
+        ~sources=[
+          mockSource(
+            ~height=100,
+            ~getBlockNumberByTagOrThrow=getBlockNumberByTagOrThrow(~blockNumber=90),
+          ),
+          mockSource(
+            ~height=102,
+            ~getBlockNumberByTagOrThrow=getBlockNumberByTagOrThrow(~blockNumber=70),
+          ),
+          mockSource(~height=102),
+        ],
+      ),
+    )
+    Assert.equal(resolved.confirmedBlockThreshold, 32)
+    Assert.deepEqual(requestedTags, [Source.Safe, Source.Safe])
+  })
+
+  Async.it("Falls back to the confirmed block threshold when the tag isn't available", async () => {
+    let resolved = await ChainFinality.resolveChainConfig(
+      chainConfig(
+        ~finality=Source.Finalized,
+        ~sources=[
+          mockSource(~height=100),
+          mockSource(~height=100, ~getBlockNumberByTagOrThrow=(~tag as _) => Promise.resolve(None)),
+          mockSource(
+            ~height=100,
+            ~getBlockNumberByTagOrThrow=(~tag as _) => Js.Exn.raiseError("Unsupported block tag"),
+          ),
+        ],
+      ),
+    )
+    Assert.equal(
+      resolved.confirmedBlockThreshold,
+      200,
+      ~message="Should keep the default from the config",
+    )
+  })
+
+  Async.it("Doesn't go below the confirmed block threshold set by the user", async () => {
+    let resolved = await ChainFinality.resolveChainConfig(
+      chainConfig(
+        ~finality=Source.Finalized,
+        ~minConfirmedBlockThreshold=96,
+        ~sources=[
+          mockSource(
+            ~height=100,
+            ~getBlockNumberByTagOrThrow=(~tag as _) => Promise.resolve(Some(36)),
+          ),
+        ],
+      ),
+    )
+    Assert.equal(
+      resolved.confirmedBlockThreshold,
+      96,
+      ~message="Should use the minimum instead of the detected depth of 64",
+    )
+  })
+
+  Async.it("Doesn't wait for a source which never responds", async () => {
+    let resolved = await ChainFinality.resolveChainConfig(
+      chainConfig(
+        ~finality=Source.Finalized,
+        ~sources=[
+          mockSource(
+            ~height=100,
+            ~getBlockNumberByTagOrThrow=(~tag as _) => Promise.make((_, _) => ()),
+          ),
+          mockSource(
+            ~height=100,
+            ~getBlockNumberByTagOrThrow=(~tag as _) => Promise.resolve(Some(50)),
+          ),
+        ],
+      ),
+      ~timeoutMillis=10,
+    )
+    Assert.equal(
+      resolved.confirmedBlockThreshold,
+      50,
+      ~message="Should use the depth of the source which responded in time",
+    )
+  })
+})
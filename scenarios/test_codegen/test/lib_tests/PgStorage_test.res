@@ -95,6 +95,70 @@ describe("Test PgStorage SQL generation functions", () => {
     )
   })
 
+  describe("Full text search", () => {
+    let fullTextIndex: Table.fullTextIndex = {
+      name: "tokenSearch",
+      language: "english",
+      fields: ["name", "symbol"],
+    }
+    let table = Table.mkTable(
+      "Token",
+      ~fullTextIndices=[fullTextIndex],
+      ~fields=[
+        Table.mkField("id", Text, ~fieldSchema=S.string, ~isPrimaryKey=true),
+        Table.mkField("name", Text, ~fieldSchema=S.string),
+        Table.mkField("symbol", Text, ~fieldSchema=S.null(S.string), ~isNullable=true),
+      ],
+    )
+
+    Async.it(
+      "Should create a generated tsvector column",
+      async () => {
+        let query = PgStorage.makeCreateTableQuery(table, ~pgSchema="test_schema")
+
+        Assert.equal(
+          query,
+          `CREATE TABLE IF NOT EXISTS "test_schema"."Token"("id" TEXT NOT NULL, "name" TEXT NOT NULL, "symbol" TEXT, "tokenSearch_tsvector" tsvector GENERATED ALWAYS AS (to_tsvector('english', coalesce("name", '') || ' ' || coalesce("symbol", ''))) STORED, PRIMARY KEY("id"));`,
+          ~message="Should add the tsvector column to the table",
+        )
+      },
+    )
+
+    Async.it(
+      "Should create a GIN index on the tsvector column",
+      async () => {
+        let query = PgStorage.makeCreateTableIndicesQuery(table, ~pgSchema="test_schema")
+
+        Assert.equal(
+          query,
+          `CREATE INDEX IF NOT EXISTS "Token_tokenSearch_tsvector" ON "test_schema"."Token" USING GIN("tokenSearch_tsvector");`,
+          ~message="Should index the tsvector column",
+        )
+      },
+    )
+
+    Async.it(
+      "Should create a search function for Hasura",
+      async () => {
+        let query = PgStorage.makeFullTextSearchFnQuery(
+          fullTextIndex,
+          ~tableName="Token",
+          ~pgSchema="test_schema",
+        )
+
+        Assert.equal(
+          query,
+          `CREATE OR REPLACE FUNCTION "test_schema"."tokenSearch"(search text)
+RETURNS SETOF "test_schema"."Token" AS $$
+  SELECT * FROM "test_schema"."Token"
+  WHERE "tokenSearch_tsvector" @@ websearch_to_tsquery('english', search);
+$$ LANGUAGE sql STABLE;`,
+          ~message="Should query the tsvector column with the index language",
+        )
+      },
+    )
+  })
+
   describe("makeInitializeTransaction", () => {
     Async.it(
       "Should create complete initialization queries",
@@ -0,0 +1,39 @@
+open RescriptMocha
+
+describe("RateLimiter", () => {
+  it("Reserves slots spaced by the interval", () => {
+    let rateLimiter = RateLimiter.make(~maxRequestsPerSecond=4)
+
+    Assert.deepEqual(
+      [
+        rateLimiter->RateLimiter.reserveSlot(~nowMillis=1000.),
+        rateLimiter->RateLimiter.reserveSlot(~nowMillis=1000.),
+        rateLimiter->RateLimiter.reserveSlot(~nowMillis=1100.),
+      ],
+      [0., 250., 400.],
+      ~message="Each slot should be 250ms after the previous one",
+    )
+
+    Assert.deepEqual(
+      rateLimiter->RateLimiter.reserveSlot(~nowMillis=5000.),
+      0.,
+      ~message="Shouldn't wait when the previous slots are in the past",
+    )
+  })
+
+  Async.it("Delays the scheduled calls", async () => {
+    let rateLimiter = RateLimiter.make(~maxRequestsPerSecond=100)
+    let actionsCalled = []
+
+    let promise = Promise.all([
+      rateLimiter->RateLimiter.schedule(async () => actionsCalled->Js.Array2.push(1)->ignore),
+      rateLimiter->RateLimiter.schedule(async () => actionsCalled->Js.Array2.push(2)->ignore),
+    ])
+
+    await Time.resolvePromiseAfterDelay(~delayMilliseconds=2)
+    Assert.deepEqual(actionsCalled, [1], ~message="Second call should wait for its slot")
+
+    let _ = await promise
+    Assert.deepEqual(actionsCalled, [1, 2], ~message="Second call should run after 10ms")
+  })
+})
@@ -18,6 +18,7 @@ let sourceMock = (
   ~mockGetHeightOrThrow=false,
   ~mockGetItemsOrThrow=false,
   ~pollingInterval=1000,
+  ~weight=?,
 ) => {
   let getHeightOrThrowCalls = []
   let getHeightOrThrowResolveFns = []
@@ -47,6 +48,7 @@ let sourceMock = (
         poweredByHyperSync: false,
         chain: ChainMap.Chain.makeUnsafe(~chainId=0),
         pollingInterval,
+        ?weight,
         getBlockHashes: (~blockNumbers as _, ~logger as _) =>
           Js.Exn.raiseError("The getBlockHashes not implemented"),
         getHeightOrThrow: if mockGetHeightOrThrow {
@@ -921,6 +923,36 @@ describe("SourceManager wait for new blocks", () => {
     },
   )
 
+  Async.it(
+    "Immediately polls Realtime sources, but doesn't make them the active source",
+    async () => {
+      let syncMock = sourceMock(~mockGetHeightOrThrow=true)
+      let realtimeMock = sourceMock(~sourceFor=Realtime, ~mockGetHeightOrThrow=true)
+      let sourceManager = SourceManager.make(
+        ~sources=[syncMock.source, realtimeMock.source],
+        ~maxPartitionConcurrency=10,
+      )
+
+      let p = sourceManager->SourceManager.waitForNewBlock(~currentBlockHeight=0)
+
+      Assert.deepEqual(syncMock.getHeightOrThrowCalls->Array.length, 1)
+      Assert.deepEqual(realtimeMock.getHeightOrThrowCalls->Array.length, 1)
+
+      realtimeMock.resolveGetHeightOrThrow(2)
+
+      Assert.deepEqual(
+        await p,
+        2,
+        ~message="Should resolve with the height from the Realtime source",
+      )
+      Assert.equal(
+        sourceManager->SourceManager.getActiveSource,
+        syncMock.source,
+        ~message=`Realtime source can't fetch items, so it shouldn't become active`,
+      )
+    },
+  )
+
   Async.it("Start polling all sources with it's own rates if new block isn't found", async () => {
     let pollingInterval0 = 1
     let pollingInterval1 = 2
@@ -1532,4 +1564,71 @@ describe("SourceManager.executeQuery", () => {
       Assert.equal((await p).parsedQueueItems, items)
     },
   )
+
+  Async.it("Spreads queries between sync sources by weight", async () => {
+    let mock0 = sourceMock(~mockGetItemsOrThrow=true, ~weight=2)
+    let mock1 = sourceMock(~mockGetItemsOrThrow=true, ~weight=1)
+    let sourceManager = SourceManager.make(
+      ~sources=[mock0.source, mock1.source, sourceMock(~sourceFor=Fallback).source],
+      ~maxPartitionConcurrency=10,
+    )
+
+    let p = Promise.all(
+      [0, 1, 2]->Array.map(_ =>
+        sourceManager->SourceManager.executeQuery(~query=mockQuery(), ~currentBlockHeight=100)
+      ),
+    )
+
+    Assert.deepEqual(
+      (mock0.getItemsOrThrowCalls->Array.length, mock1.getItemsOrThrowCalls->Array.length),
+      (2, 1),
+      ~message="Should split the queries according to the weights",
+    )
+
+    mock0.resolveGetItemsOrThrow(items)
+    mock1.resolveGetItemsOrThrow(items)
+    let _ = await p
+  })
+
+  Async.it(
+    "When load balanced, immediately retries a failed query with another sync source and demotes the failing one",
+    async () => {
+      let mock0 = sourceMock(~mockGetItemsOrThrow=true, ~weight=1)
+      let mock1 = sourceMock(~mockGetItemsOrThrow=true, ~weight=1)
+      let sourceManager = SourceManager.make(
+        ~sources=[mock0.source, mock1.source],
+        ~maxPartitionConcurrency=10,
+      )
+
+      let p = sourceManager->SourceManager.executeQuery(~query=mockQuery(), ~currentBlockHeight=100)
+      mock0.rejectGetItemsOrThrow(
+        Source.GetItemsError(
+          FailedGettingItems({
+            exn: %raw(`null`),
+            attemptedToBlock: 100,
+            // Would time out the test if it waited for the backoff
+            retry: WithBackoff({message: "test", backoffMillis: 60_000}),
+          }),
+        ),
+      )
+      await Promise.resolve()
+
+      Assert.deepEqual(
+        (mock0.getItemsOrThrowCalls, mock1.getItemsOrThrowCalls),
+        ([{"toBlock": None, "retry": 0}], [{"toBlock": None, "retry": 1}]),
+        ~message="Should retry with the other source without waiting for backoff",
+      )
+      mock1.resolveGetItemsOrThrow(items)
+      Assert.equal((await p).parsedQueueItems, items)
+
+      let p = sourceManager->SourceManager.executeQuery(~query=mockQuery(), ~currentBlockHeight=100)
+      Assert.deepEqual(
+        (mock0.getItemsOrThrowCalls->Array.length, mock1.getItemsOrThrowCalls->Array.length),
+        (1, 2),
+        ~message="Should prefer the healthy source for the next query",
+      )
+      mock1.resolveGetItemsOrThrow(items)
+      let _ = await p
+    },
+  )
 })